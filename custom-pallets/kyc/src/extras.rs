@@ -0,0 +1,61 @@
+use crate::*;
+
+impl<T: Config> KycLink for Pallet<T> {
+    type AccountId = AccountIdOf<T>;
+
+    fn ensure_live_commitment_link(who: Self::AccountId) -> DispatchResult {
+        Self::ensure_live_commitment(who)
+    }
+
+    fn commitment_of_link(who: Self::AccountId) -> Option<Commitment> {
+        <AccountCommitment<T>>::get(who)
+    }
+
+    fn transfer_commitment_link(from: Self::AccountId, to: Self::AccountId) -> DispatchResult {
+        Self::transfer_commitment(from, to)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Current unix timestamp in seconds
+    pub(super) fn now() -> u64 {
+        let now: u64 = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        now / 1000
+    }
+
+    pub fn is_expired(expiry: u64) -> bool {
+        expiry <= Self::now()
+    }
+
+    pub(super) fn ensure_live_commitment(who: T::AccountId) -> DispatchResult {
+        let commitment = <AccountCommitment<T>>::get(&who).ok_or(Error::<T>::NoCommitment)?;
+        let (owner, expiry) = <Commitments<T>>::get(commitment).ok_or(Error::<T>::NoCommitment)?;
+        ensure!(owner == who, Error::<T>::NotAuthorized);
+        ensure!(!Self::is_expired(expiry), Error::<T>::KycExpired);
+        Ok(())
+    }
+
+    /// Accounts without a commitment have nothing to move
+    pub(super) fn transfer_commitment(from: T::AccountId, to: T::AccountId) -> DispatchResult {
+        let Some(commitment) = <AccountCommitment<T>>::get(&from) else {
+            return Ok(());
+        };
+        ensure!(
+            !<AccountCommitment<T>>::contains_key(&to),
+            Error::<T>::AccountHasCommitment
+        );
+        <Commitments<T>>::try_mutate(commitment, |entry| -> DispatchResult {
+            let (owner, _expiry) = entry.as_mut().ok_or(Error::<T>::NotRegistered)?;
+            *owner = to.clone();
+            Ok(())
+        })?;
+        <AccountCommitment<T>>::remove(&from);
+        <AccountCommitment<T>>::insert(&to, commitment);
+        Self::deposit_event(Event::KycTransferred {
+            from,
+            to,
+            commitment,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Anonymous KYC, see `docs/kyc/kyc.typ`
+///
+/// Validators check the user and the user proves with a zero knowledge proof that they know
+/// the secret of the commitment `hash(name:address:secret)`. Only the commitment and its expiry
+/// are stored, a commitment can only be registered once.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+mod extras;
+pub mod verifier;
+
+use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::SaturatedConversion;
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+use trait_kyc::KycLink;
+pub use verifier::{HashProofVerifier, ProofVerifier, RejectAllProofVerifier};
+
+type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+/// Commitment `hash(name:address:secret)` of the user
+pub type Commitment = [u8; 32];
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+    use super::*;
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    /// Configure the pallet by specifying the parameters and types on which it depends.
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_timestamp::Config {
+        /// Because this pallet emits events, it depends on the runtime's definition of an event.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Verifier of the proof that the user knows the secret of the commitment
+        type ProofVerifier: ProofVerifier<Self::AccountId>;
+
+        /// Origin that can remove commitments
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    /// Commitment => (owner, expiry), expiry is a unix timestamp in seconds
+    #[pallet::storage]
+    #[pallet::getter(fn commitment)]
+    pub type Commitments<T: Config> =
+        StorageMap<_, Blake2_128Concat, Commitment, (T::AccountId, u64)>;
+
+    /// Commitment of the account, an account has a single commitment
+    #[pallet::storage]
+    #[pallet::getter(fn commitment_of)]
+    pub type AccountCommitment<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Commitment>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        KycRegistered {
+            who: T::AccountId,
+            commitment: Commitment,
+            expiry: u64,
+        },
+        KycExtended {
+            who: T::AccountId,
+            commitment: Commitment,
+            expiry: u64,
+        },
+        KycRemoved {
+            commitment: Commitment,
+        },
+        KycTransferred {
+            from: T::AccountId,
+            to: T::AccountId,
+            commitment: Commitment,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        KycAlreadyRegistered,
+        AccountHasCommitment,
+        NotRegistered,
+        NotAuthorized,
+        InvalidProof,
+        InvalidExpiry,
+        KycExpired,
+        NoCommitment,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// # Register KYC
+        ///
+        /// Register the commitment with its expiry, `proof` shows the user knows the secret of
+        /// the commitment.
+        #[pallet::call_index(0)]
+        #[pallet::weight(0)]
+        pub fn register_kyc(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            hash: Commitment,
+            expiry: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(expiry > Self::now(), Error::<T>::InvalidExpiry);
+            ensure!(
+                T::ProofVerifier::verify(&proof, &who, &hash, expiry),
+                Error::<T>::InvalidProof
+            );
+            ensure!(
+                !<Commitments<T>>::contains_key(hash),
+                Error::<T>::KycAlreadyRegistered
+            );
+            ensure!(
+                !<AccountCommitment<T>>::contains_key(&who),
+                Error::<T>::AccountHasCommitment
+            );
+
+            <Commitments<T>>::insert(hash, (who.clone(), expiry));
+            <AccountCommitment<T>>::insert(&who, hash);
+            Self::deposit_event(Event::KycRegistered {
+                who,
+                commitment: hash,
+                expiry,
+            });
+            Ok(())
+        }
+
+        /// # Extend KYC
+        ///
+        /// Owner of the commitment sets a new expiry with a new proof, also used to renew an expired commitment.
+        #[pallet::call_index(1)]
+        #[pallet::weight(0)]
+        pub fn extend_kyc(
+            origin: OriginFor<T>,
+            proof: Vec<u8>,
+            hash: Commitment,
+            new_expiry: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(new_expiry > Self::now(), Error::<T>::InvalidExpiry);
+            ensure!(
+                T::ProofVerifier::verify(&proof, &who, &hash, new_expiry),
+                Error::<T>::InvalidProof
+            );
+
+            <Commitments<T>>::try_mutate(hash, |entry| -> DispatchResult {
+                let (account, expiry) = entry.as_mut().ok_or(Error::<T>::NotRegistered)?;
+                ensure!(*account == who, Error::<T>::NotAuthorized);
+                *expiry = new_expiry;
+                Ok(())
+            })?;
+            Self::deposit_event(Event::KycExtended {
+                who,
+                commitment: hash,
+                expiry: new_expiry,
+            });
+            Ok(())
+        }
+
+        /// # Remove KYC
+        ///
+        /// Governance removes a commitment, e.g. of a fraudulent user. The commitment can be registered again.
+        #[pallet::call_index(2)]
+        #[pallet::weight(0)]
+        pub fn governance_remove(origin: OriginFor<T>, hash: Commitment) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            let (owner, _expiry) = <Commitments<T>>::take(hash).ok_or(Error::<T>::NotRegistered)?;
+            <AccountCommitment<T>>::remove(&owner);
+            Self::deposit_event(Event::KycRemoved { commitment: hash });
+            Ok(())
+        }
+    }
+}
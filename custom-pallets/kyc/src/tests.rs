@@ -0,0 +1,118 @@
+use crate::{mock::*, Error, Event, HashProofVerifier};
+use frame_support::{assert_noop, assert_ok};
+use trait_kyc::KycLink;
+
+const COMMITMENT: [u8; 32] = [7u8; 32];
+
+fn proof(who: u64, commitment: [u8; 32], expiry: u64) -> Vec<u8> {
+	HashProofVerifier::proof(&who, &commitment, expiry).to_vec()
+}
+
+#[test]
+fn register_and_extend_kyc() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000_000);
+
+		assert_noop!(
+			Kyc::register_kyc(
+				RuntimeOrigin::signed(1),
+				proof(1, COMMITMENT, 2000),
+				COMMITMENT,
+				1000
+			),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			Kyc::register_kyc(
+				RuntimeOrigin::signed(1),
+				proof(1, COMMITMENT, 3000),
+				COMMITMENT,
+				2000
+			),
+			Error::<Test>::InvalidProof
+		);
+		assert_noop!(Kyc::ensure_live_commitment_link(1), Error::<Test>::NoCommitment);
+		// Proof is bound to the account
+		assert_noop!(
+			Kyc::register_kyc(
+				RuntimeOrigin::signed(2),
+				proof(1, COMMITMENT, 2000),
+				COMMITMENT,
+				2000
+			),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(Kyc::register_kyc(
+			RuntimeOrigin::signed(1),
+			proof(1, COMMITMENT, 2000),
+			COMMITMENT,
+			2000
+		));
+		System::assert_last_event(
+			Event::KycRegistered { who: 1, commitment: COMMITMENT, expiry: 2000 }.into(),
+		);
+		assert_eq!(Kyc::commitment(COMMITMENT), Some((1, 2000)));
+		assert_eq!(Kyc::commitment_of(1), Some(COMMITMENT));
+		assert_ok!(Kyc::ensure_live_commitment_link(1));
+
+		// Commitment is unique
+		assert_noop!(
+			Kyc::register_kyc(
+				RuntimeOrigin::signed(2),
+				proof(2, COMMITMENT, 2000),
+				COMMITMENT,
+				2000
+			),
+			Error::<Test>::KycAlreadyRegistered
+		);
+		assert_noop!(
+			Kyc::register_kyc(RuntimeOrigin::signed(1), proof(1, [8u8; 32], 2000), [8u8; 32], 2000),
+			Error::<Test>::AccountHasCommitment
+		);
+
+		Timestamp::set_timestamp(2_000_000);
+		assert_noop!(Kyc::ensure_live_commitment_link(1), Error::<Test>::KycExpired);
+
+		assert_noop!(
+			Kyc::extend_kyc(RuntimeOrigin::signed(2), proof(2, COMMITMENT, 3000), COMMITMENT, 3000),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(Kyc::extend_kyc(
+			RuntimeOrigin::signed(1),
+			proof(1, COMMITMENT, 3000),
+			COMMITMENT,
+			3000
+		));
+		assert_eq!(Kyc::commitment(COMMITMENT), Some((1, 3000)));
+		assert_ok!(Kyc::ensure_live_commitment_link(1));
+	});
+}
+
+#[test]
+fn governance_remove_kyc() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000_000);
+		assert_ok!(Kyc::register_kyc(
+			RuntimeOrigin::signed(1),
+			proof(1, COMMITMENT, 2000),
+			COMMITMENT,
+			2000
+		));
+
+		assert_noop!(
+			Kyc::governance_remove(RuntimeOrigin::signed(1), COMMITMENT),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Kyc::governance_remove(RuntimeOrigin::root(), COMMITMENT));
+		System::assert_last_event(Event::KycRemoved { commitment: COMMITMENT }.into());
+		assert_eq!(Kyc::commitment(COMMITMENT), None);
+		assert_eq!(Kyc::commitment_of(1), None);
+		assert_noop!(
+			Kyc::governance_remove(RuntimeOrigin::root(), COMMITMENT),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
@@ -0,0 +1,42 @@
+use crate::Commitment;
+use codec::Encode;
+
+/// Verifies the zero knowledge proof of a commitment
+///
+/// The proof commits to the registering account, the commitment and its expiry, e.g. the journal
+/// of a risc0 receipt, so the proof of one account can't be replayed by another.
+pub trait ProofVerifier<AccountId> {
+    fn verify(proof: &[u8], who: &AccountId, commitment: &Commitment, expiry: u64) -> bool;
+}
+
+/// Deterministic verifier for tests and development chains
+///
+/// The proof is `blake2_256((who, commitment, expiry))`, it proves nothing about the secret.
+pub struct HashProofVerifier;
+
+impl HashProofVerifier {
+    pub fn proof<AccountId: Encode>(
+        who: &AccountId,
+        commitment: &Commitment,
+        expiry: u64,
+    ) -> [u8; 32] {
+        sp_io::hashing::blake2_256(&(who, commitment, expiry).encode())
+    }
+}
+
+impl<AccountId: Encode> ProofVerifier<AccountId> for HashProofVerifier {
+    fn verify(proof: &[u8], who: &AccountId, commitment: &Commitment, expiry: u64) -> bool {
+        proof == Self::proof(who, commitment, expiry).as_slice()
+    }
+}
+
+/// Rejects every proof, for chains without a verifier of the KYC proof
+///
+/// Commitments can't be registered or extended until a verifier is configured.
+pub struct RejectAllProofVerifier;
+
+impl<AccountId> ProofVerifier<AccountId> for RejectAllProofVerifier {
+    fn verify(_proof: &[u8], _who: &AccountId, _commitment: &Commitment, _expiry: u64) -> bool {
+        false
+    }
+}
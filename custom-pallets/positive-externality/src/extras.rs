@@ -41,7 +41,19 @@ impl<T: Config> Pallet<T> {
 		let min_juror_stake: u64 = T::MinJurorStake::get();
 		let juror_incentives: (u64, u64) = T::JurorIncentives::get();
      
-		T::SchellingGameSharedSource::create_phase_with_all_data(evidence_length, end_of_staking_time, staking_length, drawing_length, commit_length, vote_length, appeal_length, max_draws, min_number_juror_staked, min_juror_stake, juror_incentives)
+		T::SchellingGameSharedSource::create_phase_with_all_data(PhaseParams {
+			evidence_length,
+			end_of_staking_time,
+			staking_length,
+			drawing_length,
+			commit_length,
+			vote_length,
+			appeal_length,
+			max_draws,
+			min_number_juror_staked,
+			min_juror_stake,
+			juror_incentives,
+		})
 	}
 
 	pub fn ensure_validation_on_positive_externality(account: T::AccountId) -> DispatchResult {
@@ -61,6 +73,14 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return the `StakeBalance` of the user to the account that paid it
+	pub(super) fn refund_stake_balance(user_to_calculate: T::AccountId) {
+		let stake = StakeBalance::<T>::take(&user_to_calculate);
+		let payer = StakePayer::<T>::take(&user_to_calculate).unwrap_or(user_to_calculate);
+		let r = <T as pallet::Config>::Currency::deposit_creating(&payer, stake);
+		<T as pallet::Config>::Reward::on_unbalanced(r);
+	}
+
 	pub(super) fn u64_to_balance_saturated(input: u64) -> BalanceOf<T> {
 		input.saturated_into::<BalanceOf<T>>()
 	}
@@ -26,9 +26,11 @@ use frame_support::traits::{
 	Currency, ExistenceRequirement, Get, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 };
 use frame_support::{dispatch::DispatchResult, ensure};
+use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::*;
 use pallet_schelling_game_shared::types::{
-	JurorGameResult, Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
+	CancellationReason, JurorGameResult, Period, PhaseData, RangePoint, RubricDimension,
+	SchellingGameType, WinningDecision,
 };
 use pallet_sortition_sum_game::types::SumTreeName;
 use pallet_support::{
@@ -37,8 +39,9 @@ use pallet_support::{
 };
 use types::{Incentives, IncentivesMetaData};
 
+use sp_arithmetic::FixedPointNumber;
 use sp_std::prelude::*;
-use trait_schelling_game_shared::SchellingGameSharedLink;
+use trait_schelling_game_shared::{PhaseParams, SchellingGameSharedLink};
 use trait_shared_storage::SharedStorageLink;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
@@ -78,6 +81,8 @@ pub mod pallet {
 			PhaseData = PhaseData<Self>,
 			WinningDecision = WinningDecision,
 			JurorGameResult = JurorGameResult,
+			CancellationReason = CancellationReason,
+			RubricDimension = RubricDimension,
 		>;
 		type Currency: ReservableCurrency<Self::AccountId>;
 		/// Handler for the unbalanced increment when rewarding (minting rewards)
@@ -167,6 +172,11 @@ pub mod pallet {
 	pub type StakeBalance<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// Account that paid the `StakeBalance` of a user, refunded if the game is cancelled
+	#[pallet::storage]
+	#[pallet::getter(fn stake_payer)]
+	pub type StakePayer<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::AccountId>;
+
 	#[pallet::type_value]
 	pub fn DefaultValidate<T: Config>() -> bool {
 		true
@@ -206,6 +216,12 @@ pub mod pallet {
 	pub type IncentivesMeta<T: Config> =
 		StorageValue<_, IncentivesMetaData<T>, ValueQuery, IncentivesMetaValue<T>>;
 
+	/// Rubric used to score positive externality, e.g. reach, verifiability, durability, cost-effectiveness
+	/// Empty rubric scores with a single number
+	#[pallet::storage]
+	#[pallet::getter(fn impact_rubric)]
+	pub type ImpactRubric<T: Config> = StorageValue<_, Vec<RubricDimension>, ValueQuery>;
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -214,6 +230,10 @@ pub mod pallet {
 		/// Event documentation should end with an array that provides descriptive names for event
 		/// parameters. [something, who]
 		SomethingStored { something: u32, who: T::AccountId },
+		/// Positive externality validation cancelled. [user, reason]
+		ValidationCancelled { user: T::AccountId, reason: CancellationReason },
+		/// Rubric for new validations is set. [dimensions]
+		ImpactRubricSet { dimensions: u32 },
 	}
 
 	// Errors inform users that something went wrong.
@@ -232,6 +252,8 @@ pub mod pallet {
 		NotReachedMinimumDecision,
 		NoIncentiveCount,
 		AlreadyFunded,
+		/// Rubric weights add up to zero.
+		InvalidRubric,
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -304,6 +326,7 @@ pub mod pallet {
 			)?;
 
 			StakeBalance::<T>::insert(&user_to_calculate, stake);
+			StakePayer::<T>::insert(&user_to_calculate, &who);
 
 			let pe_block_number = <ValidationBlock<T>>::get(user_to_calculate.clone());
 			// println!("{:?}", pe_block_number);
@@ -342,6 +365,10 @@ pub mod pallet {
 				}
 				// check what if called again
 				T::SchellingGameSharedSource::set_to_staking_period_pe_link(key.clone(), now)?;
+				let rubric = <ImpactRubric<T>>::get();
+				if !rubric.is_empty() {
+					T::SchellingGameSharedSource::set_rubric_link(key.clone(), rubric)?;
+				}
 				T::SchellingGameSharedSource::create_tree_helper_link(key, 3)?;
 
 			//  println!("{:?}", data);
@@ -353,7 +380,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::SchellingGameSharedSource::set_stake_weight_link())]
 		pub fn apply_jurors(
 			origin: OriginFor<T>,
 			user_to_calculate: T::AccountId,
@@ -401,7 +428,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(5)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::SchellingGameSharedSource::draw_jurors_weight_link(*iterations))]
 		pub fn draw_jurors(
 			origin: OriginFor<T>,
 			user_to_calculate: T::AccountId,
@@ -426,7 +453,7 @@ pub mod pallet {
 		// Unstaking
 		// Stop drawn juror to unstake ✔️
 		#[pallet::call_index(6)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::SchellingGameSharedSource::set_stake_weight_link())]
 		pub fn unstaking(origin: OriginFor<T>, user_to_calculate: T::AccountId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let pe_block_number = <ValidationBlock<T>>::get(user_to_calculate.clone());
@@ -455,7 +482,19 @@ pub mod pallet {
 				block_number: pe_block_number.clone(),
 			};
 
-			T::SchellingGameSharedSource::commit_vote_for_score_helper_link(key, who, vote_commit)?;
+			if T::SchellingGameSharedSource::get_rubric_link(key.clone()).is_some() {
+				T::SchellingGameSharedSource::commit_vote_for_rubric_helper_link(
+					key,
+					who,
+					vote_commit,
+				)?;
+			} else {
+				T::SchellingGameSharedSource::commit_vote_for_score_helper_link(
+					key,
+					who,
+					vote_commit,
+				)?;
+			}
 			Ok(())
 		}
 
@@ -496,7 +535,11 @@ pub mod pallet {
 				block_number: pe_block_number.clone(),
 			};
 
-			T::SchellingGameSharedSource::set_new_mean_value(key)?;
+			if T::SchellingGameSharedSource::get_rubric_link(key.clone()).is_some() {
+				T::SchellingGameSharedSource::set_new_rubric_mean_value_link(key)?;
+			} else {
+				T::SchellingGameSharedSource::set_new_mean_value(key)?;
+			}
 			Ok(())
 		}
 
@@ -527,7 +570,13 @@ pub mod pallet {
 
 			if got_incentives_bool == false {
 				<GotPositiveExternality<T>>::insert(key.clone(), true);
-				let balance = Self::u64_to_balance_saturated((score as u64) * 1000);
+				// Mean score of 1 pays 1_000_000, scores of zero or less pay nothing
+				let amount = if score.is_positive() {
+					score.checked_mul_int(1_000_000u64).ok_or(Error::<T>::StorageOverflow)?
+				} else {
+					0
+				};
+				let balance = Self::u64_to_balance_saturated(amount);
 
 				let r = <T as pallet::Config>::Currency::deposit_into_existing(
 					&user_to_calculate,
@@ -625,11 +674,19 @@ pub mod pallet {
 			};
 
 			let phase_data = Self::get_phase_data();
-			T::SchellingGameSharedSource::get_incentives_score_schelling_helper_link(
-				key.clone(),
-				phase_data,
-				RangePoint::ZeroToFive,
-			)?;
+			if T::SchellingGameSharedSource::get_rubric_link(key.clone()).is_some() {
+				T::SchellingGameSharedSource::get_incentives_rubric_schelling_helper_link(
+					key.clone(),
+					phase_data,
+					RangePoint::ZeroToFive,
+				)?;
+			} else {
+				T::SchellingGameSharedSource::get_incentives_score_schelling_helper_link(
+					key.clone(),
+					phase_data,
+					RangePoint::ZeroToFive,
+				)?;
+			}
 
 			let score = T::SchellingGameSharedSource::get_mean_value_link(key.clone())?;
 			// println!("Score {:?}", score);
@@ -638,6 +695,82 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Cancel the positive externality game of a user
+		/// `CancellationReason::Governance` requires root, `CancellationReason::NotEnoughJurorsStaked`
+		/// can be called by anyone once staking is over with less than `MinNumberJurorStaked` jurors.
+		/// Juror stakes are refunded by the schelling game and the `StakeBalance` is returned to its payer.
+		#[pallet::call_index(12)]
+		#[pallet::weight(0)]
+		pub fn cancel_validation(
+			origin: OriginFor<T>,
+			user_to_calculate: T::AccountId,
+			reason: CancellationReason,
+		) -> DispatchResult {
+			let who = ensure_signed_or_root(origin)?;
+			if reason == CancellationReason::Governance {
+				ensure!(who.is_none(), DispatchError::BadOrigin);
+			}
+			let pe_block_number = <ValidationBlock<T>>::get(user_to_calculate.clone());
+
+			let key = SumTreeName::PositiveExternality {
+				user_address: user_to_calculate.clone(),
+				block_number: pe_block_number,
+			};
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let phase_data = Self::get_phase_data();
+			T::SchellingGameSharedSource::cancel_game_link(key, phase_data, now, reason.clone())?;
+
+			Self::refund_stake_balance(user_to_calculate.clone());
+
+			Self::deposit_event(Event::ValidationCancelled { user: user_to_calculate, reason });
+			Ok(())
+		}
+
+		/// Set the rubric for new validations, an empty rubric goes back to a single score
+		#[pallet::call_index(13)]
+		#[pallet::weight(0)]
+		pub fn set_impact_rubric(
+			origin: OriginFor<T>,
+			rubric: Vec<RubricDimension>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let total_weight =
+				rubric.iter().fold(0u32, |total, dimension| total.saturating_add(dimension.weight));
+			ensure!(rubric.is_empty() || total_weight > 0, Error::<T>::InvalidRubric);
+			let dimensions = rubric.len() as u32;
+			<ImpactRubric<T>>::put(rubric);
+			Self::deposit_event(Event::ImpactRubricSet { dimensions });
+			Ok(())
+		}
+
+		/// Reveal a score from 1 to 5 for every dimension of the rubric
+		#[pallet::call_index(14)]
+		#[pallet::weight(0)]
+		pub fn reveal_rubric_vote(
+			origin: OriginFor<T>,
+			user_to_calculate: T::AccountId,
+			choices: Vec<i64>,
+			salt: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				choices.iter().all(|choice| *choice <= 5 && *choice >= 1),
+				Error::<T>::ChoiceOutOfRange
+			);
+
+			let pe_block_number = <ValidationBlock<T>>::get(user_to_calculate.clone());
+
+			let key = SumTreeName::PositiveExternality {
+				user_address: user_to_calculate,
+				block_number: pe_block_number,
+			};
+
+			T::SchellingGameSharedSource::reveal_vote_rubric_helper_link(key, who, choices, salt)?;
+			Ok(())
+		}
+
 		// Provide incentives
 		// Provide incentives to juror based on number of games played and their win/loss ratio
 		// Provide incentives when total_numbers of games is reached
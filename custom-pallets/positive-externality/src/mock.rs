@@ -1,5 +1,5 @@
 use crate as pallet_template;
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 
 use sp_runtime::BuildStorage;
@@ -107,17 +107,32 @@ impl pallet_balances::Config for Test {
     type AccountStore = System;
 }
 
+parameter_types! {
+    pub const JurorRewardPoolPalletId: PalletId = PalletId(*b"sg/jrpol");
+    pub const RewardEpochLength: u64 = 1000;
+    pub const RewardEpochInflation: u64 = 1_000_000;
+    pub const MaxRewardPerEpoch: u64 = 1_000_000;
+    pub const AppealFeePerJuror: u64 = 10;
+    pub const MaxJurorPoolSize: u32 = 100;
+}
+
 impl pallet_schelling_game_shared::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances; // New code
     type RandomnessSource = TestRandomness<Self>;
-    type Slash = ();
     type Reward = ();
     type SortitionSumGameSource = SortitionSumGame;
+    type RewardPoolPalletId = JurorRewardPoolPalletId;
+    type RewardEpochLength = RewardEpochLength;
+    type RewardEpochInflation = RewardEpochInflation;
+    type MaxRewardPerEpoch = MaxRewardPerEpoch;
+    type AppealFeePerJuror = AppealFeePerJuror;
+    type MaxJurorPoolSize = MaxJurorPoolSize;
 }
 
 impl pallet_sortition_sum_game::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 
 impl pallet_template::Config for Test {
@@ -2,7 +2,7 @@ use crate::types::Incentives;
 use crate::types::Post;
 use crate::{mock::*, Error};
 use frame_support::{assert_noop, assert_ok};
-use pallet_schelling_game_shared::types::Period;
+use pallet_schelling_game_shared::types::{CancellationReason, Period};
 use pallet_sortition_sum_game::types::SumTreeName;
 use pallet_support::WhenDetails;
 use pallet_support::{Content, WhoAndWhen};
@@ -255,7 +255,7 @@ fn full_schelling_game_func(user_to_calculate: u64, start_block_number: u64) {
 	assert_eq!(5, draws_in_round);
 
 	let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
-	assert_eq!(vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)], drawn_jurors);
+	assert_eq!(vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)], drawn_jurors);
 
 	assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(5), user_to_calculate));
 
@@ -271,23 +271,23 @@ fn full_schelling_game_func(user_to_calculate: u64, start_block_number: u64) {
 		<pallet_schelling_game_shared::Error<Test>>::JurorDoesNotExists
 	);
 	let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(4), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
 
 	// You can replace vote within the commit period.
 	let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(4), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(7), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(17), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(23), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt5".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(14), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(25), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("3salt6".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(15), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(29), user_to_calculate, hash));
 
 	assert_noop!(
 		TemplateModule::pass_period(RuntimeOrigin::signed(5), user_to_calculate),
@@ -303,7 +303,7 @@ fn full_schelling_game_func(user_to_calculate: u64, start_block_number: u64) {
 
 	assert_noop!(
 		TemplateModule::reveal_vote(
-			RuntimeOrigin::signed(4),
+			RuntimeOrigin::signed(13),
 			user_to_calculate,
 			2,
 			"salt2".as_bytes().to_vec()
@@ -312,28 +312,28 @@ fn full_schelling_game_func(user_to_calculate: u64, start_block_number: u64) {
 	);
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(4),
+		RuntimeOrigin::signed(13),
 		user_to_calculate,
 		1,
 		"salt2".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(7),
+		RuntimeOrigin::signed(17),
 		user_to_calculate,
 		1,
 		"salt3".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(14),
+		RuntimeOrigin::signed(25),
 		user_to_calculate,
 		1,
 		"salt5".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(15),
+		RuntimeOrigin::signed(29),
 		user_to_calculate,
 		3,
 		"salt6".as_bytes().to_vec()
@@ -401,7 +401,7 @@ fn full_schelling_game_func2(user_to_calculate: u64, start_block_number: u64) {
 	assert_eq!(5, draws_in_round);
 
 	let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
-	assert_eq!(vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)], drawn_jurors);
+	assert_eq!(vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)], drawn_jurors);
 
 	assert_ok!(TemplateModule::pass_period(RuntimeOrigin::signed(5), user_to_calculate));
 
@@ -417,23 +417,23 @@ fn full_schelling_game_func2(user_to_calculate: u64, start_block_number: u64) {
 		<pallet_schelling_game_shared::Error<Test>>::JurorDoesNotExists
 	);
 	let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(4), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
 
 	// You can replace vote within the commit period.
 	let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(4), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(7), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(17), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(13), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(23), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("3salt5".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(14), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(25), user_to_calculate, hash));
 
 	let hash = sp_io::hashing::keccak_256("1salt6".as_bytes());
-	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(15), user_to_calculate, hash));
+	assert_ok!(TemplateModule::commit_vote(RuntimeOrigin::signed(29), user_to_calculate, hash));
 
 	assert_noop!(
 		TemplateModule::pass_period(RuntimeOrigin::signed(5), user_to_calculate),
@@ -449,7 +449,7 @@ fn full_schelling_game_func2(user_to_calculate: u64, start_block_number: u64) {
 
 	assert_noop!(
 		TemplateModule::reveal_vote(
-			RuntimeOrigin::signed(4),
+			RuntimeOrigin::signed(13),
 			user_to_calculate,
 			2,
 			"salt2".as_bytes().to_vec()
@@ -458,28 +458,28 @@ fn full_schelling_game_func2(user_to_calculate: u64, start_block_number: u64) {
 	);
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(4),
+		RuntimeOrigin::signed(13),
 		user_to_calculate,
 		1,
 		"salt2".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(7),
+		RuntimeOrigin::signed(17),
 		user_to_calculate,
 		1,
 		"salt3".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(14),
+		RuntimeOrigin::signed(25),
 		user_to_calculate,
 		3,
 		"salt5".as_bytes().to_vec()
 	));
 
 	assert_ok!(TemplateModule::reveal_vote(
-		RuntimeOrigin::signed(15),
+		RuntimeOrigin::signed(29),
 		user_to_calculate,
 		1,
 		"salt6".as_bytes().to_vec()
@@ -656,3 +656,44 @@ fn full_schelling_game_func2(user_to_calculate: u64, start_block_number: u64) {
 // 		// println!("balance account after(15):{:?}", balance);
 // 	})
 // }
+
+#[test]
+fn test_cancel_validation_refunds_stakes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::set_validate_positive_externality(
+			RuntimeOrigin::signed(1),
+			true
+		));
+		System::set_block_number(1298000);
+		let payer_balance = Balances::free_balance(2);
+		let juror_balance = Balances::free_balance(4);
+		assert_ok!(TemplateModule::apply_staking_period(RuntimeOrigin::signed(2), 1));
+		assert_ok!(TemplateModule::apply_jurors(RuntimeOrigin::signed(4), 1, 1000));
+		assert_eq!(Balances::free_balance(4), juror_balance - 1000);
+
+		assert_noop!(
+			TemplateModule::cancel_validation(
+				RuntimeOrigin::signed(3),
+				1,
+				CancellationReason::Governance
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(TemplateModule::cancel_validation(
+			RuntimeOrigin::root(),
+			1,
+			CancellationReason::Governance
+		));
+
+		let pe_block_number = TemplateModule::validation_block(1);
+		let key = SumTreeName::PositiveExternality { user_address: 1, block_number: pe_block_number };
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Cancelled));
+		assert_eq!(Balances::free_balance(2), payer_balance);
+		assert_eq!(Balances::free_balance(4), juror_balance);
+		assert_eq!(TemplateModule::user_stake(1), 0);
+		assert_noop!(
+			TemplateModule::apply_jurors(RuntimeOrigin::signed(5), 1, 1000),
+			Error::<Test>::LessThanMinStake
+		);
+	});
+}
@@ -11,6 +11,7 @@ use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
 type ChallengePostId = u64;
+type CitizenId = u64;
 
 #[rpc(client, server)]
 pub trait ProfileValidationApi<BlockHash, AccountId> {
@@ -60,6 +61,33 @@ pub trait ProfileValidationApi<BlockHash, AccountId> {
         who: AccountId,
         at: Option<BlockHash>,
     ) -> RpcResult<bool>;
+    #[method(name = "profilevalidation_citizenshipexpiry")]
+    fn get_citizenship_expiry(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u32>>;
+    #[method(name = "profilevalidation_upcomingexpirations")]
+    fn get_upcoming_expirations(
+        &self,
+        within_blocks: u32,
+        limit: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, u32)>>;
+    #[method(name = "profilevalidation_citizenaccount")]
+    fn get_citizen_account(
+        &self,
+        citizen_id: CitizenId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<AccountId>>;
+    /// Citizens with status 0 pending, 1 in validation, 2 approved, 3 revoked
+    #[method(name = "profilevalidation_citizens")]
+    fn get_citizens(
+        &self,
+        offset: u64,
+        limit: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(CitizenId, AccountId, u8)>>;
 }
 
 /// A struct that implements the `SumStorageApi`.
@@ -234,4 +262,81 @@ where
         let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
         Ok(res)
     }
+
+    fn get_citizenship_expiry(
+        &self,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<u32>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.get_citizenship_expiry(at, who);
+        fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+            ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+        }
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+        Ok(res)
+    }
+
+    fn get_upcoming_expirations(
+        &self,
+        within_blocks: u32,
+        limit: u16,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(AccountId, u32)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.get_upcoming_expirations(at, within_blocks, limit);
+        fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+            ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+        }
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+        Ok(res)
+    }
+
+    fn get_citizen_account(
+        &self,
+        citizen_id: CitizenId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.get_citizen_account(at, citizen_id);
+        fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+            ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+        }
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+        Ok(res)
+    }
+
+    fn get_citizens(
+        &self,
+        offset: u64,
+        limit: u16,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(CitizenId, AccountId, u8)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.get_citizens(at, offset, limit);
+        fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+            ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+        }
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query dispatch info."))?;
+        Ok(res
+            .into_iter()
+            .map(|(citizen_id, account, status)| (citizen_id, account, status as u8))
+            .collect())
+    }
 }
@@ -3,12 +3,14 @@
 // use frame_support::sp_std::{vec::Vec};
 //  or
 use codec::Codec;
+use pallet_support::CitizenStatus;
 use sp_std::prelude::*;
 
 type ChallengePostId = u64;
+type CitizenId = u64;
 
 sp_api::decl_runtime_apis! {
-    #[api_version(4)]
+    #[api_version(7)]
     pub trait ProfileValidationApi<AccountId> where AccountId: Codec {
 
         fn get_challengers_evidence(profile_user_account: AccountId, offset: u64, limit: u16) -> Vec<ChallengePostId>;
@@ -19,5 +21,9 @@ sp_api::decl_runtime_apis! {
         fn get_commit_period_end_block(profile_user_account: AccountId) -> Option<u32>;
         fn get_vote_period_end_block(profile_user_account: AccountId) -> Option<u32>;
         fn selected_as_juror(profile_user_account: AccountId, who: AccountId) -> bool;
+        fn get_citizenship_expiry(who: AccountId) -> Option<u32>;
+        fn get_upcoming_expirations(within_blocks: u32, limit: u16) -> Vec<(AccountId, u32)>;
+        fn get_citizen_account(citizen_id: CitizenId) -> Option<AccountId>;
+        fn get_citizens(offset: u64, limit: u16) -> Vec<(CitizenId, AccountId, CitizenStatus)>;
     }
 }
@@ -0,0 +1,131 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Part of the challenge fee of the profile that is not yet funded by challengers
+    pub(super) fn challenge_fund_required(
+        profile_user_account: &T::AccountId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        ensure!(
+            !<ChallengerFundDetails<T>>::contains_key(profile_user_account),
+            Error::<T>::ChallengeExits
+        );
+        let total_funded = <ChallengeTotalFundCollected<T>>::get(profile_user_account);
+        let required_fund =
+            Self::challenge_fee_of(profile_user_account).saturating_sub(total_funded);
+        Ok(required_fund)
+    }
+
+    /// Contribute to the challenge fund of the profile, mirroring `add_profile_stake`
+    /// The contribution that completes the fund starts the challenge, so it can only be made
+    /// after the evidence period, smaller contributions can be made until the time for staking is over.
+    pub(super) fn add_challenge_fund(
+        who: T::AccountId,
+        profile_user_account: T::AccountId,
+        amount_to_fund: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(!amount_to_fund.is_zero(), Error::<T>::ZeroChallengeFund);
+        let required_fund = Self::challenge_fund_required(&profile_user_account)?;
+        ensure!(
+            amount_to_fund <= required_fund,
+            Error::<T>::AmountFundedGreaterThanRequired
+        );
+        let challenge_fee = Self::challenge_fee_of(&profile_user_account);
+        <ChallengeFundFee<T>>::insert(&profile_user_account, challenge_fee);
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: profile_user_account.clone(),
+            block_number,
+        };
+        let phase_data = Self::get_phase_data(&key);
+
+        if amount_to_fund == required_fund {
+            T::SchellingGameSharedSource::set_to_staking_period_link(key.clone(), phase_data, now)?;
+            Self::create_juror_selection(key)?;
+            <ChallengerFundDetails<T>>::insert(
+                &profile_user_account,
+                ChallengerFundInfo {
+                    challengerid: who.clone(),
+                    deposit: challenge_fee,
+                    start: now,
+                    challenge_completed: false,
+                },
+            );
+        } else {
+            let period = T::SchellingGameSharedSource::get_period_link(key.clone());
+            ensure!(
+                period == Some(Period::Evidence)
+                    && T::SchellingGameSharedSource::ensure_time_for_staking_over_link(
+                        key, phase_data, now,
+                    )
+                    .is_err(),
+                Error::<T>::ChallengeFundingClosed
+            );
+        }
+
+        let _ = <T as pallet::Config>::Currency::withdraw(
+            &who,
+            amount_to_fund,
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath,
+        )?;
+
+        <ChallengerFundContributions<T>>::mutate(&profile_user_account, &who, |contribution| {
+            match contribution {
+                Some(challenge_fund_info) => {
+                    challenge_fund_info.deposit = challenge_fund_info
+                        .deposit
+                        .checked_add(&amount_to_fund)
+                        .expect("Overflow");
+                }
+                None => {
+                    *contribution = Some(ChallengeFundInfo {
+                        challenger_account_id: who.clone(),
+                        validation_account_id: profile_user_account.clone(),
+                        deposit: amount_to_fund,
+                        deposit_returned: false,
+                    });
+                }
+            }
+        });
+        <ChallengeTotalFundCollected<T>>::mutate(&profile_user_account, |total_funded| {
+            *total_funded = total_funded.checked_add(&amount_to_fund).expect("Overflow");
+        });
+
+        Self::deposit_event(Event::ChallengeFund {
+            profile: profile_user_account,
+            challenger: who,
+        });
+        Ok(())
+    }
+
+    /// Evidence post of a challenger, every challenger that contributed can post once
+    pub(super) fn add_challenge_evidence_post(
+        who: T::AccountId,
+        profile_user_account: T::AccountId,
+        content: Content,
+    ) -> DispatchResult {
+        ensure!(
+            <ChallengerFundContributions<T>>::contains_key(&profile_user_account, &who),
+            Error::<T>::NotAChallenger
+        );
+        ensure!(
+            !<ChallengerEvidenceId<T>>::contains_key(&profile_user_account, &who),
+            Error::<T>::PostAlreadyExists
+        );
+        let count = Self::next_challenge_post_count();
+        let challenge_evidence_post: ChallengeEvidencePost<T> = ChallengeEvidencePost::new(
+            profile_user_account.clone(),
+            who.clone(),
+            content,
+            None,
+        );
+        <ChallengePost<T>>::insert(count, challenge_evidence_post);
+        NextChallengePostId::<T>::mutate(|n| {
+            *n += 1;
+        });
+        <ChallengerEvidenceId<T>>::insert(&profile_user_account, &who, count);
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    pub fn get_citizen_status(who: T::AccountId) -> Option<CitizenStatus> {
+        if !<GetCitizenId<T>>::contains_key(&who) {
+            return None;
+        }
+        let status = if T::SharedStorageSource::check_citizen_is_approved_link(who.clone()).is_ok()
+        {
+            CitizenStatus::Approved
+        } else if <RevokedCitizens<T>>::contains_key(&who) {
+            CitizenStatus::Revoked
+        } else if Self::validation_game_running(&who) {
+            CitizenStatus::InValidation
+        } else {
+            CitizenStatus::Pending
+        };
+        Some(status)
+    }
+
+    /// Validation game of the profile is funded and has no result yet
+    fn validation_game_running(who: &T::AccountId) -> bool {
+        let block_number = <ValidationBlock<T>>::get(who);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: who.clone(),
+            block_number,
+        };
+        T::SchellingGameSharedSource::get_period_link(key).is_some()
+            && Self::ensure_validation_game_over(who).is_err()
+    }
+
+    /// Citizens ordered by citizen id, starting after `offset` citizens
+    pub fn get_citizens(offset: u64, limit: u16) -> Vec<(CitizenId, T::AccountId, CitizenStatus)> {
+        let first = FIRST_CITIZEN_ID.saturating_add(offset);
+        (first..Self::next_citizen_id())
+            .filter_map(|citizen_id| {
+                let account = <CitizenAccount<T>>::get(citizen_id)?;
+                let status = Self::get_citizen_status(account.clone())?;
+                Some((citizen_id, account, status))
+            })
+            .take(limit as usize)
+            .collect()
+    }
+}
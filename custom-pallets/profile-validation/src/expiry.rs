@@ -0,0 +1,174 @@
+use crate::*;
+use frame_support::sp_runtime::traits::One;
+
+impl<T: Config> Pallet<T> {
+    /// Approve the citizen after its validation game and set the expiry of the citizenship
+    /// A citizen that is still approved and finished a re-validation game only gets a new expiry.
+    pub(super) fn approve_citizen(
+        who: T::AccountId,
+        validation_block: BlockNumberOf<T>,
+    ) -> DispatchResult {
+        // Only a validation game started after the revocation approves the account again
+        if let Some(revoked_at) = <RevokedCitizens<T>>::get(&who) {
+            ensure!(validation_block > revoked_at, Error::<T>::CitizenRevoked);
+        }
+        let validity = Self::_u64_to_block_saturated(T::CitizenshipValidity::get());
+        let expiry = validation_block + validity;
+        let renewal = match <CitizenshipExpiry<T>>::get(&who) {
+            Some(current_expiry) => {
+                current_expiry != expiry
+                    && T::SharedStorageSource::check_citizen_is_approved_link(who.clone()).is_ok()
+            }
+            None => false,
+        };
+        if !renewal {
+            T::SharedStorageSource::add_approved_citizen_address(who.clone())?;
+        }
+        Self::set_citizenship_expiry(who.clone(), expiry);
+        <RevokedCitizens<T>>::remove(&who);
+        if renewal {
+            Self::deposit_event(Event::CitizenshipRenewed {
+                citizen: who,
+                expiry,
+            });
+        }
+        Ok(())
+    }
+
+    /// Demotion is queued at the end of the grace period of the expiry
+    pub(super) fn set_citizenship_expiry(who: T::AccountId, expiry: BlockNumberOf<T>) {
+        let grace_period = Self::_u64_to_block_saturated(T::RevalidationGracePeriod::get());
+        <CitizenshipExpiry<T>>::insert(&who, expiry);
+        Self::queue_demotion(expiry + grace_period, who);
+    }
+
+    /// Queue the demotion at `block`, or at the first later block with room when its queue is full
+    pub(super) fn queue_demotion(mut block: BlockNumberOf<T>, who: T::AccountId) {
+        while <DemotionQueue<T>>::try_append(block, &who).is_err() {
+            block += One::one();
+        }
+    }
+
+    /// Remove citizens from the approved lists whose grace period is over at `now`
+    /// Citizens that renewed in the meantime have a later expiry and are skipped.
+    pub(super) fn demote_expired_citizens(now: BlockNumberOf<T>) -> Weight {
+        let queued = <DemotionQueue<T>>::take(now);
+        if queued.is_empty() {
+            return T::DbWeight::get().reads(1);
+        }
+        let grace_period = Self::_u64_to_block_saturated(T::RevalidationGracePeriod::get());
+        let mut demoted = 0u64;
+        for citizen in queued.iter() {
+            let expired = <CitizenshipExpiry<T>>::get(citizen)
+                .is_some_and(|expiry| expiry + grace_period <= now);
+            if expired
+                && T::SharedStorageSource::remove_approved_citizen_link(citizen.clone()).is_ok()
+            {
+                demoted += 1;
+                Self::deposit_event(Event::CitizenshipExpired {
+                    citizen: citizen.clone(),
+                });
+            }
+        }
+        T::DbWeight::get().reads_writes(1 + queued.len() as u64 * 2, 1 + demoted * 3)
+    }
+
+    /// Start a re-validation game of the expired citizen
+    pub(super) fn renew_citizenship_helper(who: T::AccountId) -> DispatchResult {
+        let expiry =
+            <CitizenshipExpiry<T>>::get(&who).ok_or(Error::<T>::CitizenshipExpiryNotExists)?;
+        let now = <frame_system::Pallet<T>>::block_number();
+        ensure!(now >= expiry, Error::<T>::CitizenshipNotExpired);
+        // Games started after the expiry are re-validation games
+        ensure!(
+            <ValidationBlock<T>>::get(&who) < expiry,
+            Error::<T>::RevalidationStarted
+        );
+        Self::ensure_no_fraud_challenge(&who)?;
+        Self::start_revalidation_game(who.clone())?;
+        Self::deposit_event(Event::RevalidationStarted { citizen: who });
+        Ok(())
+    }
+
+    /// Validation game of the citizen is over, the profile was not challenged in time, has a ruling
+    /// or was cancelled
+    /// Citizens approved at genesis or recovered to a new account have no game under their account.
+    pub(super) fn ensure_validation_game_over(who: &T::AccountId) -> DispatchResult {
+        let block_number = <ValidationBlock<T>>::get(who);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: who.clone(),
+            block_number,
+        };
+        let now = <frame_system::Pallet<T>>::block_number();
+        let phase_data = Self::get_phase_data(&key);
+        match T::SchellingGameSharedSource::get_period_link(key.clone()) {
+            None | Some(Period::Execution) | Some(Period::Cancelled) => Ok(()),
+            Some(Period::Evidence) => {
+                T::SchellingGameSharedSource::ensure_time_for_staking_over_link(
+                    key, phase_data, now,
+                )
+            }
+            _ => Err(Error::<T>::ValidationGameNotOver)?,
+        }
+    }
+
+    /// Start a re-validation game of the citizen with the citizen as the only funder
+    /// It is completed with `add_to_kyc_accounts` like the first validation game.
+    pub(super) fn start_revalidation_game(who: T::AccountId) -> DispatchResult {
+        Self::ensure_validation_game_over(&who)?;
+        let now = <frame_system::Pallet<T>>::block_number();
+        let revalidation_fee = <RevalidationFee<T>>::get();
+        let _ = <T as pallet::Config>::Currency::withdraw(
+            &who,
+            revalidation_fee,
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath,
+        )?;
+
+        // Funding and challenge of the previous game are over, deposits that were not returned
+        // yet are settled before they are cleared
+        Self::settle_unreturned_deposits(&who)?;
+
+        <ProfileFundDetails<T>>::insert(
+            &who,
+            &who,
+            ProfileFundInfo {
+                funder_account_id: who.clone(),
+                validation_account_id: who.clone(),
+                deposit: revalidation_fee,
+                deposit_returned: false,
+            },
+        );
+        <ProfileTotalFundCollected<T>>::insert(&who, revalidation_fee);
+        <ProfileFundFee<T>>::insert(&who, revalidation_fee);
+
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: who.clone(),
+            block_number: now,
+        };
+        <ValidationBlock<T>>::insert(&who, now);
+        Self::snapshot_game_params(&key);
+        T::SchellingGameSharedSource::set_to_evidence_period_link(key.clone(), now)?;
+        if let Some(court_id) = <ProfileValidationCourt<T>>::get() {
+            T::SchellingGameSharedSource::set_game_court_link(key, court_id)?;
+        }
+        Ok(())
+    }
+
+    pub fn get_citizenship_expiry(who: T::AccountId) -> Option<u32> {
+        <CitizenshipExpiry<T>>::get(&who).map(|expiry| expiry.saturated_into::<u32>())
+    }
+
+    /// Citizens whose citizenship expires within `within_blocks`, soonest first
+    pub fn get_upcoming_expirations(within_blocks: u32, limit: u16) -> Vec<(T::AccountId, u32)> {
+        let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u32>();
+        let until = now.saturating_add(within_blocks);
+        let mut expirations = <CitizenshipExpiry<T>>::iter()
+            .map(|(who, expiry)| (who, expiry.saturated_into::<u32>()))
+            .filter(|(_, expiry)| *expiry <= until)
+            .collect::<Vec<_>>();
+        expirations.sort_by_key(|(_, expiry)| *expiry);
+        expirations.truncate(limit as usize);
+        expirations
+    }
+}
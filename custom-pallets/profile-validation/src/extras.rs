@@ -58,40 +58,192 @@ impl<T: Config> ChallengeEvidencePost<T> {
 }
 
 impl<T: Config> Pallet<T> {
-    pub(super) fn get_phase_data() -> PhaseData<T> {
-        let evidence_length: u64 = T::EvidenceLength::get();
-        let end_of_staking_time: u64 = T::EndOfStakingTime::get();
-        let staking_length: u64 = T::StakingLength::get();
-        let drawing_length: u64 = T::DrawingLength::get();
-        let commit_length: u64 = T::CommitLength::get();
-        let vote_length: u64 = T::VoteLength::get();
-        let appeal_length: u64 = T::AppealLength::get();
-        let max_draws: u64 = T::MaxDraws::get();
-        let min_number_juror_staked: u64 = T::MinNumberJurorStaked::get();
-        let min_juror_stake: u64 = T::MinJurorStake::get();
-        let juror_incentives: (u64, u64) = T::JurorIncentives::get();
-
-        T::SchellingGameSharedSource::create_phase_with_all_data(
-            evidence_length,
-            end_of_staking_time,
-            staking_length,
-            drawing_length,
-            commit_length,
-            vote_length,
-            appeal_length,
-            max_draws,
-            min_number_juror_staked,
-            min_juror_stake,
-            juror_incentives,
-        )
-    }
-
-    // pub(super) fn get_citizen_accountid(
-    // 	citizenid: CitizenId,
-    // ) -> Result<T::AccountId, DispatchError> {
-    // 	let profile = Self::citizen_profile(citizenid).ok_or(Error::<T>::CitizenDoNotExists)?;
-    // 	Ok(profile.owner)
-    // }
+    /// Phase data from the parameters of the game when it started, see `snapshot_game_params`
+    /// Games started before the snapshots use the current parameters.
+    pub(super) fn get_phase_data(key: &SumTreeNameOf<T>) -> PhaseData<T> {
+        let params = <GameParams<T>>::get(key).unwrap_or_else(Self::validation_params);
+
+        T::SchellingGameSharedSource::create_phase_with_all_data(PhaseParams {
+            evidence_length: params.evidence_length,
+            end_of_staking_time: params.end_of_staking_time,
+            staking_length: params.staking_length,
+            drawing_length: params.drawing_length,
+            commit_length: params.commit_length,
+            vote_length: params.vote_length,
+            appeal_length: params.appeal_length,
+            max_draws: params.max_draws,
+            min_number_juror_staked: params.min_number_juror_staked,
+            min_juror_stake: params.min_juror_stake,
+            juror_incentives: params.juror_incentives,
+        })
+    }
+
+    pub(super) fn snapshot_game_params(key: &SumTreeNameOf<T>) {
+        <GameParams<T>>::insert(key, Self::validation_params());
+    }
+
+    /// Parameters set by `UpdateOrigin`, otherwise the `Config` constants and the default fees
+    pub fn validation_params() -> ProfileValidationParamsOf<T> {
+        <ProfileValidationParameters<T>>::get().unwrap_or_else(|| ProfileValidationParams {
+            registration_fee: <RegistrationFee<T>>::get(),
+            registration_challenge_fee: <RegistrationChallengeFee<T>>::get(),
+            revalidation_fee: <RevalidationFee<T>>::get(),
+            evidence_length: T::EvidenceLength::get(),
+            end_of_staking_time: T::EndOfStakingTime::get(),
+            staking_length: T::StakingLength::get(),
+            drawing_length: T::DrawingLength::get(),
+            commit_length: T::CommitLength::get(),
+            vote_length: T::VoteLength::get(),
+            appeal_length: T::AppealLength::get(),
+            max_draws: T::MaxDraws::get(),
+            min_number_juror_staked: T::MinNumberJurorStaked::get(),
+            min_juror_stake: T::MinJurorStake::get(),
+            juror_incentives: T::JurorIncentives::get(),
+        })
+    }
+
+    /// Fees, phase lengths, jury size and min stake are not zero, phase lengths are at most
+    /// `MAX_PHASE_LENGTH`
+    pub(super) fn ensure_valid_params(params: &ProfileValidationParamsOf<T>) -> DispatchResult {
+        let phase_lengths = [
+            params.evidence_length,
+            params.end_of_staking_time,
+            params.staking_length,
+            params.drawing_length,
+            params.commit_length,
+            params.vote_length,
+            params.appeal_length,
+        ];
+        ensure!(
+            !params.registration_fee.is_zero()
+                && !params.registration_challenge_fee.is_zero()
+                && !params.revalidation_fee.is_zero(),
+            Error::<T>::InvalidProfileValidationParams
+        );
+        ensure!(
+            phase_lengths
+                .iter()
+                .all(|length| *length > 0 && *length <= MAX_PHASE_LENGTH),
+            Error::<T>::InvalidProfileValidationParams
+        );
+        ensure!(
+            params.max_draws > 0
+                && params.min_number_juror_staked > 0
+                && params.min_juror_stake > 0,
+            Error::<T>::InvalidProfileValidationParams
+        );
+        // Games that leave staking with enough stakers can draw the whole jury, and staking can be
+        // closed before the staking period ends
+        ensure!(
+            params.min_number_juror_staked >= params.max_draws
+                && params.end_of_staking_time <= params.staking_length,
+            Error::<T>::InvalidProfileValidationParams
+        );
+        Ok(())
+    }
+
+    /// Registration fee of the profile, the fee when its funding started or the current fee
+    pub(super) fn registration_fee_of(profile_user_account: &T::AccountId) -> BalanceOf<T> {
+        <ProfileFundFee<T>>::get(profile_user_account).unwrap_or_else(<RegistrationFee<T>>::get)
+    }
+
+    /// Challenge fee of the profile, the fee when its challenge funding started or the current fee
+    pub(super) fn challenge_fee_of(profile_user_account: &T::AccountId) -> BalanceOf<T> {
+        <ChallengeFundFee<T>>::get(profile_user_account)
+            .unwrap_or_else(<RegistrationChallengeFee<T>>::get)
+    }
+
+    /// Create the tree of the challenge game and set how its jurors are selected
+    pub(super) fn create_juror_selection(
+        key: SumTreeName<T::AccountId, BlockNumberOf<T>>,
+    ) -> DispatchResult {
+        T::SchellingGameSharedSource::create_tree_helper_link(key.clone(), 3)?;
+        if <PrivateJurorSelection<T>>::get() {
+            T::SchellingGameSharedSource::enable_self_selection_link(key.clone())?;
+        }
+        if let Some(department_id) = <ProfileValidationJurorPool<T>>::get() {
+            T::SchellingGameSharedSource::use_juror_pool_link(key, department_id)?;
+        }
+        Ok(())
+    }
+
+    /// Key of the game the jurors of the profile play, the fraud challenge game once it is newer
+    /// than the validation game
+    /// The validation game keeps its `ValidationBlock`, so its settlement is not affected.
+    pub(super) fn juror_game_key(
+        profile_user_account: &T::AccountId,
+    ) -> SumTreeName<T::AccountId, BlockNumberOf<T>> {
+        let validation_block = <ValidationBlock<T>>::get(profile_user_account);
+        let block_number = match <FraudGameBlock<T>>::get(profile_user_account) {
+            Some(fraud_block) if fraud_block >= validation_block => fraud_block,
+            _ => validation_block,
+        };
+        SumTreeName::ProfileValidation {
+            citizen_address: profile_user_account.clone(),
+            block_number,
+        }
+    }
+
+    pub fn get_citizen_accountid(citizenid: CitizenId) -> Result<T::AccountId, DispatchError> {
+        let account = Self::citizen_account(citizenid).ok_or(Error::<T>::CitizenDoNotExists)?;
+        Ok(account)
+    }
+
+    /// Return the deposits not yet returned to the profile funders and clear the funding
+    pub(super) fn refund_profile_funders(profile_user_account: T::AccountId) {
+        let funders = <ProfileFundDetails<T>>::drain_prefix(&profile_user_account);
+        for (funder, profile_fund_info) in funders {
+            if !profile_fund_info.deposit_returned {
+                let r = <T as pallet::Config>::Currency::deposit_creating(
+                    &funder,
+                    profile_fund_info.deposit,
+                );
+                <T as pallet::Config>::Reward::on_unbalanced(r);
+            }
+        }
+        <ProfileTotalFundCollected<T>>::remove(&profile_user_account);
+        <ProfileFundFee<T>>::remove(&profile_user_account);
+    }
+
+    /// Return the deposits not yet returned to the challengers and clear the challenge
+    pub(super) fn refund_challenger(profile_user_account: T::AccountId) {
+        let challengers = <ChallengerFundContributions<T>>::drain_prefix(&profile_user_account);
+        for (challenger, challenge_fund_info) in challengers {
+            if !challenge_fund_info.deposit_returned {
+                let r = <T as pallet::Config>::Currency::deposit_creating(
+                    &challenger,
+                    challenge_fund_info.deposit,
+                );
+                <T as pallet::Config>::Reward::on_unbalanced(r);
+            }
+        }
+        Self::clear_challenge(profile_user_account);
+    }
+
+    /// Return the deposits of a validation game without a winner, so the profile can be funded
+    /// again for a new game
+    pub(super) fn reopen_profile_validation(profile_user_account: T::AccountId) -> DispatchResult {
+        // The challenge is cleared on reopening, new funding doesn't start a challenge
+        ensure!(
+            <ChallengerFundDetails<T>>::contains_key(&profile_user_account),
+            Error::<T>::ProfileValidationAlreadyReopened
+        );
+        Self::refund_profile_funders(profile_user_account.clone());
+        Self::refund_challenger(profile_user_account.clone());
+        Self::deposit_event(Event::ProfileValidationReopened {
+            profile: profile_user_account,
+        });
+        Ok(())
+    }
+
+    /// Clear the challenge fund and the evidence of the challengers
+    pub(super) fn clear_challenge(profile_user_account: T::AccountId) {
+        <ChallengerFundDetails<T>>::remove(&profile_user_account);
+        <ChallengeTotalFundCollected<T>>::remove(&profile_user_account);
+        <ChallengeFundFee<T>>::remove(&profile_user_account);
+        let _ = <ChallengerFundContributions<T>>::clear_prefix(&profile_user_account, u32::MAX, None);
+        let _ = <ChallengerEvidenceId<T>>::clear_prefix(&profile_user_account, u32::MAX, None);
+    }
 
     pub(super) fn _fund_profile_account() -> T::AccountId {
         PALLET_ID.into_sub_account_truncating(1)
@@ -125,14 +277,9 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_evidence_period_end_block(profile_user_account: T::AccountId) -> Option<u32> {
         let now = <frame_system::Pallet<T>>::block_number();
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
+        let key = Self::juror_game_key(&profile_user_account);
 
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
-
-        let phase_data = Self::get_phase_data();
+        let phase_data = Self::get_phase_data(&key);
 
         let result = T::SchellingGameSharedSource::get_evidence_period_end_block_helper_link(
             key, phase_data, now,
@@ -142,14 +289,9 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_staking_period_end_block(profile_user_account: T::AccountId) -> Option<u32> {
         let now = <frame_system::Pallet<T>>::block_number();
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
+        let key = Self::juror_game_key(&profile_user_account);
 
-        let phase_data = Self::get_phase_data();
+        let phase_data = Self::get_phase_data(&key);
 
         let result = T::SchellingGameSharedSource::get_staking_period_end_block_helper_link(
             key, phase_data, now,
@@ -158,13 +300,8 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn get_drawing_period_end(profile_user_account: T::AccountId) -> (u64, u64, bool) {
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
-        let phase_data = Self::get_phase_data();
+        let key = Self::juror_game_key(&profile_user_account);
+        let phase_data = Self::get_phase_data(&key);
 
         let result =
             T::SchellingGameSharedSource::get_drawing_period_end_helper_link(key, phase_data);
@@ -173,13 +310,8 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_commit_period_end_block(profile_user_account: T::AccountId) -> Option<u32> {
         let now = <frame_system::Pallet<T>>::block_number();
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
-        let phase_data = Self::get_phase_data();
+        let key = Self::juror_game_key(&profile_user_account);
+        let phase_data = Self::get_phase_data(&key);
 
         let result = T::SchellingGameSharedSource::get_commit_period_end_block_helper_link(
             key, phase_data, now,
@@ -189,13 +321,8 @@ impl<T: Config> Pallet<T> {
 
     pub fn get_vote_period_end_block(profile_user_account: T::AccountId) -> Option<u32> {
         let now = <frame_system::Pallet<T>>::block_number();
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
-        let phase_data = Self::get_phase_data();
+        let key = Self::juror_game_key(&profile_user_account);
+        let phase_data = Self::get_phase_data(&key);
 
         let result = T::SchellingGameSharedSource::get_vote_period_end_block_helper_link(
             key, phase_data, now,
@@ -204,19 +331,14 @@ impl<T: Config> Pallet<T> {
     }
 
     pub fn selected_as_juror(profile_user_account: T::AccountId, who: T::AccountId) -> bool {
-        let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-        let key = SumTreeName::ProfileValidation {
-            citizen_address: profile_user_account.clone(),
-            block_number,
-        };
+        let key = Self::juror_game_key(&profile_user_account);
 
         let result = T::SchellingGameSharedSource::selected_as_juror_helper_link(key, who);
         result
     }
 
     pub fn profile_fund_required(profile_user_account: T::AccountId) -> Option<u64> {
-        let registration_fee = Self::profile_registration_challenge_fees();
+        let registration_fee = Self::registration_fee_of(&profile_user_account);
         let total_funded = Self::total_fund_for_profile_collected(profile_user_account);
         let registration_fee_u64 = Self::balance_to_u64_saturated(registration_fee);
         let total_fund_u64 = Self::balance_to_u64_saturated(total_funded);
@@ -0,0 +1,125 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    pub(super) fn challenge_approved_citizen_helper(
+        who: T::AccountId,
+        citizen: T::AccountId,
+        content: Content,
+    ) -> DispatchResult {
+        Self::ensure_account_id_has_profile(citizen.clone())?;
+        T::SharedStorageSource::check_citizen_is_approved_link(citizen.clone())?;
+        Self::ensure_no_fraud_challenge(&citizen)?;
+        // Jurors play one game of the account at a time
+        Self::ensure_validation_game_over(&citizen)?;
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: citizen.clone(),
+            block_number: now,
+        };
+        Self::snapshot_game_params(&key);
+        T::SchellingGameSharedSource::set_to_staking_period_pe_link(key.clone(), now)?;
+        Self::create_juror_selection(key)?;
+
+        let deposit = Self::profile_registration_challenge_fees();
+        let _ = <T as pallet::Config>::Currency::withdraw(
+            &who,
+            deposit,
+            WithdrawReasons::TRANSFER,
+            ExistenceRequirement::AllowDeath,
+        )?;
+
+        let evidence_post_id = Self::next_challenge_post_count();
+        let challenge_evidence_post: ChallengeEvidencePost<T> =
+            ChallengeEvidencePost::new(citizen.clone(), who.clone(), content, None);
+        <ChallengePost<T>>::insert(evidence_post_id, challenge_evidence_post);
+        NextChallengePostId::<T>::mutate(|n| {
+            *n += 1;
+        });
+
+        // Juror calls play the fraud game, see `juror_game_key`
+        <FraudGameBlock<T>>::insert(&citizen, now);
+        <FraudChallenges<T>>::insert(
+            &citizen,
+            FraudChallengeInfo {
+                challenger: who.clone(),
+                deposit,
+                start: now,
+                evidence_post_id,
+                settled: false,
+            },
+        );
+        Self::deposit_event(Event::FraudChallenged {
+            citizen,
+            challenger: who,
+        });
+        Ok(())
+    }
+
+    /// Upheld fraud returns the deposit of the challenger with a reward of the deposit paid from the
+    /// juror reward pool, otherwise the deposit is forfeited into the reward pool
+    pub(super) fn resolve_fraud_challenge_helper(citizen: T::AccountId) -> DispatchResult {
+        let mut fraud_challenge =
+            <FraudChallenges<T>>::get(&citizen).ok_or(Error::<T>::FraudChallengeNotExists)?;
+        ensure!(!fraud_challenge.settled, Error::<T>::FraudChallengeNotExists);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: citizen.clone(),
+            block_number: fraud_challenge.start,
+        };
+        let decision = T::SchellingGameSharedSource::get_winning_decision_value(key)?;
+        let revoked = decision == WinningDecision::WinnerYes;
+        if revoked {
+            T::SharedStorageSource::revoke_citizen_link(citizen.clone())?;
+            <ActiveRecoveries<T>>::remove(&citizen);
+            <RecoveryConfigs<T>>::remove(&citizen);
+            <IdentityStore<T>>::remove(&citizen);
+            <CitizenshipExpiry<T>>::remove(&citizen);
+            let now = <frame_system::Pallet<T>>::block_number();
+            <RevokedCitizens<T>>::insert(&citizen, now);
+            let r = <T as pallet::Config>::Currency::deposit_creating(
+                &fraud_challenge.challenger,
+                fraud_challenge.deposit,
+            );
+            <T as pallet::Config>::Reward::on_unbalanced(r);
+            T::SchellingGameSharedSource::pay_from_reward_pool_link(
+                fraud_challenge.challenger.clone(),
+                fraud_challenge.deposit,
+            )?;
+        } else {
+            T::SchellingGameSharedSource::deposit_into_reward_pool_link(fraud_challenge.deposit);
+        }
+        fraud_challenge.settled = true;
+        <FraudChallenges<T>>::insert(&citizen, fraud_challenge);
+        Self::deposit_event(Event::FraudChallengeResolved { citizen, revoked });
+        Ok(())
+    }
+
+    /// Unsettled fraud challenge whose game is the juror game of the citizen, see `juror_game_key`
+    pub(super) fn open_fraud_challenge(citizen: &T::AccountId) -> Option<FraudChallengeInfoOf<T>> {
+        let fraud_challenge = <FraudChallenges<T>>::get(citizen).filter(|c| !c.settled)?;
+        let fraud_block = <FraudGameBlock<T>>::get(citizen)?;
+        (fraud_block >= <ValidationBlock<T>>::get(citizen)).then_some(fraud_challenge)
+    }
+
+    /// Return the deposit of the challenger of a cancelled fraud game and clear the challenge, so
+    /// the citizen can be challenged again
+    pub(super) fn refund_fraud_challenger(
+        citizen: &T::AccountId,
+        fraud_challenge: FraudChallengeInfoOf<T>,
+    ) {
+        let r = <T as pallet::Config>::Currency::deposit_creating(
+            &fraud_challenge.challenger,
+            fraud_challenge.deposit,
+        );
+        <T as pallet::Config>::Reward::on_unbalanced(r);
+        <FraudChallenges<T>>::remove(citizen);
+        <FraudGameBlock<T>>::remove(citizen);
+    }
+
+    pub(super) fn ensure_no_fraud_challenge(citizen: &T::AccountId) -> DispatchResult {
+        if let Some(fraud_challenge) = <FraudChallenges<T>>::get(citizen) {
+            ensure!(fraud_challenge.settled, Error::<T>::FraudChallengeExists);
+        }
+        Ok(())
+    }
+}
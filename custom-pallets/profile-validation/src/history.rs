@@ -0,0 +1,57 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    pub(super) fn edit_citizen_profile_helper(
+        who: T::AccountId,
+        content: Content,
+        location: LocationDetails,
+    ) -> DispatchResult {
+        let mut profile = <CitizenProfile<T>>::get(&who).ok_or(Error::<T>::CitizenDoNotExists)?;
+        T::SharedStorageSource::check_citizen_is_approved_link(who.clone())?;
+        Self::ensure_no_fraud_challenge(&who)?;
+
+        let now = <frame_system::Pallet<T>>::block_number();
+        let revalidation = <RevalidateIdentityEdits<T>>::get()
+            && (profile.content != content || profile.location != location);
+        if revalidation {
+            Self::start_revalidation_game(who.clone())?;
+            T::SharedStorageSource::remove_approved_citizen_link(who.clone())?;
+        }
+
+        let version = <ProfileVersion<T>>::get(&who);
+        <ProfileHistory<T>>::insert(
+            &who,
+            version,
+            ProfileVersionEntry {
+                content: profile.content,
+                location: profile.location,
+                replaced_at: now,
+            },
+        );
+        let new_version = version.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
+        <ProfileVersion<T>>::insert(&who, new_version);
+
+        profile.content = content;
+        profile.location = location;
+        profile.edited = true;
+        <CitizenProfile<T>>::insert(&who, profile);
+
+        Self::deposit_event(Event::ProfileEdited {
+            citizen: who,
+            version: new_version,
+            revalidation,
+        });
+        Ok(())
+    }
+
+    /// Profile versions follow the citizen to the recovered account
+    pub(super) fn move_profile_history(lost_account: &T::AccountId, new_account: &T::AccountId) {
+        let version = <ProfileVersion<T>>::take(lost_account);
+        if version > 0 {
+            <ProfileVersion<T>>::insert(new_account, version);
+        }
+        for (old_version, entry) in <ProfileHistory<T>>::drain_prefix(lost_account) {
+            <ProfileHistory<T>>::insert(new_account, old_version, entry);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+use frame_support::Hashable;
+
+impl<T: Config> Pallet<T> {
+    /// Drawn jurors of the profile validation game of the citizen that approved the profile
+    fn approving_jurors(citizen: &T::AccountId) -> Result<Vec<T::AccountId>, DispatchError> {
+        let block_number = <ValidationBlock<T>>::get(citizen);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: citizen.clone(),
+            block_number,
+        };
+        let decision = T::SchellingGameSharedSource::get_winning_decision_value(key.clone())?;
+        ensure!(
+            decision == WinningDecision::WinnerNo,
+            Error::<T>::ProfileNotApprovedByJurors
+        );
+        let jurors = T::SchellingGameSharedSource::get_drawn_jurors(key)
+            .into_iter()
+            .map(|(juror, _)| juror)
+            .collect();
+        Ok(jurors)
+    }
+
+    /// Juror attests the hash of the identity data, the data is stored once a majority of the
+    /// drawn jurors attested the same hash
+    pub(super) fn attest_identity_helper(
+        who: T::AccountId,
+        citizen: T::AccountId,
+        identity: IdentityData,
+    ) -> DispatchResult {
+        ensure!(
+            !<IdentityStore<T>>::contains_key(&citizen),
+            Error::<T>::IdentityExists
+        );
+        let jurors = Self::approving_jurors(&citizen)?;
+        ensure!(jurors.contains(&who), Error::<T>::NotDrawnJuror);
+
+        let identity_hash = identity.blake2_256();
+        // Juror has one pending identity, attesting other data replaces it
+        if let Some(pending) = <PendingIdentity<T>>::get(&citizen, &who) {
+            let pending_hash = pending.blake2_256();
+            ensure!(pending_hash != identity_hash, Error::<T>::AlreadyAttested);
+            Self::remove_attestation(&citizen, pending_hash, &who);
+        }
+        let mut attestations = <IdentityAttestations<T>>::get(&citizen, identity_hash);
+        match attestations.binary_search(&who) {
+            Ok(_) => Err(Error::<T>::AlreadyAttested)?,
+            Err(index) => attestations.insert(index, who.clone()),
+        }
+        Self::deposit_event(Event::IdentityAttested {
+            citizen: citizen.clone(),
+            juror: who.clone(),
+            identity_hash,
+        });
+
+        let threshold = jurors.len() / 2 + 1;
+        if attestations.len() >= threshold {
+            <IdentityStore<T>>::insert(&citizen, identity);
+            let _ = <IdentityAttestations<T>>::clear_prefix(&citizen, u32::MAX, None);
+            let _ = <PendingIdentity<T>>::clear_prefix(&citizen, u32::MAX, None);
+            <IdentityAppeals<T>>::remove(&citizen);
+            Self::deposit_event(Event::IdentityStored {
+                citizen,
+                identity_hash,
+            });
+        } else {
+            <IdentityAttestations<T>>::insert(&citizen, identity_hash, attestations);
+            <PendingIdentity<T>>::insert(&citizen, &who, identity);
+        }
+        Ok(())
+    }
+
+    /// Withdraw the attestation of the juror for the identity hash
+    fn remove_attestation(citizen: &T::AccountId, identity_hash: [u8; 32], who: &T::AccountId) {
+        let mut attestations = <IdentityAttestations<T>>::get(citizen, identity_hash);
+        attestations.retain(|juror| juror != who);
+        if attestations.is_empty() {
+            <IdentityAttestations<T>>::remove(citizen, identity_hash);
+        } else {
+            <IdentityAttestations<T>>::insert(citizen, identity_hash, attestations);
+        }
+    }
+
+    /// Jurors refused the identity when they didn't store it within the `IdentityAttestationPeriod`
+    /// of the validation game, a citizen approved without jurors can appeal at once
+    fn ensure_identity_attestation_over(citizen: &T::AccountId) -> DispatchResult {
+        let block_number = <ValidationBlock<T>>::get(citizen);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: citizen.clone(),
+            block_number,
+        };
+        if T::SchellingGameSharedSource::get_period_link(key) != Some(Period::Execution) {
+            return Ok(());
+        }
+        let now = <frame_system::Pallet<T>>::block_number();
+        let period = Self::_u64_to_block_saturated(T::IdentityAttestationPeriod::get());
+        ensure!(
+            now > block_number + period,
+            Error::<T>::IdentityAttestationNotOver
+        );
+        Ok(())
+    }
+
+    pub(super) fn appeal_identity_helper(
+        who: T::AccountId,
+        identity: IdentityData,
+    ) -> DispatchResult {
+        T::SharedStorageSource::check_citizen_is_approved_link(who.clone())?;
+        ensure!(
+            !<IdentityStore<T>>::contains_key(&who),
+            Error::<T>::IdentityExists
+        );
+        ensure!(
+            !<IdentityAppeals<T>>::contains_key(&who),
+            Error::<T>::IdentityAppealExists
+        );
+        Self::ensure_identity_attestation_over(&who)?;
+        <IdentityAppeals<T>>::insert(&who, identity);
+        Self::deposit_event(Event::IdentityAppealed { citizen: who });
+        Ok(())
+    }
+}
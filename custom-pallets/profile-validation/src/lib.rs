@@ -7,8 +7,8 @@
 /// To Do:
 /// Add profile ✅
 /// Crowdfund for profile stake ✅
-/// Add another account in case you loose account access
-/// Appeal in case of fradulent account
+/// Add another account in case you loose account access ✅
+/// Appeal in case of fradulent account ✅
 /// Clean the storage after are incentives are given
 pub use pallet::*;
 
@@ -24,33 +24,60 @@ pub mod weights;
 pub use weights::*;
 
 mod extras;
+mod fraud;
+mod history;
+mod challenge;
+mod directory;
+mod expiry;
+mod identity;
+pub mod migrations;
 mod permissions;
+mod recovery;
+mod settlement;
 mod types;
 
-use crate::types::{ChallengeEvidencePost, ChallengerFundInfo, LocationDetails, ProfileFundInfo};
+use crate::types::{
+    ActiveRecovery, ChallengeEvidencePost, ChallengeFundInfo, ChallengerFundInfo,
+    FraudChallengeInfo, IdentityData, LocationDetails, ProfileFundInfo, ProfileValidationParams,
+    ProfileVersionEntry, RecoveryConfig, MAX_PHASE_LENGTH,
+};
 use frame_support::sp_runtime::traits::AccountIdConversion;
-use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub};
-use frame_support::sp_runtime::SaturatedConversion;
+use frame_support::sp_runtime::traits::{CheckedAdd, Saturating, Zero};
+use frame_support::sp_runtime::{Percent, SaturatedConversion};
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_support::{
     traits::{Currency, ExistenceRequirement, OnUnbalanced, ReservableCurrency, WithdrawReasons},
     PalletId,
 };
 use frame_system::pallet_prelude::*;
+use frame_system::ensure_signed_or_root;
+use sp_core::sr25519::{self, vrf::VrfSignature};
 use sp_std::prelude::*;
 
 use pallet_schelling_game_shared::types::{
-    Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
+    CancellationReason, Period, PhaseData, RangePoint, SchellingGameType, WinningDecision,
 };
 use pallet_sortition_sum_game::types::SumTreeName;
+pub use pallet_support::CitizenStatus;
 use pallet_support::{new_who_and_when, Content, WhoAndWhenOf};
-use trait_schelling_game_shared::SchellingGameSharedLink;
+use trait_schelling_game_shared::{PhaseParams, SchellingGameSharedLink};
+use trait_kyc::KycLink;
 use trait_shared_storage::SharedStorageLink;
-pub use types::{CitizenDetailsPost, FIRST_CHALLENGE_POST_ID, FIRST_CITIZEN_ID};
+pub use types::{
+    CitizenDetailsPost, IdentityAddressLength, IdentityNameLength, PhotoHashLength,
+    FIRST_CHALLENGE_POST_ID, FIRST_CITIZEN_ID,
+};
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 type ProfileFundInfoOf<T> = ProfileFundInfo<BalanceOf<T>, AccountIdOf<T>>;
+type ChallengeFundInfoOf<T> = ChallengeFundInfo<BalanceOf<T>, AccountIdOf<T>>;
 type ChallengerFundInfoOf<T> = ChallengerFundInfo<BalanceOf<T>, BlockNumberFor<T>, AccountIdOf<T>>;
+type ProfileValidationParamsOf<T> = ProfileValidationParams<BalanceOf<T>>;
+type ProfileVersionEntryOf<T> = ProfileVersionEntry<BlockNumberFor<T>>;
+type RecoveryConfigOf<T> = RecoveryConfig<AccountIdOf<T>>;
+type ActiveRecoveryOf<T> = ActiveRecovery<AccountIdOf<T>, BlockNumberFor<T>>;
+type FraudChallengeInfoOf<T> = FraudChallengeInfo<BalanceOf<T>, BlockNumberFor<T>, AccountIdOf<T>>;
+type SumTreeNameOf<T> = SumTreeName<AccountIdOf<T>, BlockNumberFor<T>>;
 pub type BlockNumberOf<T> = BlockNumberFor<T>;
 type CitizenId = u64;
 type ChallengePostId = u64;
@@ -67,7 +94,11 @@ const PALLET_ID: PalletId = PalletId(*b"ex/cfund");
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -91,10 +122,18 @@ pub mod pallet {
             Period = Period,
             WinningDecision = WinningDecision,
             PhaseData = PhaseData<Self>,
+            CancellationReason = CancellationReason,
+            VrfPublic = sr25519::Public,
+            VrfSignature = VrfSignature,
         >;
 
         type SharedStorageSource: SharedStorageLink<AccountId = AccountIdOf<Self>>;
 
+        type KycSource: KycLink<AccountId = AccountIdOf<Self>>;
+
+        /// Origin that can update `ProfileValidationParameters`
+        type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type Currency: ReservableCurrency<Self::AccountId>;
         /// Handler for the unbalanced increment when rewarding (minting rewards)
         type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
@@ -134,6 +173,36 @@ pub mod pallet {
 
         #[pallet::constant]
         type JurorIncentives: Get<(u64, u64)>;
+
+        /// Blocks after initiating a recovery during which the lost account can veto it
+        #[pallet::constant]
+        type RecoveryDelay: Get<u64>;
+
+        /// Blocks after initiating a recovery after which it expires if not finalized,
+        /// another guardian can then initiate a new recovery
+        #[pallet::constant]
+        type RecoveryExpiry: Get<u64>;
+
+        /// Blocks the citizenship is valid after the validation game started
+        #[pallet::constant]
+        type CitizenshipValidity: Get<u64>;
+
+        /// Blocks after expiry during which the citizen stays approved and can re-validate
+        #[pallet::constant]
+        type RevalidationGracePeriod: Get<u64>;
+
+        /// Citizens demoted in a block, more demotions spill into the next block
+        #[pallet::constant]
+        type MaxDemotionsPerBlock: Get<u32>;
+
+        /// Part of the funds of the losing side of a challenge that goes to the juror reward pool
+        #[pallet::constant]
+        type JurorShareOfLosingFund: Get<Percent>;
+
+        /// Blocks after the validation game started in which its jurors attest the identity,
+        /// the citizen can appeal to governance once they are over
+        #[pallet::constant]
+        type IdentityAttestationPeriod: Get<u64>;
     }
 
     // The pallet's runtime storage items.
@@ -158,11 +227,45 @@ pub mod pallet {
     #[pallet::getter(fn get_citizen_id)]
     pub type GetCitizenId<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, CitizenId>;
 
+    /// Reverse index of `GetCitizenId`
+    #[pallet::storage]
+    #[pallet::getter(fn citizen_account)]
+    pub type CitizenAccount<T: Config> = StorageMap<_, Blake2_128Concat, CitizenId, T::AccountId>;
+
+    /// Block at which the citizen was revoked by a fraud challenge
+    #[pallet::storage]
+    #[pallet::getter(fn revoked_citizen)]
+    pub type RevokedCitizens<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberOf<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn citizen_profile)]
     pub type CitizenProfile<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, CitizenDetailsPost<T>>; // Peer account id => Peer Profile Hash
 
+    /// Current version of the citizen profile, increased by every edit after approval
+    #[pallet::storage]
+    #[pallet::getter(fn profile_version)]
+    pub type ProfileVersion<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Replaced versions of the citizen profile
+    #[pallet::storage]
+    #[pallet::getter(fn profile_history)]
+    pub type ProfileHistory<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        u32,
+        ProfileVersionEntryOf<T>,
+    >;
+
+    /// Edits that change the content or location of the citizen require a re-validation game
+    #[pallet::storage]
+    #[pallet::getter(fn revalidate_identity_edits)]
+    pub type RevalidateIdentityEdits<T> = StorageValue<_, bool, ValueQuery>;
+
     // Registration Fees
 
     #[pallet::type_value]
@@ -175,6 +278,11 @@ pub mod pallet {
         100u128.saturated_into::<BalanceOf<T>>()
     }
 
+    #[pallet::type_value]
+    pub fn DefaultRevalidationFee<T: Config>() -> BalanceOf<T> {
+        100u128.saturated_into::<BalanceOf<T>>()
+    }
+
     #[pallet::storage]
     #[pallet::getter(fn profile_registration_fees)]
     pub type RegistrationFee<T: Config> =
@@ -185,6 +293,25 @@ pub mod pallet {
     pub type RegistrationChallengeFee<T: Config> =
         StorageValue<_, BalanceOf<T>, ValueQuery, DefaultRegistrationChallengeFee<T>>;
 
+    /// Stake of the citizen to re-validate its profile
+    #[pallet::storage]
+    #[pallet::getter(fn revalidation_fees)]
+    pub type RevalidationFee<T: Config> =
+        StorageValue<_, BalanceOf<T>, ValueQuery, DefaultRevalidationFee<T>>;
+
+    /// Game parameters set by `UpdateOrigin`, the `Config` constants are used until they are set
+    /// The fees are applied to `RegistrationFee`, `RegistrationChallengeFee` and `RevalidationFee`.
+    #[pallet::storage]
+    #[pallet::getter(fn profile_validation_parameters)]
+    pub type ProfileValidationParameters<T: Config> =
+        StorageValue<_, ProfileValidationParamsOf<T>>;
+
+    /// Game parameters when the game started, later updates apply to new games only
+    #[pallet::storage]
+    #[pallet::getter(fn game_params)]
+    pub type GameParams<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameOf<T>, ProfileValidationParamsOf<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn profile_fund_details)]
     pub type ProfileFundDetails<T: Config> = StorageDoubleMap<
@@ -201,17 +328,53 @@ pub mod pallet {
     pub type ProfileTotalFundCollected<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+    /// Registration fee when the funding of the profile started, fee updates apply to the next
+    /// funding
+    #[pallet::storage]
+    #[pallet::getter(fn profile_fund_fee)]
+    pub type ProfileFundFee<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn validation_block)]
     pub type ValidationBlock<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberOf<T>, ValueQuery>;
 
+    /// Block of the fraud challenge game of the approved citizen, the key of the game instead of
+    /// `ValidationBlock`
+    #[pallet::storage]
+    #[pallet::getter(fn fraud_game_block)]
+    pub type FraudGameBlock<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberOf<T>>;
+
     #[pallet::storage]
     #[pallet::getter(fn challenger_fund)]
     pub type ChallengerFundDetails<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, ChallengerFundInfoOf<T>>; // Profile account id and challenger fund info
 
-    /// There is a single challenger, but they can have multiple posts
+    #[pallet::storage]
+    #[pallet::getter(fn challenger_fund_contribution)]
+    pub type ChallengerFundContributions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        ChallengeFundInfoOf<T>,
+    >; // Profile account id and (challenger accountid, challenge fund info)
+
+    #[pallet::storage]
+    #[pallet::getter(fn total_fund_for_challenge_collected)]
+    pub type ChallengeTotalFundCollected<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    /// Challenge fee when the challenge funding of the profile started
+    #[pallet::storage]
+    #[pallet::getter(fn challenge_fund_fee)]
+    pub type ChallengeFundFee<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+    /// Every challenger that contributed to the challenge fund has one post
     #[pallet::storage]
     #[pallet::getter(fn challenger_evidence_query)]
     pub type ChallengerEvidenceId<T: Config> = StorageDoubleMap<
@@ -243,6 +406,98 @@ pub mod pallet {
     pub type ChallengePost<T: Config> =
         StorageMap<_, Blake2_128Concat, ChallengePostId, ChallengeEvidencePost<T>>; // challenge post id => post
 
+    /// Jurors of new profile validation games select themselves with a VRF instead of being drawn
+    #[pallet::storage]
+    #[pallet::getter(fn private_juror_selection)]
+    pub type PrivateJurorSelection<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Court of the profile validation games, phase data constants are used without court
+    #[pallet::storage]
+    #[pallet::getter(fn profile_validation_court)]
+    pub type ProfileValidationCourt<T> = StorageValue<_, u64>;
+
+    /// Juror pool from which the jurors of profile validation games are drawn
+    #[pallet::storage]
+    #[pallet::getter(fn profile_validation_juror_pool)]
+    pub type ProfileValidationJurorPool<T> = StorageValue<_, u64>;
+
+    /// Identity of the citizen attested by the jurors of its profile validation
+    #[pallet::storage]
+    #[pallet::getter(fn identity_data)]
+    pub type IdentityStore<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, IdentityData>;
+
+    /// Identity data attested by each juror, stored until enough jurors attested its hash
+    #[pallet::storage]
+    #[pallet::getter(fn pending_identity)]
+    pub type PendingIdentity<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        IdentityData,
+    >;
+
+    /// Jurors that attested the identity hash of the citizen, sorted
+    #[pallet::storage]
+    #[pallet::getter(fn identity_attestations)]
+    pub type IdentityAttestations<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        [u8; 32],
+        Vec<T::AccountId>,
+        ValueQuery,
+    >;
+
+    /// Identity data of citizens that appealed to governance because jurors didn't attest it
+    #[pallet::storage]
+    #[pallet::getter(fn identity_appeal)]
+    pub type IdentityAppeals<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, IdentityData>;
+
+    /// Block at which the citizenship expires, the citizen is demoted after the grace period
+    #[pallet::storage]
+    #[pallet::getter(fn citizenship_expiry)]
+    pub type CitizenshipExpiry<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberOf<T>>;
+
+    /// Citizens to demote at the block unless they re-validated
+    #[pallet::storage]
+    #[pallet::getter(fn demotion_queue)]
+    pub type DemotionQueue<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BlockNumberOf<T>,
+        BoundedVec<T::AccountId, T::MaxDemotionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// `add_to_kyc_accounts` requires a live KYC commitment of the account
+    #[pallet::storage]
+    #[pallet::getter(fn require_kyc_commitment)]
+    pub type RequireKycCommitment<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Recovery guardians and threshold of the citizen account
+    #[pallet::storage]
+    #[pallet::getter(fn recovery_config)]
+    pub type RecoveryConfigs<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RecoveryConfigOf<T>>;
+
+    /// Recovery in progress of the lost citizen account
+    #[pallet::storage]
+    #[pallet::getter(fn active_recovery)]
+    pub type ActiveRecoveries<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, ActiveRecoveryOf<T>>;
+
+    /// Fraud challenge against an approved citizen, the game key uses `start` as block number
+    #[pallet::storage]
+    #[pallet::getter(fn fraud_challenge)]
+    pub type FraudChallenges<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FraudChallengeInfoOf<T>>;
+
     // Pallets use events to inform users when important changes are made.
     // https://docs.substrate.io/main-docs/build/events-errors/
     #[pallet::event]
@@ -259,6 +514,108 @@ pub mod pallet {
             profile: T::AccountId,
             funder: T::AccountId,
         },
+        ChallengeFund {
+            profile: T::AccountId,
+            challenger: T::AccountId,
+        },
+        ProfileValidationParamsUpdated,
+        ProfileEdited {
+            citizen: T::AccountId,
+            version: u32,
+            revalidation: bool,
+        },
+        RevalidateIdentityEditsSet {
+            required: bool,
+        },
+        ChallengeSettled {
+            profile: T::AccountId,
+            challenge_upheld: bool,
+            juror_share: BalanceOf<T>,
+        },
+        ProfileStakeReturned {
+            profile: T::AccountId,
+            funder: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ChallengeStakeReturned {
+            profile: T::AccountId,
+            challenger: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ProfileValidationCancelled {
+            profile: T::AccountId,
+            reason: CancellationReason,
+        },
+        /// Validation game ended without a winner, deposits are returned and the profile
+        /// can be funded again.
+        ProfileValidationReopened {
+            profile: T::AccountId,
+        },
+        PrivateJurorSelectionSet {
+            enabled: bool,
+        },
+        ProfileValidationCourtSet {
+            court_id: Option<u64>,
+        },
+        ProfileValidationJurorPoolSet {
+            department_id: Option<u64>,
+        },
+        RecoveryConfigSet {
+            who: T::AccountId,
+        },
+        RecoveryInitiated {
+            lost_account: T::AccountId,
+            new_account: T::AccountId,
+            guardian: T::AccountId,
+        },
+        RecoveryApproved {
+            lost_account: T::AccountId,
+            guardian: T::AccountId,
+        },
+        RecoveryVetoed {
+            lost_account: T::AccountId,
+        },
+        CitizenRecovered {
+            lost_account: T::AccountId,
+            new_account: T::AccountId,
+        },
+        FraudChallenged {
+            citizen: T::AccountId,
+            challenger: T::AccountId,
+        },
+        FraudChallengeResolved {
+            citizen: T::AccountId,
+            revoked: bool,
+        },
+        RequireKycCommitmentSet {
+            required: bool,
+        },
+        IdentityAttested {
+            citizen: T::AccountId,
+            juror: T::AccountId,
+            identity_hash: [u8; 32],
+        },
+        IdentityStored {
+            citizen: T::AccountId,
+            identity_hash: [u8; 32],
+        },
+        IdentityAppealed {
+            citizen: T::AccountId,
+        },
+        IdentityAppealResolved {
+            citizen: T::AccountId,
+            approved: bool,
+        },
+        CitizenshipRenewed {
+            citizen: T::AccountId,
+            expiry: BlockNumberOf<T>,
+        },
+        RevalidationStarted {
+            citizen: T::AccountId,
+        },
+        CitizenshipExpired {
+            citizen: T::AccountId,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -285,6 +642,51 @@ pub mod pallet {
         NotAPostOwner,
         AmountFundedGreaterThanRequired,
         ProfileFundAlreadyReturned,
+        InvalidRecoveryThreshold,
+        InvalidGuardian,
+        RecoveryConfigNotExists,
+        NotAGuardian,
+        RecoveryExists,
+        RecoveryNotExists,
+        AlreadyApproved,
+        NotEnoughApprovals,
+        RecoveryDelayNotOver,
+        RecoveryExpired,
+        NewAccountIsCitizen,
+        FraudChallengeExists,
+        FraudChallengeNotExists,
+        /// Citizen was revoked by a fraud challenge after its last validation game.
+        CitizenRevoked,
+        ProfileNotApprovedByJurors,
+        NotDrawnJuror,
+        IdentityExists,
+        AlreadyAttested,
+        IdentityAppealExists,
+        IdentityAppealNotExists,
+        CitizenshipExpiryNotExists,
+        CitizenshipNotExpired,
+        RevalidationStarted,
+        NotAChallenger,
+        ChallengeFundingClosed,
+        ChallengeFundNotExists,
+        ChallengeFundAlreadyReturned,
+        ChallengeLost,
+        ProfileFundLost,
+        ValidationGameNotOver,
+        InvalidProfileValidationParams,
+        /// Deposits of the validation game without a winner are already returned.
+        ProfileValidationAlreadyReopened,
+        /// Contribution to a challenge fund must not be zero.
+        ZeroChallengeFund,
+        /// Jurors of the validation game can still attest the identity.
+        IdentityAttestationNotOver,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            Self::demote_expired_citizens(now)
+        }
     }
 
     // Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -326,6 +728,7 @@ pub mod pallet {
                 }
                 None => {
                     <GetCitizenId<T>>::insert(&who, count);
+                    <CitizenAccount<T>>::insert(count, &who);
 
                     let new_post: CitizenDetailsPost<T> = CitizenDetailsPost::new(
                         count,
@@ -364,6 +767,7 @@ pub mod pallet {
         /// - `ProfileFundDetails`: Stores details of funds deposited by users for a specific profile.
         /// - `ProfileTotalFundCollected`: Keeps track of the total funds collected for each profile.
         /// - `RegistrationFee`: Retrieves the registration fee required for profile validation.
+        /// - `ProfileFundFee`: Registration fee of the profile, kept while the profile is funded.
         /// - `GetCitizenId`: Storage map that associates a citizen's account address with their Citizen ID.
         ///
         /// ## Usage
@@ -396,16 +800,16 @@ pub mod pallet {
             // Ensure that the `profile_user_account`` exists in `GetCitizenId` storage.
             Self::ensure_account_id_has_profile(profile_user_account.clone())?;
 
-            // Retrieve the registration fee required for profile validation.
-            let registration_fee = <RegistrationFee<T>>::get();
+            // Retrieve the registration fee of the profile, snapshotted when its funding started.
+            let registration_fee = Self::registration_fee_of(&profile_user_account);
 
             // Get the total funds already collected for the profile.
             let total_funded = <ProfileTotalFundCollected<T>>::get(profile_user_account.clone());
 
             // Calculate the required fund by subtracting the total funded from the registration fee.
-            let required_fund = registration_fee
-                .checked_sub(&total_funded)
-                .expect("Overflow");
+            let required_fund = registration_fee.saturating_sub(total_funded);
+            ensure!(!required_fund.is_zero(), Error::<T>::ProfileFundExists);
+            <ProfileFundFee<T>>::insert(&profile_user_account, registration_fee);
 
             // Check if the amount_to_fund is less than or equal to the required fund.
             if amount_to_fund <= required_fund {
@@ -417,9 +821,13 @@ pub mod pallet {
                         block_number: now.clone(),
                     };
                     <ValidationBlock<T>>::insert(&profile_user_account, now);
+                    Self::snapshot_game_params(&key);
 
                     // Set a link to the evidence period in the Schelling Game.
-                    T::SchellingGameSharedSource::set_to_evidence_period_link(key, now)?;
+                    T::SchellingGameSharedSource::set_to_evidence_period_link(key.clone(), now)?;
+                    if let Some(court_id) = <ProfileValidationCourt<T>>::get() {
+                        T::SchellingGameSharedSource::set_game_court_link(key, court_id)?;
+                    }
                 }
 
                 // Withdraw funds from the funder's account.
@@ -487,64 +895,11 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             Self::ensure_account_id_has_profile(profile_user_account.clone())?;
-            let now = <frame_system::Pallet<T>>::block_number();
 
-            let fees = Self::profile_registration_challenge_fees();
-
-            let challenger_fund_info = ChallengerFundInfo {
-                challengerid: who.clone(),
-                deposit: fees,
-                start: now.clone(),
-                challenge_completed: false,
-            };
-
-            let challenger_fund_details = <ChallengerFundDetails<T>>::get(&profile_user_account);
-            match challenger_fund_details {
-                Some(_value) => Err(Error::<T>::ChallengeExits)?,
-                None => {
-                    let _ = <T as pallet::Config>::Currency::withdraw(
-                        &who,
-                        fees.clone(),
-                        WithdrawReasons::TRANSFER,
-                        ExistenceRequirement::AllowDeath,
-                    )?;
-                    <ChallengerFundDetails<T>>::insert(&profile_user_account, challenger_fund_info);
-                }
-            }
-
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
-
-            let phase_data = Self::get_phase_data();
-
-            T::SchellingGameSharedSource::set_to_staking_period_link(key.clone(), phase_data, now)?;
-            T::SchellingGameSharedSource::create_tree_helper_link(key.clone(), 3)?;
-
-            let count = Self::next_challenge_post_count();
-
-            let challenge_evidence_post: ChallengeEvidencePost<T> = ChallengeEvidencePost::new(
-                profile_user_account.clone(),
-                who.clone(),
-                content,
-                None,
-            );
-
-            match <ChallengerEvidenceId<T>>::get(&profile_user_account, &who) {
-                None => {
-                    <ChallengePost<T>>::insert(&count, challenge_evidence_post);
-                    NextChallengePostId::<T>::mutate(|n| {
-                        *n += 1;
-                    });
-
-                    <ChallengerEvidenceId<T>>::insert(&profile_user_account, &who, count);
-                }
-                Some(_hash) => Err(Error::<T>::PostAlreadyExists)?,
-            }
-            Ok(())
+            // Pay what is left of the challenge fee, the whole fee without other challengers
+            let required_fund = Self::challenge_fund_required(&profile_user_account)?;
+            Self::add_challenge_fund(who.clone(), profile_user_account.clone(), required_fund)?;
+            Self::add_challenge_evidence_post(who, profile_user_account, content)
         }
 
         // #[pallet::call_index(2)]
@@ -703,15 +1058,10 @@ pub mod pallet {
         ) -> DispatchResult {
             let _who = ensure_signed(origin)?;
 
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
 
             let now = <frame_system::Pallet<T>>::block_number();
-            let phase_data = Self::get_phase_data();
+            let phase_data = Self::get_phase_data(&key);
 
             T::SchellingGameSharedSource::change_period_link(key, phase_data, now)?;
 
@@ -734,7 +1084,7 @@ pub mod pallet {
         // 3. Number of people staked
 
         #[pallet::call_index(6)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::SchellingGameSharedSource::set_stake_weight_link())]
         pub fn apply_jurors(
             origin: OriginFor<T>,
             profile_user_account: T::AccountId,
@@ -742,14 +1092,9 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
 
-            let phase_data = Self::get_phase_data();
+            let phase_data = Self::get_phase_data(&key);
 
             T::SchellingGameSharedSource::apply_jurors_helper_link(key, phase_data, who, stake)?;
 
@@ -764,20 +1109,15 @@ pub mod pallet {
         // Store the drawn juror stake in hashmap storage
         // Add min draws along with max draws
         #[pallet::call_index(7)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::SchellingGameSharedSource::draw_jurors_weight_link(*iterations))]
         pub fn draw_jurors(
             origin: OriginFor<T>,
             profile_user_account: T::AccountId,
             iterations: u64,
         ) -> DispatchResult {
             let _who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
-            let phase_data = Self::get_phase_data();
+            let key = Self::juror_game_key(&profile_user_account);
+            let phase_data = Self::get_phase_data(&key);
 
             T::SchellingGameSharedSource::draw_jurors_helper_link(key, phase_data, iterations)?;
 
@@ -787,18 +1127,13 @@ pub mod pallet {
         // Unstaking
         // Stop drawn juror to unstake ✔️
         #[pallet::call_index(8)]
-        #[pallet::weight(0)]
+        #[pallet::weight(T::SchellingGameSharedSource::set_stake_weight_link())]
         pub fn unstaking(
             origin: OriginFor<T>,
             profile_user_account: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
             T::SchellingGameSharedSource::unstaking_helper_link(key, who)?;
             Ok(())
         }
@@ -811,12 +1146,7 @@ pub mod pallet {
             vote_commit: [u8; 32],
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
             T::SchellingGameSharedSource::commit_vote_helper_link(key, who, vote_commit)?;
             Ok(())
         }
@@ -830,12 +1160,7 @@ pub mod pallet {
             salt: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
 
             T::SchellingGameSharedSource::reveal_vote_two_choice_helper_link(
                 key, who, choice, salt,
@@ -851,19 +1176,18 @@ pub mod pallet {
             profile_user_account: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
-
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
-                block_number,
-            };
-            let phase_data = Self::get_phase_data();
+            let key = Self::juror_game_key(&profile_user_account);
+            let phase_data = Self::get_phase_data(&key);
             T::SchellingGameSharedSource::get_incentives_two_choice_helper_link(
                 key, phase_data, who,
             )?;
             Ok(())
         }
 
+        /// # Return profile stake
+        ///
+        /// Funders get their deposit and a pro rata share of the challenge fund when the profile
+        /// wins the challenge, their deposit when there is no winner or no challenge.
         #[pallet::call_index(12)]
         #[pallet::weight(0)]
         pub fn return_profile_stake(
@@ -871,103 +1195,498 @@ pub mod pallet {
             profile_user_account: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&profile_user_account);
+            Self::return_profile_stake_helper(who, profile_user_account)
+        }
+
+        #[pallet::call_index(13)]
+        #[pallet::weight(0)]
+        pub fn add_to_kyc_accounts(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let block_number = <ValidationBlock<T>>::get(&who);
             let key = SumTreeName::ProfileValidation {
-                citizen_address: profile_user_account.clone(),
+                citizen_address: who.clone(),
                 block_number,
             };
             let now = <frame_system::Pallet<T>>::block_number();
-            let phase_data = Self::get_phase_data();
+            let phase_data = Self::get_phase_data(&key);
+
+            if <RequireKycCommitment<T>>::get() {
+                T::KycSource::ensure_live_commitment_link(who.clone())?;
+            }
 
             let period = T::SchellingGameSharedSource::get_period_link(key.clone()).unwrap();
+
             if period == Period::Execution {
                 let decision: WinningDecision =
                     T::SchellingGameSharedSource::get_winning_decision_value(key.clone())?;
-                if decision == WinningDecision::WinnerNo {
-                    match <ProfileFundDetails<T>>::get(profile_user_account.clone(), who.clone()) {
-                        Some(mut profile_fund_info) => {
-                            if profile_fund_info.deposit_returned == false {
-                                let r = <T as pallet::Config>::Currency::deposit_into_existing(
-                                    &who,
-                                    profile_fund_info.deposit,
-                                )
-                                .ok()
-                                .unwrap();
-                                <T as pallet::Config>::Reward::on_unbalanced(r);
-                                profile_fund_info.deposit_returned = true;
-                                <ProfileFundDetails<T>>::insert(
-                                    profile_user_account.clone(),
-                                    who.clone(),
-                                    profile_fund_info,
-                                );
-                            } else {
-                                Err(Error::<T>::ProfileFundAlreadyReturned)?;
-                            }
-                        }
-                        None => {
-                            Err(Error::<T>::ProfileFundNotExists)?;
-                        }
+                match decision {
+                    WinningDecision::WinnerNo => Self::approve_citizen(who.clone(), block_number)?,
+                    WinningDecision::Draw | WinningDecision::Abstain => {
+                        Self::reopen_profile_validation(who.clone())?
                     }
+                    WinningDecision::WinnerYes => {}
                 }
             } else if period == Period::Evidence {
                 T::SchellingGameSharedSource::ensure_time_for_staking_over_link(
                     key, phase_data, now,
                 )?;
-                match <ProfileFundDetails<T>>::get(profile_user_account.clone(), who.clone()) {
-                    Some(mut profile_fund_info) => {
-                        if profile_fund_info.deposit_returned == false {
-                            let r = <T as pallet::Config>::Currency::deposit_into_existing(
-                                &who,
-                                profile_fund_info.deposit,
-                            )
-                            .ok()
-                            .unwrap();
-                            <T as pallet::Config>::Reward::on_unbalanced(r);
-                            profile_fund_info.deposit_returned = true;
-                            <ProfileFundDetails<T>>::insert(
-                                profile_user_account.clone(),
-                                who.clone(),
-                                profile_fund_info,
-                            );
-                        } else {
-                            Err(Error::<T>::ProfileFundAlreadyReturned)?;
-                        }
-                    }
-                    None => {
-                        Err(Error::<T>::ProfileFundNotExists)?;
-                    }
+                Self::approve_citizen(who.clone(), block_number)?;
+            }
+            Ok(())
+        }
+
+        /// # Cancel profile validation
+        ///
+        /// Cancels the schelling game of the profile and refunds everyone.
+        ///
+        /// - `CancellationReason::Governance` requires root origin.
+        /// - `CancellationReason::NotEnoughJurorsStaked` can be called by anyone once staking is over
+        ///   with less than `MinNumberJurorStaked` jurors.
+        ///
+        /// Juror stakes are refunded by the schelling game, the profile funders and the challenger
+        /// get their deposits back and the profile can be funded again for a new validation.
+        /// A cancelled fraud game returns the deposit of the fraud challenger instead.
+        #[pallet::call_index(14)]
+        #[pallet::weight(0)]
+        pub fn cancel_profile_validation(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            reason: CancellationReason,
+        ) -> DispatchResult {
+            let who = ensure_signed_or_root(origin)?;
+            if reason == CancellationReason::Governance {
+                ensure!(who.is_none(), DispatchError::BadOrigin);
+            }
+            let key = Self::juror_game_key(&profile_user_account);
+            let now = <frame_system::Pallet<T>>::block_number();
+            let phase_data = Self::get_phase_data(&key);
+
+            T::SchellingGameSharedSource::cancel_game_link(key, phase_data, now, reason.clone())?;
+
+            match Self::open_fraud_challenge(&profile_user_account) {
+                Some(fraud_challenge) => {
+                    Self::refund_fraud_challenger(&profile_user_account, fraud_challenge)
+                }
+                None => {
+                    Self::refund_profile_funders(profile_user_account.clone());
+                    Self::refund_challenger(profile_user_account.clone());
                 }
             }
 
+            Self::deposit_event(Event::ProfileValidationCancelled {
+                profile: profile_user_account,
+                reason,
+            });
             Ok(())
         }
 
-        #[pallet::call_index(13)]
+        /// # Fund appeal
+        ///
+        /// Contribute `amount` to the appeal fee of `choice` (0 or 1) during the appeal period
+        /// after the vote period. A new round with a larger jury opens once the side is fully funded.
+        #[pallet::call_index(15)]
         #[pallet::weight(0)]
-        pub fn add_to_kyc_accounts(origin: OriginFor<T>) -> DispatchResult {
+        pub fn fund_appeal(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            choice: u128,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            let block_number = <ValidationBlock<T>>::get(&who);
-            let key = SumTreeName::ProfileValidation {
-                citizen_address: who.clone(),
-                block_number,
-            };
+            let key = Self::juror_game_key(&profile_user_account);
             let now = <frame_system::Pallet<T>>::block_number();
-            let phase_data = Self::get_phase_data();
+            let phase_data = Self::get_phase_data(&key);
 
-            let period = T::SchellingGameSharedSource::get_period_link(key.clone()).unwrap();
+            T::SchellingGameSharedSource::fund_appeal_link(
+                key, phase_data, who, choice, amount, now,
+            )?;
+            Ok(())
+        }
 
-            if period == Period::Execution {
-                let decision: WinningDecision =
-                    T::SchellingGameSharedSource::get_winning_decision_value(key.clone())?;
-                if decision == WinningDecision::WinnerNo {
-                    T::SharedStorageSource::add_approved_citizen_address(who.clone())?;
-                }
-            } else if period == Period::Evidence {
-                T::SchellingGameSharedSource::ensure_time_for_staking_over_link(
-                    key, phase_data, now,
-                )?;
-                T::SharedStorageSource::add_approved_citizen_address(who.clone())?;
+        /// # Withdraw appeal contribution
+        ///
+        /// Settle the appeal contribution of `round` once the profile validation reached execution.
+        #[pallet::call_index(16)]
+        #[pallet::weight(0)]
+        pub fn withdraw_appeal_contribution(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            round: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let key = Self::juror_game_key(&profile_user_account);
+
+            T::SchellingGameSharedSource::withdraw_appeal_contribution_link(key, who, round)?;
+            Ok(())
+        }
+
+        /// # Set private juror selection
+        ///
+        /// When enabled, jurors of new challenges are not drawn. Stakers prove with their VRF key
+        /// that they are selected while committing their vote.
+        #[pallet::call_index(17)]
+        #[pallet::weight(0)]
+        pub fn set_private_juror_selection(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            <PrivateJurorSelection<T>>::put(enabled);
+            Self::deposit_event(Event::PrivateJurorSelectionSet { enabled });
+            Ok(())
+        }
+
+        /// # Set juror VRF key
+        ///
+        /// Key used to prove private juror selection.
+        #[pallet::call_index(18)]
+        #[pallet::weight(0)]
+        pub fn set_juror_vrf_key(origin: OriginFor<T>, vrf_key: sr25519::Public) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::SchellingGameSharedSource::set_juror_vrf_key_link(who, vrf_key)?;
+            Ok(())
+        }
+
+        /// # Commit vote with selection proof
+        ///
+        /// Commit vote of a privately selected juror, `vrf_signature` is the VRF signature over
+        /// the seed of the round that shows the juror is within the stake weighted threshold.
+        #[pallet::call_index(19)]
+        #[pallet::weight(0)]
+        pub fn commit_vote_self_selected(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            vote_commit: [u8; 32],
+            vrf_signature: VrfSignature,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let key = Self::juror_game_key(&profile_user_account);
+            let phase_data = Self::get_phase_data(&key);
+
+            T::SchellingGameSharedSource::commit_vote_self_selected_link(
+                key,
+                phase_data,
+                who,
+                vote_commit,
+                vrf_signature,
+            )?;
+            Ok(())
+        }
+
+        /// # Set profile validation court
+        ///
+        /// New profile validation games are created in the court and use its parameters.
+        #[pallet::call_index(20)]
+        #[pallet::weight(0)]
+        pub fn set_profile_validation_court(
+            origin: OriginFor<T>,
+            court_id: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match court_id {
+                Some(court_id) => <ProfileValidationCourt<T>>::put(court_id),
+                None => <ProfileValidationCourt<T>>::kill(),
+            }
+            Self::deposit_event(Event::ProfileValidationCourtSet { court_id });
+            Ok(())
+        }
+
+        /// # Set profile validation juror pool
+        ///
+        /// Jurors of new challenges are drawn from the juror pool of the department,
+        /// `None` lets jurors stake in every game.
+        #[pallet::call_index(21)]
+        #[pallet::weight(0)]
+        pub fn set_profile_validation_juror_pool(
+            origin: OriginFor<T>,
+            department_id: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            match department_id {
+                Some(department_id) => <ProfileValidationJurorPool<T>>::put(department_id),
+                None => <ProfileValidationJurorPool<T>>::kill(),
+            }
+            Self::deposit_event(Event::ProfileValidationJurorPoolSet { department_id });
+            Ok(())
+        }
+
+        /// # Set recovery guardians
+        ///
+        /// Approved citizen sets other approved citizens as guardians, `threshold` of them
+        /// can recover the profile to a new account.
+        #[pallet::call_index(22)]
+        #[pallet::weight(0)]
+        pub fn set_recovery_config(
+            origin: OriginFor<T>,
+            guardians: Vec<T::AccountId>,
+            threshold: u32,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::set_recovery_config_helper(who, guardians, threshold)
+        }
+
+        /// # Initiate recovery
+        ///
+        /// Guardian of `lost_account` starts the recovery to `new_account`, it counts as the
+        /// first approval. A recovery that expired unfinalized is replaced.
+        #[pallet::call_index(23)]
+        #[pallet::weight(0)]
+        pub fn initiate_recovery(
+            origin: OriginFor<T>,
+            lost_account: T::AccountId,
+            new_account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::initiate_recovery_helper(who, lost_account, new_account)
+        }
+
+        /// # Approve recovery
+        #[pallet::call_index(24)]
+        #[pallet::weight(0)]
+        pub fn approve_recovery(
+            origin: OriginFor<T>,
+            lost_account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::approve_recovery_helper(who, lost_account)
+        }
+
+        /// # Veto recovery
+        ///
+        /// The account being recovered still holds its key and cancels the recovery.
+        #[pallet::call_index(25)]
+        #[pallet::weight(0)]
+        pub fn veto_recovery(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            <ActiveRecoveries<T>>::take(&who).ok_or(Error::<T>::RecoveryNotExists)?;
+            Self::deposit_event(Event::RecoveryVetoed { lost_account: who });
+            Ok(())
+        }
+
+        /// # Finalize recovery
+        ///
+        /// Once the threshold is reached and `RecoveryDelay` is over, anyone can move the citizen id,
+        /// profile, approved citizen address, reputation and department memberships to the new account.
+        #[pallet::call_index(26)]
+        #[pallet::weight(0)]
+        pub fn finalize_recovery(
+            origin: OriginFor<T>,
+            lost_account: T::AccountId,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+            Self::finalize_recovery_helper(lost_account)
+        }
+
+        /// # Challenge approved citizen
+        ///
+        /// Anyone can stake `RegistrationChallengeFee` with new evidence to re-open a two-choice game
+        /// against an approved citizen. The game starts in the staking period and is played with the
+        /// usual juror calls, `WinnerYes` upholds the fraud.
+        #[pallet::call_index(27)]
+        #[pallet::weight(0)]
+        pub fn challenge_approved_citizen(
+            origin: OriginFor<T>,
+            citizen: T::AccountId,
+            content: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::challenge_approved_citizen_helper(who, citizen, content)
+        }
+
+        /// # Resolve fraud challenge
+        ///
+        /// Once the game reached execution, an upheld fraud removes the citizen from approved
+        /// citizens and departments, freezes its reputation and rewards the challenger.
+        #[pallet::call_index(28)]
+        #[pallet::weight(0)]
+        pub fn resolve_fraud_challenge(
+            origin: OriginFor<T>,
+            citizen: T::AccountId,
+        ) -> DispatchResult {
+            let _who = ensure_signed(origin)?;
+            Self::resolve_fraud_challenge_helper(citizen)
+        }
+
+        /// # Require KYC commitment
+        ///
+        /// Profiles are added to the approved citizens only with a registered KYC commitment
+        /// that is not expired.
+        #[pallet::call_index(29)]
+        #[pallet::weight(0)]
+        pub fn set_require_kyc_commitment(origin: OriginFor<T>, required: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            <RequireKycCommitment<T>>::put(required);
+            Self::deposit_event(Event::RequireKycCommitmentSet { required });
+            Ok(())
+        }
+
+        /// # Attest identity
+        ///
+        /// Drawn juror of the profile validation that approved the citizen attests its identity
+        /// after the video call. The identity is stored once a majority of the drawn jurors
+        /// attested the same data.
+        #[pallet::call_index(30)]
+        #[pallet::weight(0)]
+        pub fn attest_identity(
+            origin: OriginFor<T>,
+            citizen: T::AccountId,
+            name: BoundedVec<u8, IdentityNameLength>,
+            address: BoundedVec<u8, IdentityAddressLength>,
+            photo_hash: BoundedVec<u8, PhotoHashLength>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let identity = IdentityData {
+                name,
+                address,
+                photo_hash,
+                validator_approved: true,
+            };
+            Self::attest_identity_helper(who, citizen, identity)
+        }
+
+        /// # Appeal identity
+        ///
+        /// Approved citizen whose identity is not attested by the jurors submits it to governance,
+        /// once the `IdentityAttestationPeriod` of a validation game with jurors is over.
+        #[pallet::call_index(31)]
+        #[pallet::weight(0)]
+        pub fn appeal_identity(
+            origin: OriginFor<T>,
+            name: BoundedVec<u8, IdentityNameLength>,
+            address: BoundedVec<u8, IdentityAddressLength>,
+            photo_hash: BoundedVec<u8, PhotoHashLength>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let identity = IdentityData {
+                name,
+                address,
+                photo_hash,
+                validator_approved: false,
+            };
+            Self::appeal_identity_helper(who, identity)
+        }
+
+        /// # Resolve identity appeal
+        #[pallet::call_index(32)]
+        #[pallet::weight(0)]
+        pub fn resolve_identity_appeal(
+            origin: OriginFor<T>,
+            citizen: T::AccountId,
+            approve: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let identity =
+                <IdentityAppeals<T>>::take(&citizen).ok_or(Error::<T>::IdentityAppealNotExists)?;
+            if approve {
+                <IdentityStore<T>>::insert(&citizen, identity);
             }
+            Self::deposit_event(Event::IdentityAppealResolved {
+                citizen,
+                approved: approve,
+            });
+            Ok(())
+        }
+
+        /// # Renew citizenship
+        ///
+        /// Once the citizenship expired the citizen stakes `RevalidationFee` and its profile goes
+        /// through a new evidence period, where it can be challenged as a new profile.
+        /// `add_to_kyc_accounts` renews the citizenship after the game, the citizen is demoted
+        /// if it is not renewed within `RevalidationGracePeriod`.
+        #[pallet::call_index(33)]
+        #[pallet::weight(0)]
+        pub fn renew_citizenship(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::renew_citizenship_helper(who)
+        }
+
+        /// # Fund challenge
+        ///
+        /// Contribute `amount_to_fund` to the challenge fund of the profile, so several challengers
+        /// can crowdfund `RegistrationChallengeFee`. The challenge starts with the contribution that
+        /// completes the fund. Every challenger can post evidence with `add_challenge_evidence`.
+        #[pallet::call_index(34)]
+        #[pallet::weight(0)]
+        pub fn fund_challenge(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            amount_to_fund: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::ensure_account_id_has_profile(profile_user_account.clone())?;
+            Self::add_challenge_fund(who, profile_user_account, amount_to_fund)
+        }
+
+        #[pallet::call_index(35)]
+        #[pallet::weight(0)]
+        pub fn add_challenge_evidence(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+            content: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::add_challenge_evidence_post(who, profile_user_account, content)
+        }
+
+        /// # Return challenge stake
+        ///
+        /// Challengers get their deposit and a pro rata share of the profile funds when the
+        /// challenge wins, their deposit when there is no winner or the fund was not completed.
+        /// `JurorShareOfLosingFund` of the losing side goes to the juror reward pool.
+        #[pallet::call_index(36)]
+        #[pallet::weight(0)]
+        pub fn return_challenge_stake(
+            origin: OriginFor<T>,
+            profile_user_account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::return_challenge_stake_helper(who, profile_user_account)
+        }
+
+        /// # Set profile validation params
+        ///
+        /// Update the fees and the schelling game parameters of profile validation.
+        /// Games that already started keep their phase data for the periods already passed.
+        #[pallet::call_index(37)]
+        #[pallet::weight(0)]
+        pub fn set_profile_validation_params(
+            origin: OriginFor<T>,
+            params: ProfileValidationParamsOf<T>,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            Self::ensure_valid_params(&params)?;
+            <RegistrationFee<T>>::put(params.registration_fee);
+            <RegistrationChallengeFee<T>>::put(params.registration_challenge_fee);
+            <RevalidationFee<T>>::put(params.revalidation_fee);
+            <ProfileValidationParameters<T>>::put(params);
+            Self::deposit_event(Event::ProfileValidationParamsUpdated);
+            Ok(())
+        }
+
+        /// # Edit citizen profile
+        ///
+        /// Approved citizen replaces the content and location of its profile, the replaced version
+        /// is kept in `ProfileHistory`. When `RevalidateIdentityEdits` is set and the content or
+        /// location changes, the citizen is removed from the approved citizens until a re-validation
+        /// game, staked with `RevalidationFee`, is completed with `add_to_kyc_accounts`. Its
+        /// department memberships are restored on approval.
+        #[pallet::call_index(38)]
+        #[pallet::weight(0)]
+        pub fn edit_citizen_profile(
+            origin: OriginFor<T>,
+            content: Content,
+            location: LocationDetails,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::edit_citizen_profile_helper(who, content, location)
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight(0)]
+        pub fn set_revalidate_identity_edits(
+            origin: OriginFor<T>,
+            required: bool,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <RevalidateIdentityEdits<T>>::put(required);
+            Self::deposit_event(Event::RevalidateIdentityEditsSet { required });
             Ok(())
         }
     }
@@ -0,0 +1,77 @@
+use crate::*;
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{
+        migrations::VersionedMigration, sp_runtime::traits::One, traits::UncheckedOnRuntimeUpgrade,
+    };
+
+    /// Sets the citizenship expiry of citizens approved before citizenship could expire and
+    /// queues their demotion.
+    /// The expiry follows from the `ValidationBlock` of the citizen, citizens whose grace period
+    /// is already over are demoted from the next block.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let next_block = <frame_system::Pallet<T>>::block_number() + One::one();
+            let validity = Pallet::<T>::_u64_to_block_saturated(T::CitizenshipValidity::get());
+            let grace_period =
+                Pallet::<T>::_u64_to_block_saturated(T::RevalidationGracePeriod::get());
+            let mut reads = 0u64;
+            let mut writes = 0u64;
+            for who in GetCitizenId::<T>::iter_keys().collect::<Vec<_>>() {
+                reads += 3;
+                if CitizenshipExpiry::<T>::contains_key(&who)
+                    || T::SharedStorageSource::check_citizen_is_approved_link(who.clone()).is_err()
+                {
+                    continue;
+                }
+                let expiry = ValidationBlock::<T>::get(&who) + validity;
+                CitizenshipExpiry::<T>::insert(&who, expiry);
+                Pallet::<T>::queue_demotion((expiry + grace_period).max(next_block), who);
+                reads += 2;
+                writes += 2;
+            }
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    /// Sets the expiry of approved citizens, runs only when the on-chain storage version is 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+    use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+    /// Fills the `CitizenAccount` reverse index from `GetCitizenId` for citizens added before the
+    /// index existed.
+    pub struct InnerMigrateV1ToV2<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut count = 0u64;
+            for (who, citizen_id) in GetCitizenId::<T>::iter() {
+                CitizenAccount::<T>::insert(citizen_id, who);
+                count += 1;
+            }
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+
+    /// Fills the citizen account index, runs only when the on-chain storage version is 1.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,6 +1,6 @@
 use crate as pallet_template;
-use frame_support::{derive_impl, parameter_types};
-use sp_runtime::BuildStorage;
+use frame_support::{derive_impl, parameter_types, PalletId};
+use sp_runtime::{BuildStorage, Percent};
 
 use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -32,9 +32,16 @@ parameter_types! {
     pub const VoteLengthProfileValidation: u64 = 50;
     pub const AppealLengthProfileValidation: u64 = 50 ;
     pub const MaxDrawsProfileValidation: u64 = 5;
-    pub const MinNumberJurorStakedProfileValidation: u64 = 3;
+    pub const MinNumberJurorStakedProfileValidation: u64 = 5;
     pub const MinJurorStakeProfileValidation: u64 = 100;
     pub const JurorIncentivesProfileValidation: (u64, u64) = (100, 100);
+    pub const RecoveryDelayProfileValidation: u64 = 10;
+    pub const RecoveryExpiryProfileValidation: u64 = 30;
+    pub const CitizenshipValidityProfileValidation: u64 = 1000;
+    pub const RevalidationGracePeriodProfileValidation: u64 = 200;
+    pub const MaxDemotionsPerBlockProfileValidation: u32 = 2;
+    pub const JurorShareOfLosingFundProfileValidation: Percent = Percent::from_percent(10);
+    pub const IdentityAttestationPeriodProfileValidation: u64 = 500;
 }
 
 #[frame_support::runtime]
@@ -75,6 +82,9 @@ mod runtime {
 
     #[runtime::pallet_index(6)]
     pub type SortitionSumGame = pallet_sortition_sum_game::Pallet<Test>;
+
+    #[runtime::pallet_index(7)]
+    pub type Kyc = pallet_kyc::Pallet<Test>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -117,23 +127,53 @@ impl pallet_template::Config for Test {
     type MinNumberJurorStaked = MinNumberJurorStakedProfileValidation;
     type MinJurorStake = MinJurorStakeProfileValidation;
     type JurorIncentives = JurorIncentivesProfileValidation;
+    type RecoveryDelay = RecoveryDelayProfileValidation;
+    type RecoveryExpiry = RecoveryExpiryProfileValidation;
+    type CitizenshipValidity = CitizenshipValidityProfileValidation;
+    type RevalidationGracePeriod = RevalidationGracePeriodProfileValidation;
+    type MaxDemotionsPerBlock = MaxDemotionsPerBlockProfileValidation;
+    type JurorShareOfLosingFund = JurorShareOfLosingFundProfileValidation;
+    type IdentityAttestationPeriod = IdentityAttestationPeriodProfileValidation;
+    type KycSource = Kyc;
+    type UpdateOrigin = frame_system::EnsureRoot<u64>;
+}
+
+impl pallet_kyc::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ProofVerifier = pallet_kyc::HashProofVerifier;
+    type GovernanceOrigin = frame_system::EnsureRoot<u64>;
 }
 
 impl pallet_shared_storage::Config for Test {
     type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+    pub const JurorRewardPoolPalletId: PalletId = PalletId(*b"sg/jrpol");
+    pub const RewardEpochLength: u64 = 1000;
+    pub const RewardEpochInflation: u64 = 1_000_000;
+    pub const MaxRewardPerEpoch: u64 = 1_000_000;
+    pub const AppealFeePerJuror: u64 = 10;
+    pub const MaxJurorPoolSize: u32 = 100;
+}
+
 impl pallet_schelling_game_shared::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances; // New code
     type RandomnessSource = TestRandomness<Self>;
-    type Slash = ();
     type Reward = ();
     type SortitionSumGameSource = SortitionSumGame;
+    type RewardPoolPalletId = JurorRewardPoolPalletId;
+    type RewardEpochLength = RewardEpochLength;
+    type RewardEpochInflation = RewardEpochInflation;
+    type MaxRewardPerEpoch = MaxRewardPerEpoch;
+    type AppealFeePerJuror = AppealFeePerJuror;
+    type MaxJurorPoolSize = MaxJurorPoolSize;
 }
 
 impl pallet_sortition_sum_game::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -0,0 +1,169 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    pub(super) fn set_recovery_config_helper(
+        who: T::AccountId,
+        mut guardians: Vec<T::AccountId>,
+        threshold: u32,
+    ) -> DispatchResult {
+        Self::ensure_account_id_has_profile(who.clone())?;
+        T::SharedStorageSource::check_citizen_is_approved_link(who.clone())?;
+        ensure!(
+            !Self::recovery_in_progress(&who),
+            Error::<T>::RecoveryExists
+        );
+        guardians.sort();
+        guardians.dedup();
+        ensure!(
+            threshold > 0 && threshold as usize <= guardians.len(),
+            Error::<T>::InvalidRecoveryThreshold
+        );
+        for guardian in guardians.iter() {
+            ensure!(*guardian != who, Error::<T>::InvalidGuardian);
+            T::SharedStorageSource::check_citizen_is_approved_link(guardian.clone())
+                .map_err(|_| Error::<T>::InvalidGuardian)?;
+        }
+        <RecoveryConfigs<T>>::insert(
+            &who,
+            RecoveryConfig {
+                guardians,
+                threshold,
+            },
+        );
+        Self::deposit_event(Event::RecoveryConfigSet { who });
+        Ok(())
+    }
+
+    fn ensure_guardian(lost_account: &T::AccountId, guardian: &T::AccountId) -> DispatchResult {
+        let config =
+            <RecoveryConfigs<T>>::get(lost_account).ok_or(Error::<T>::RecoveryConfigNotExists)?;
+        ensure!(
+            config.guardians.binary_search(guardian).is_ok(),
+            Error::<T>::NotAGuardian
+        );
+        Ok(())
+    }
+
+    /// Recovery has expired once `RecoveryExpiry` blocks passed since it was initiated
+    fn recovery_expired(recovery: &ActiveRecoveryOf<T>) -> bool {
+        let now = <frame_system::Pallet<T>>::block_number();
+        let expiry = Self::_u64_to_block_saturated(T::RecoveryExpiry::get());
+        now >= recovery.initiated + expiry
+    }
+
+    /// A guardian can't block the recovery forever, expired recoveries are not in progress
+    fn recovery_in_progress(lost_account: &T::AccountId) -> bool {
+        <ActiveRecoveries<T>>::get(lost_account)
+            .is_some_and(|recovery| !Self::recovery_expired(&recovery))
+    }
+
+    pub(super) fn initiate_recovery_helper(
+        who: T::AccountId,
+        lost_account: T::AccountId,
+        new_account: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_guardian(&lost_account, &who)?;
+        ensure!(
+            !Self::recovery_in_progress(&lost_account),
+            Error::<T>::RecoveryExists
+        );
+        ensure!(
+            !<GetCitizenId<T>>::contains_key(&new_account),
+            Error::<T>::NewAccountIsCitizen
+        );
+        let now = <frame_system::Pallet<T>>::block_number();
+        <ActiveRecoveries<T>>::insert(
+            &lost_account,
+            ActiveRecovery {
+                new_account: new_account.clone(),
+                initiated: now,
+                approvals: vec![who.clone()],
+            },
+        );
+        Self::deposit_event(Event::RecoveryInitiated {
+            lost_account,
+            new_account,
+            guardian: who,
+        });
+        Ok(())
+    }
+
+    pub(super) fn approve_recovery_helper(
+        who: T::AccountId,
+        lost_account: T::AccountId,
+    ) -> DispatchResult {
+        Self::ensure_guardian(&lost_account, &who)?;
+        <ActiveRecoveries<T>>::try_mutate(&lost_account, |recovery| -> DispatchResult {
+            let recovery = recovery.as_mut().ok_or(Error::<T>::RecoveryNotExists)?;
+            ensure!(!Self::recovery_expired(recovery), Error::<T>::RecoveryExpired);
+            match recovery.approvals.binary_search(&who) {
+                Ok(_) => Err(Error::<T>::AlreadyApproved)?,
+                Err(index) => recovery.approvals.insert(index, who.clone()),
+            }
+            Ok(())
+        })?;
+        Self::deposit_event(Event::RecoveryApproved {
+            lost_account,
+            guardian: who,
+        });
+        Ok(())
+    }
+
+    /// Move the citizen to the new account once enough guardians approved and the veto delay is over,
+    /// before the recovery expires
+    /// Votes already cast in games stay with the lost account.
+    pub(super) fn finalize_recovery_helper(lost_account: T::AccountId) -> DispatchResult {
+        let recovery =
+            <ActiveRecoveries<T>>::get(&lost_account).ok_or(Error::<T>::RecoveryNotExists)?;
+        ensure!(!Self::recovery_expired(&recovery), Error::<T>::RecoveryExpired);
+        let config =
+            <RecoveryConfigs<T>>::get(&lost_account).ok_or(Error::<T>::RecoveryConfigNotExists)?;
+        ensure!(
+            recovery.approvals.len() >= config.threshold as usize,
+            Error::<T>::NotEnoughApprovals
+        );
+        let now = <frame_system::Pallet<T>>::block_number();
+        let delay = Self::_u64_to_block_saturated(T::RecoveryDelay::get());
+        ensure!(
+            now >= recovery.initiated + delay,
+            Error::<T>::RecoveryDelayNotOver
+        );
+        Self::ensure_no_fraud_challenge(&lost_account)?;
+        // Games are keyed by the lost account, a running validation game can't be moved
+        Self::ensure_validation_game_over(&lost_account)?;
+        let new_account = recovery.new_account;
+        ensure!(
+            !<GetCitizenId<T>>::contains_key(&new_account),
+            Error::<T>::NewAccountIsCitizen
+        );
+
+        let citizen_id =
+            <GetCitizenId<T>>::take(&lost_account).ok_or(Error::<T>::CitizenDoNotExists)?;
+        <GetCitizenId<T>>::insert(&new_account, citizen_id);
+        <CitizenAccount<T>>::insert(citizen_id, &new_account);
+        if let Some(mut profile) = <CitizenProfile<T>>::take(&lost_account) {
+            profile.owner = new_account.clone();
+            <CitizenProfile<T>>::insert(&new_account, profile);
+        }
+        Self::move_profile_history(&lost_account, &new_account);
+        if let Some(identity) = <IdentityStore<T>>::take(&lost_account) {
+            <IdentityStore<T>>::insert(&new_account, identity);
+        }
+        if let Some(expiry) = <CitizenshipExpiry<T>>::take(&lost_account) {
+            Self::set_citizenship_expiry(new_account.clone(), expiry);
+        }
+        // Renewal compares the validation block with the expiry
+        <ValidationBlock<T>>::insert(&new_account, <ValidationBlock<T>>::take(&lost_account));
+        T::KycSource::transfer_commitment_link(lost_account.clone(), new_account.clone())?;
+        T::SharedStorageSource::transfer_citizen_link(lost_account.clone(), new_account.clone())?;
+
+        <ActiveRecoveries<T>>::remove(&lost_account);
+        <RecoveryConfigs<T>>::remove(&lost_account);
+        <RecoveryConfigs<T>>::insert(&new_account, config);
+        Self::deposit_event(Event::CitizenRecovered {
+            lost_account,
+            new_account,
+        });
+        Ok(())
+    }
+}
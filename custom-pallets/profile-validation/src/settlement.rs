@@ -0,0 +1,219 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+    /// Share of `losing_total` for a deposit of the winning side, pro rata to the deposit
+    /// <pre>
+    /// share = losing_total * deposit / winning_total
+    /// </pre>
+    pub(super) fn pro_rata_share(
+        deposit: BalanceOf<T>,
+        winning_total: BalanceOf<T>,
+        losing_total: BalanceOf<T>,
+    ) -> BalanceOf<T> {
+        let deposit = deposit.saturated_into::<u128>();
+        let winning_total = winning_total.saturated_into::<u128>();
+        let losing_total = losing_total.saturated_into::<u128>();
+        losing_total
+            .saturating_mul(deposit)
+            .checked_div(winning_total)
+            .unwrap_or(0)
+            .saturated_into::<BalanceOf<T>>()
+    }
+
+    /// Total funds of the (winning, losing) side of the challenge
+    fn challenge_totals(
+        profile_user_account: &T::AccountId,
+        challenge_upheld: bool,
+    ) -> (BalanceOf<T>, BalanceOf<T>) {
+        let profile_total = <ProfileTotalFundCollected<T>>::get(profile_user_account);
+        let challenge_total = <ChallengeTotalFundCollected<T>>::get(profile_user_account);
+        if challenge_upheld {
+            (challenge_total, profile_total)
+        } else {
+            (profile_total, challenge_total)
+        }
+    }
+
+    /// Pay the juror share of the losing side into the reward pool, once per challenge
+    fn settle_challenge(profile_user_account: &T::AccountId, challenge_upheld: bool) {
+        let mut challenger_fund_info = match <ChallengerFundDetails<T>>::get(profile_user_account) {
+            Some(challenger_fund_info) => challenger_fund_info,
+            None => return,
+        };
+        if challenger_fund_info.challenge_completed {
+            return;
+        }
+        let (_, losing_total) = Self::challenge_totals(profile_user_account, challenge_upheld);
+        let juror_share = T::JurorShareOfLosingFund::get() * losing_total;
+        T::SchellingGameSharedSource::deposit_into_reward_pool_link(juror_share);
+        challenger_fund_info.challenge_completed = true;
+        <ChallengerFundDetails<T>>::insert(profile_user_account, challenger_fund_info);
+        Self::deposit_event(Event::ChallengeSettled {
+            profile: profile_user_account.clone(),
+            challenge_upheld,
+            juror_share,
+        });
+    }
+
+    /// Amount returned for a deposit of a profile funder or a challenger
+    /// The winning side gets its deposit and a pro rata share of the losing side funds without the
+    /// juror share, the losing side gets nothing. Deposits are returned when there is no winner,
+    /// or when the profile was not challenged before the time for staking was over.
+    fn settlement_amount(
+        profile_user_account: &T::AccountId,
+        deposit: BalanceOf<T>,
+        challenger: bool,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let block_number = <ValidationBlock<T>>::get(profile_user_account);
+        let key = SumTreeName::ProfileValidation {
+            citizen_address: profile_user_account.clone(),
+            block_number,
+        };
+        let now = <frame_system::Pallet<T>>::block_number();
+        let phase_data = Self::get_phase_data(&key);
+
+        let period = T::SchellingGameSharedSource::get_period_link(key.clone())
+            .ok_or(Error::<T>::ValidationGameNotOver)?;
+        match period {
+            Period::Execution => {
+                let decision: WinningDecision =
+                    T::SchellingGameSharedSource::get_winning_decision_value(key)?;
+                let challenge_upheld = match decision {
+                    WinningDecision::WinnerYes => true,
+                    WinningDecision::WinnerNo => false,
+                    WinningDecision::Draw | WinningDecision::Abstain => return Ok(deposit),
+                };
+                if challenge_upheld != challenger {
+                    if challenger {
+                        Err(Error::<T>::ChallengeLost)?
+                    } else {
+                        Err(Error::<T>::ProfileFundLost)?
+                    }
+                }
+                Self::settle_challenge(profile_user_account, challenge_upheld);
+                let (winning_total, losing_total) =
+                    Self::challenge_totals(profile_user_account, challenge_upheld);
+                let juror_share = T::JurorShareOfLosingFund::get() * losing_total;
+                let share = Self::pro_rata_share(
+                    deposit,
+                    winning_total,
+                    losing_total.saturating_sub(juror_share),
+                );
+                Ok(deposit.checked_add(&share).ok_or(Error::<T>::StorageOverflow)?)
+            }
+            Period::Evidence => {
+                T::SchellingGameSharedSource::ensure_time_for_staking_over_link(
+                    key, phase_data, now,
+                )?;
+                Ok(deposit)
+            }
+            _ => Err(Error::<T>::ValidationGameNotOver)?,
+        }
+    }
+
+    /// Pay the profile funders and challengers of a finished validation game that did not get
+    /// their deposits back and clear the funding, deposits of the losing side are kept
+    pub(super) fn settle_unreturned_deposits(
+        profile_user_account: &T::AccountId,
+    ) -> DispatchResult {
+        let funders =
+            <ProfileFundDetails<T>>::iter_prefix(profile_user_account).collect::<Vec<_>>();
+        for (funder, profile_fund_info) in funders {
+            if profile_fund_info.deposit_returned {
+                continue;
+            }
+            match Self::settlement_amount(profile_user_account, profile_fund_info.deposit, false) {
+                Ok(amount) => {
+                    let r = <T as pallet::Config>::Currency::deposit_creating(&funder, amount);
+                    <T as pallet::Config>::Reward::on_unbalanced(r);
+                    Self::deposit_event(Event::ProfileStakeReturned {
+                        profile: profile_user_account.clone(),
+                        funder,
+                        amount,
+                    });
+                }
+                Err(error) if error == Error::<T>::ProfileFundLost.into() => {}
+                Err(error) => Err(error)?,
+            }
+        }
+
+        let challengers =
+            <ChallengerFundContributions<T>>::iter_prefix(profile_user_account).collect::<Vec<_>>();
+        for (challenger, challenge_fund_info) in challengers {
+            if challenge_fund_info.deposit_returned {
+                continue;
+            }
+            match Self::settlement_amount(profile_user_account, challenge_fund_info.deposit, true) {
+                Ok(amount) => {
+                    let r = <T as pallet::Config>::Currency::deposit_creating(&challenger, amount);
+                    <T as pallet::Config>::Reward::on_unbalanced(r);
+                    Self::deposit_event(Event::ChallengeStakeReturned {
+                        profile: profile_user_account.clone(),
+                        challenger,
+                        amount,
+                    });
+                }
+                Err(error) if error == Error::<T>::ChallengeLost.into() => {}
+                Err(error) => Err(error)?,
+            }
+        }
+
+        let _ = <ProfileFundDetails<T>>::clear_prefix(profile_user_account, u32::MAX, None);
+        <ProfileTotalFundCollected<T>>::remove(profile_user_account);
+        <ProfileFundFee<T>>::remove(profile_user_account);
+        Self::clear_challenge(profile_user_account.clone());
+        Ok(())
+    }
+
+    pub(super) fn return_profile_stake_helper(
+        who: T::AccountId,
+        profile_user_account: T::AccountId,
+    ) -> DispatchResult {
+        let mut profile_fund_info =
+            <ProfileFundDetails<T>>::get(&profile_user_account, &who)
+                .ok_or(Error::<T>::ProfileFundNotExists)?;
+        ensure!(
+            !profile_fund_info.deposit_returned,
+            Error::<T>::ProfileFundAlreadyReturned
+        );
+        let amount =
+            Self::settlement_amount(&profile_user_account, profile_fund_info.deposit, false)?;
+
+        let r = <T as pallet::Config>::Currency::deposit_creating(&who, amount);
+        <T as pallet::Config>::Reward::on_unbalanced(r);
+        profile_fund_info.deposit_returned = true;
+        <ProfileFundDetails<T>>::insert(&profile_user_account, &who, profile_fund_info);
+        Self::deposit_event(Event::ProfileStakeReturned {
+            profile: profile_user_account,
+            funder: who,
+            amount,
+        });
+        Ok(())
+    }
+
+    pub(super) fn return_challenge_stake_helper(
+        who: T::AccountId,
+        profile_user_account: T::AccountId,
+    ) -> DispatchResult {
+        let mut challenge_fund_info =
+            <ChallengerFundContributions<T>>::get(&profile_user_account, &who)
+                .ok_or(Error::<T>::ChallengeFundNotExists)?;
+        ensure!(
+            !challenge_fund_info.deposit_returned,
+            Error::<T>::ChallengeFundAlreadyReturned
+        );
+        let amount =
+            Self::settlement_amount(&profile_user_account, challenge_fund_info.deposit, true)?;
+
+        let r = <T as pallet::Config>::Currency::deposit_creating(&who, amount);
+        <T as pallet::Config>::Reward::on_unbalanced(r);
+        challenge_fund_info.deposit_returned = true;
+        <ChallengerFundContributions<T>>::insert(&profile_user_account, &who, challenge_fund_info);
+        Self::deposit_event(Event::ChallengeStakeReturned {
+            profile: profile_user_account,
+            challenger: who,
+            amount,
+        });
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
-use crate::types::{CitizenDetailsPost, LocationDetails};
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
-use pallet_schelling_game_shared::types::Period;
+use crate::types::{
+	CitizenDetailsPost, IdentityAddressLength, IdentityData, IdentityNameLength, LocationDetails,
+	PhotoHashLength, MAX_PHASE_LENGTH,
+};
+use crate::{mock::*, CitizenStatus, Error, Event};
+use frame_support::{assert_noop, assert_ok, BoundedVec, Hashable};
+use pallet_schelling_game_shared::types::{CancellationReason, Period};
 use pallet_sortition_sum_game::types::SumTreeName;
 use pallet_support::Content;
 use pallet_support::WhoAndWhen;
@@ -190,7 +193,7 @@ fn challenge_evidence() {
 				.to_vec(),
 		);
 
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::validation_params();
 
 		assert_noop!(
 			ProfileValidation::challenge_profile(
@@ -257,7 +260,7 @@ fn challenge_profile_after_time_for_staking_over_test() {
 				.to_vec(),
 		);
 
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::validation_params();
 
 		assert_noop!(
 			ProfileValidation::challenge_profile(
@@ -311,7 +314,7 @@ fn return_profile_stake_test() {
 		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
 		let period = SchellingGameShared::get_period(key.clone());
 		assert_eq!(Some(Period::Evidence), period);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::validation_params();
 		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time);
 		assert_noop!(
 			ProfileValidation::return_profile_stake(RuntimeOrigin::signed(3), 1),
@@ -370,7 +373,7 @@ fn schelling_game_test() {
 				.as_bytes()
 				.to_vec(),
 		);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::validation_params();
 		System::set_block_number(phase_data.evidence_length + 1);
 		assert_ok!(ProfileValidation::challenge_profile(
 			RuntimeOrigin::signed(4),
@@ -409,7 +412,7 @@ fn schelling_game_test() {
 		assert_eq!(5, draws_in_round);
 
 		let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
-		assert_eq!(vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)], drawn_jurors);
+		assert_eq!(vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)], drawn_jurors);
 
 		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
 
@@ -429,23 +432,23 @@ fn schelling_game_test() {
 			<pallet_schelling_game_shared::Error<Test>>::JurorDoesNotExists
 		);
 		let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(4), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(13), 1, hash));
 
 		// You can replace vote within the commit period.
 		let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(4), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(13), 1, hash));
 
 		let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(7), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(17), 1, hash));
 
 		let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(13), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(23), 1, hash));
 
 		let hash = sp_io::hashing::keccak_256("1salt5".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(14), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(25), 1, hash));
 
 		let hash = sp_io::hashing::keccak_256("0salt6".as_bytes());
-		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(15), 1, hash));
+		assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(29), 1, hash));
 
 		assert_noop!(
 			ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1),
@@ -458,7 +461,7 @@ fn schelling_game_test() {
 
 		assert_noop!(
 			ProfileValidation::reveal_vote(
-				RuntimeOrigin::signed(4),
+				RuntimeOrigin::signed(13),
 				1,
 				2,
 				"salt2".as_bytes().to_vec()
@@ -467,28 +470,28 @@ fn schelling_game_test() {
 		);
 
 		assert_ok!(ProfileValidation::reveal_vote(
-			RuntimeOrigin::signed(4),
+			RuntimeOrigin::signed(13),
 			1,
 			1,
 			"salt2".as_bytes().to_vec()
 		));
 
 		assert_ok!(ProfileValidation::reveal_vote(
-			RuntimeOrigin::signed(7),
+			RuntimeOrigin::signed(17),
 			1,
 			1,
 			"salt3".as_bytes().to_vec()
 		));
 
 		assert_ok!(ProfileValidation::reveal_vote(
-			RuntimeOrigin::signed(13),
+			RuntimeOrigin::signed(23),
 			1,
 			1,
 			"salt4".as_bytes().to_vec()
 		));
 
 		assert_ok!(ProfileValidation::reveal_vote(
-			RuntimeOrigin::signed(14),
+			RuntimeOrigin::signed(25),
 			1,
 			1,
 			"salt5".as_bytes().to_vec()
@@ -507,13 +510,26 @@ fn schelling_game_test() {
 		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
 
 		assert_noop!(
-			ProfileValidation::get_incentives(RuntimeOrigin::signed(15), 1),
+			ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1),
+			<pallet_schelling_game_shared::Error<Test>>::AppealPeriodNotOver
+		);
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		assert_noop!(
+			ProfileValidation::get_incentives(RuntimeOrigin::signed(29), 1),
 			<pallet_schelling_game_shared::Error<Test>>::VoteNotRevealed
 		);
-		let balance: u64 = Balances::free_balance(14);
-		assert_eq!(300000 - 14 * 100, balance);
-		assert_ok!(ProfileValidation::get_incentives(RuntimeOrigin::signed(14), 1));
-		let balance: u64 = Balances::free_balance(14);
+		let balance: u64 = Balances::free_balance(25);
+		assert_eq!(300000 - 25 * 100, balance);
+		assert_ok!(ProfileValidation::get_incentives(RuntimeOrigin::signed(25), 1));
+		let balance: u64 = Balances::free_balance(25);
 		assert_eq!(300025, balance);
 	})
 }
@@ -545,7 +561,7 @@ fn test_draw_juror() {
 				.as_bytes()
 				.to_vec(),
 		);
-		let phase_data = ProfileValidation::get_phase_data();
+		let phase_data = ProfileValidation::validation_params();
 		System::set_block_number(phase_data.evidence_length + 1);
 		assert_ok!(ProfileValidation::challenge_profile(
 			RuntimeOrigin::signed(4),
@@ -568,3 +584,1462 @@ fn test_draw_juror() {
 		// assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
 	})
 }
+
+#[test]
+fn cancel_profile_validation_refunds_all() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location.clone()
+		));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(RuntimeOrigin::signed(4), 1, content));
+		assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(5), 1, 500));
+		assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(6), 1, 600));
+		assert_eq!(Balances::free_balance(3), 300000 - 1000);
+		assert_eq!(Balances::free_balance(4), 300000 - 100);
+		assert_eq!(Balances::free_balance(5), 300000 - 500);
+
+		assert_noop!(
+			ProfileValidation::cancel_profile_validation(
+				RuntimeOrigin::signed(7),
+				1,
+				CancellationReason::Governance
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ProfileValidation::cancel_profile_validation(
+				RuntimeOrigin::signed(7),
+				1,
+				CancellationReason::NotEnoughJurorsStaked
+			),
+			<pallet_schelling_game_shared::Error<Test>>::StakingPeriodNotOver
+		);
+
+		System::set_block_number(phase_data.evidence_length + 1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::cancel_profile_validation(
+			RuntimeOrigin::signed(7),
+			1,
+			CancellationReason::NotEnoughJurorsStaked
+		));
+		System::assert_last_event(
+			Event::ProfileValidationCancelled {
+				profile: 1,
+				reason: CancellationReason::NotEnoughJurorsStaked,
+			}
+			.into(),
+		);
+
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		assert_eq!(SchellingGameShared::get_period(key.clone()), Some(Period::Cancelled));
+		assert_eq!(
+			SchellingGameShared::cancellation_reason(key),
+			Some(CancellationReason::NotEnoughJurorsStaked)
+		);
+		assert_eq!(Balances::free_balance(3), 300000);
+		assert_eq!(Balances::free_balance(4), 300000);
+		assert_eq!(Balances::free_balance(5), 300000);
+		assert_eq!(Balances::free_balance(6), 300000);
+		assert_eq!(ProfileValidation::total_fund_for_profile_collected(1), 0);
+		assert_eq!(ProfileValidation::challenger_fund(1), None);
+
+		assert_noop!(
+			ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1),
+			<pallet_schelling_game_shared::Error<Test>>::GameCancelled
+		);
+	});
+}
+
+#[test]
+fn recover_citizen_to_new_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let commitment = [7u8; 32];
+		let proof = pallet_kyc::HashProofVerifier::proof(&1u64, &commitment, 2000).to_vec();
+		assert_ok!(Kyc::register_kyc(RuntimeOrigin::signed(1), proof, commitment, 2000));
+		let phase_data = ProfileValidation::validation_params();
+		let game_length = phase_data.evidence_length + phase_data.end_of_staking_time + 1;
+		System::set_block_number(game_length);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		pallet_shared_storage::ApprovedCitizenAddress::<Test>::put(vec![1, 2, 3, 4]);
+		assert_ok!(SharedStorage::set_department_reputation_score(1, 0, 5));
+
+		assert_noop!(
+			ProfileValidation::set_recovery_config(RuntimeOrigin::signed(1), vec![2, 3], 3),
+			Error::<Test>::InvalidRecoveryThreshold
+		);
+		assert_noop!(
+			ProfileValidation::set_recovery_config(RuntimeOrigin::signed(1), vec![2, 5], 2),
+			Error::<Test>::InvalidGuardian
+		);
+		assert_ok!(ProfileValidation::set_recovery_config(
+			RuntimeOrigin::signed(1),
+			vec![4, 2, 3],
+			2
+		));
+
+		assert_noop!(
+			ProfileValidation::initiate_recovery(RuntimeOrigin::signed(5), 1, 10),
+			Error::<Test>::NotAGuardian
+		);
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(2), 1, 10));
+		assert_noop!(
+			ProfileValidation::finalize_recovery(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::NotEnoughApprovals
+		);
+
+		// Old key vetoes the recovery
+		assert_ok!(ProfileValidation::veto_recovery(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::RecoveryVetoed { lost_account: 1 }.into());
+		assert_eq!(ProfileValidation::active_recovery(1), None);
+
+		// Recovery of a single guardian blocks others until it expires
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(4), 1, 11));
+		assert_noop!(
+			ProfileValidation::initiate_recovery(RuntimeOrigin::signed(2), 1, 10),
+			Error::<Test>::RecoveryExists
+		);
+		System::set_block_number(game_length + 30);
+		assert_noop!(
+			ProfileValidation::approve_recovery(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::RecoveryExpired
+		);
+		assert_noop!(
+			ProfileValidation::finalize_recovery(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::RecoveryExpired
+		);
+
+		assert_ok!(ProfileValidation::initiate_recovery(RuntimeOrigin::signed(2), 1, 10));
+		assert_eq!(ProfileValidation::active_recovery(1).unwrap().approvals, vec![2]);
+		assert_noop!(
+			ProfileValidation::approve_recovery(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_ok!(ProfileValidation::approve_recovery(RuntimeOrigin::signed(3), 1));
+		assert_noop!(
+			ProfileValidation::finalize_recovery(RuntimeOrigin::signed(7), 1),
+			Error::<Test>::RecoveryDelayNotOver
+		);
+
+		System::set_block_number(game_length + 40);
+		assert_ok!(ProfileValidation::finalize_recovery(RuntimeOrigin::signed(7), 1));
+		System::assert_last_event(Event::CitizenRecovered { lost_account: 1, new_account: 10 }.into());
+
+		assert_eq!(ProfileValidation::get_citizen_id(1), None);
+		assert_eq!(ProfileValidation::get_citizen_id(10), Some(1));
+		assert_eq!(ProfileValidation::citizen_profile(1), None);
+		assert_eq!(ProfileValidation::citizen_profile(10).unwrap().owner, 10);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![2, 3, 4, 10]);
+		assert_eq!(SharedStorage::get_department_reputation_score(10, 0), Some(5));
+		assert_eq!(SharedStorage::get_department_reputation_score(1, 0), None);
+		assert_eq!(ProfileValidation::recovery_config(10).unwrap().guardians, vec![2, 3, 4]);
+		assert_eq!(ProfileValidation::active_recovery(1), None);
+		assert_eq!(ProfileValidation::validation_block(10), 1);
+		assert_eq!(Kyc::commitment_of(1), None);
+		assert_eq!(Kyc::commitment_of(10), Some(commitment));
+		assert_eq!(Kyc::commitment(commitment), Some((10, 2000)));
+
+		// The new account renews the citizenship with the moved KYC commitment
+		assert_ok!(ProfileValidation::set_require_kyc_commitment(RuntimeOrigin::root(), true));
+		System::set_block_number(1001);
+		assert_ok!(ProfileValidation::renew_citizenship(RuntimeOrigin::signed(10)));
+		System::set_block_number(1001 + game_length);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(10)));
+		System::assert_last_event(Event::CitizenshipRenewed { citizen: 10, expiry: 2001 }.into());
+	});
+}
+
+#[test]
+fn fraud_challenge_revokes_approved_citizen() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location
+		));
+		assert_noop!(
+			ProfileValidation::challenge_approved_citizen(RuntimeOrigin::signed(2), 1, content.clone()),
+			<pallet_shared_storage::Error<Test>>::CitizenNotApproved
+		);
+		pallet_shared_storage::ApprovedCitizenAddress::<Test>::put(vec![1]);
+		assert_ok!(SharedStorage::set_department_reputation_score(1, 0, 5));
+
+		assert_ok!(ProfileValidation::challenge_approved_citizen(
+			RuntimeOrigin::signed(2),
+			1,
+			content.clone()
+		));
+		System::assert_last_event(Event::FraudChallenged { citizen: 1, challenger: 2 }.into());
+		assert_eq!(Balances::free_balance(2), 200000 - 100);
+		assert_noop!(
+			ProfileValidation::challenge_approved_citizen(RuntimeOrigin::signed(3), 1, content),
+			Error::<Test>::FraudChallengeExists
+		);
+
+		// Fraud game has its own key, the validation block is kept
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		assert_eq!(ProfileValidation::fraud_game_block(1), Some(1));
+		assert_eq!(ProfileValidation::validation_block(1), 0);
+		assert_eq!(SchellingGameShared::get_period(key.clone()), Some(Period::Staking));
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
+		assert_eq!(drawn_jurors.len(), 5);
+		for (juror, _) in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(1 + phase_data.staking_length + phase_data.commit_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				1,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			1 + phase_data.staking_length + phase_data.commit_length + phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_noop!(
+			ProfileValidation::resolve_fraud_challenge(RuntimeOrigin::signed(5), 1),
+			<pallet_schelling_game_shared::Error<Test>>::PeriodDontMatch
+		);
+		System::set_block_number(
+			1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		assert_ok!(ProfileValidation::resolve_fraud_challenge(RuntimeOrigin::signed(5), 1));
+		System::assert_last_event(Event::FraudChallengeResolved { citizen: 1, revoked: true }.into());
+		assert_eq!(SharedStorage::approved_citizen_address(), Vec::<u64>::new());
+		assert!(SharedStorage::is_reputation_frozen(1));
+		assert_noop!(
+			SharedStorage::set_department_reputation_score(1, 0, 10),
+			<pallet_shared_storage::Error<Test>>::ReputationFrozen
+		);
+		assert_eq!(SharedStorage::get_department_reputation_score(1, 0), Some(5));
+		// Deposit is returned, the reward is paid from the juror reward pool
+		assert_eq!(Balances::free_balance(2), 200000 + 100);
+		assert!(ProfileValidation::fraud_challenge(1).unwrap().settled);
+		assert_eq!(ProfileValidation::get_citizen_status(1), Some(CitizenStatus::Revoked));
+		assert_noop!(
+			ProfileValidation::resolve_fraud_challenge(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::FraudChallengeNotExists
+		);
+
+		let (juror, _) = drawn_jurors[0];
+		assert_ok!(ProfileValidation::get_incentives(RuntimeOrigin::signed(juror), 1));
+	});
+}
+
+#[test]
+fn revoked_citizen_cannot_reapply() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location
+		));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time + 1);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+
+		let start = 200;
+		System::set_block_number(start);
+		assert_ok!(ProfileValidation::challenge_approved_citizen(
+			RuntimeOrigin::signed(2),
+			1,
+			content
+		));
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		System::set_block_number(start + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: start };
+		let drawn_jurors = SchellingGameShared::drawn_jurors(key);
+		for (juror, _) in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(start + phase_data.staking_length + phase_data.commit_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				1,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			start + phase_data.staking_length + phase_data.commit_length + phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		let revoked_at = start
+			+ phase_data.staking_length
+			+ phase_data.commit_length
+			+ phase_data.vote_length
+			+ phase_data.appeal_length;
+		System::set_block_number(revoked_at);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::resolve_fraud_challenge(RuntimeOrigin::signed(5), 1));
+		assert_eq!(ProfileValidation::revoked_citizen(1), Some(revoked_at));
+
+		// The validation game of the revoked citizen was played before the revocation
+		assert_noop!(
+			ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)),
+			Error::<Test>::CitizenRevoked
+		);
+		assert_eq!(SharedStorage::approved_citizen_address(), Vec::<u64>::new());
+		assert_eq!(ProfileValidation::get_citizen_status(1), Some(CitizenStatus::Revoked));
+	});
+}
+
+#[test]
+fn cancel_fraud_challenge_refunds_challenger() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location
+		));
+		pallet_shared_storage::ApprovedCitizenAddress::<Test>::put(vec![1]);
+		assert_ok!(ProfileValidation::challenge_approved_citizen(
+			RuntimeOrigin::signed(2),
+			1,
+			content.clone()
+		));
+		assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(4), 1, 400));
+		assert_eq!(Balances::free_balance(2), 200000 - 100);
+		assert_eq!(Balances::free_balance(4), 300000 - 400);
+
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::cancel_profile_validation(
+			RuntimeOrigin::signed(7),
+			1,
+			CancellationReason::NotEnoughJurorsStaked
+		));
+
+		// The fraud game is cancelled, not the validation game
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Cancelled));
+		assert_eq!(Balances::free_balance(2), 200000);
+		assert_eq!(Balances::free_balance(4), 300000);
+		assert_eq!(ProfileValidation::fraud_challenge(1), None);
+		assert_eq!(ProfileValidation::fraud_game_block(1), None);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+
+		assert_ok!(ProfileValidation::challenge_approved_citizen(
+			RuntimeOrigin::signed(2),
+			1,
+			content
+		));
+		assert_eq!(ProfileValidation::fraud_game_block(1), Some(1 + phase_data.staking_length));
+	});
+}
+
+#[test]
+fn add_to_kyc_accounts_requires_live_commitment() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(1_000_000);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time + 1);
+
+		assert_noop!(
+			ProfileValidation::set_require_kyc_commitment(RuntimeOrigin::signed(1), true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProfileValidation::set_require_kyc_commitment(RuntimeOrigin::root(), true));
+		assert_noop!(
+			ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)),
+			<pallet_kyc::Error<Test>>::NoCommitment
+		);
+
+		let commitment = [7u8; 32];
+		let proof = pallet_kyc::HashProofVerifier::proof(&1u64, &commitment, 2000).to_vec();
+		assert_ok!(Kyc::register_kyc(RuntimeOrigin::signed(1), proof, commitment, 2000));
+		Timestamp::set_timestamp(2_000_000);
+		assert_noop!(
+			ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)),
+			<pallet_kyc::Error<Test>>::KycExpired
+		);
+
+		let proof = pallet_kyc::HashProofVerifier::proof(&1u64, &commitment, 3000).to_vec();
+		assert_ok!(Kyc::extend_kyc(RuntimeOrigin::signed(1), proof, commitment, 3000));
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+	});
+}
+
+#[test]
+fn jurors_attest_identity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location
+		));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		let start = phase_data.evidence_length + 1;
+		System::set_block_number(start);
+		assert_ok!(ProfileValidation::challenge_profile(RuntimeOrigin::signed(4), 1, content));
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		System::set_block_number(start + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		let drawn_jurors: Vec<u64> =
+			SchellingGameShared::drawn_jurors(key).into_iter().map(|(juror, _)| juror).collect();
+		for juror in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("0salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(start + phase_data.staking_length + phase_data.commit_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for juror in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				0,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			start + phase_data.staking_length + phase_data.commit_length + phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		let name: BoundedVec<u8, IdentityNameLength> = b"Alice".to_vec().try_into().unwrap();
+		let address: BoundedVec<u8, IdentityAddressLength> =
+			b"123 Main St".to_vec().try_into().unwrap();
+		let photo_hash: BoundedVec<u8, PhotoHashLength> =
+			b"bafkreiphoto".to_vec().try_into().unwrap();
+		assert_noop!(
+			ProfileValidation::attest_identity(
+				RuntimeOrigin::signed(drawn_jurors[0]),
+				1,
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			<pallet_schelling_game_shared::Error<Test>>::PeriodDontMatch
+		);
+		System::set_block_number(
+			start
+				+ phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		assert_noop!(
+			ProfileValidation::attest_identity(
+				RuntimeOrigin::signed(30),
+				1,
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			Error::<Test>::NotDrawnJuror
+		);
+		// A juror attesting other data doesn't count for the majority
+		assert_ok!(ProfileValidation::attest_identity(
+			RuntimeOrigin::signed(drawn_jurors[4]),
+			1,
+			b"Bob".to_vec().try_into().unwrap(),
+			address.clone(),
+			photo_hash.clone()
+		));
+		// Attesting again replaces the pending identity of the juror
+		assert_ok!(ProfileValidation::attest_identity(
+			RuntimeOrigin::signed(drawn_jurors[4]),
+			1,
+			b"Carol".to_vec().try_into().unwrap(),
+			address.clone(),
+			photo_hash.clone()
+		));
+		let bob = IdentityData {
+			name: b"Bob".to_vec().try_into().unwrap(),
+			address: address.clone(),
+			photo_hash: photo_hash.clone(),
+			validator_approved: true,
+		};
+		assert!(ProfileValidation::identity_attestations(1, bob.blake2_256()).is_empty());
+		assert_eq!(
+			ProfileValidation::pending_identity(1, drawn_jurors[4]).map(|identity| identity.name),
+			Some(b"Carol".to_vec().try_into().unwrap())
+		);
+		for juror in drawn_jurors[..2].iter() {
+			assert_ok!(ProfileValidation::attest_identity(
+				RuntimeOrigin::signed(*juror),
+				1,
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			));
+		}
+		assert_noop!(
+			ProfileValidation::attest_identity(
+				RuntimeOrigin::signed(drawn_jurors[0]),
+				1,
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			Error::<Test>::AlreadyAttested
+		);
+		assert_eq!(ProfileValidation::identity_data(1), None);
+
+		// Citizen appeals only after the jurors had the time to attest
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			ProfileValidation::appeal_identity(
+				RuntimeOrigin::signed(1),
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			Error::<Test>::IdentityAttestationNotOver
+		);
+		System::set_block_number(1 + IdentityAttestationPeriodProfileValidation::get() + 1);
+		assert_ok!(ProfileValidation::appeal_identity(
+			RuntimeOrigin::signed(1),
+			name.clone(),
+			address.clone(),
+			photo_hash.clone()
+		));
+
+		assert_ok!(ProfileValidation::attest_identity(
+			RuntimeOrigin::signed(drawn_jurors[2]),
+			1,
+			name.clone(),
+			address.clone(),
+			photo_hash.clone()
+		));
+		let identity = IdentityData { name, address, photo_hash, validator_approved: true };
+		System::assert_last_event(
+			Event::IdentityStored { citizen: 1, identity_hash: identity.blake2_256() }.into(),
+		);
+		assert_eq!(ProfileValidation::identity_data(1), Some(identity));
+		assert_eq!(ProfileValidation::identity_appeal(1), None);
+		assert_eq!(ProfileValidation::pending_identity(1, drawn_jurors[4]), None);
+	});
+}
+
+#[test]
+fn appeal_identity_to_governance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let name: BoundedVec<u8, IdentityNameLength> = b"Alice".to_vec().try_into().unwrap();
+		let address: BoundedVec<u8, IdentityAddressLength> =
+			b"123 Main St".to_vec().try_into().unwrap();
+		let photo_hash: BoundedVec<u8, PhotoHashLength> =
+			b"bafkreiphoto".to_vec().try_into().unwrap();
+		assert_noop!(
+			ProfileValidation::appeal_identity(
+				RuntimeOrigin::signed(1),
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			<pallet_shared_storage::Error<Test>>::CitizenNotApproved
+		);
+		pallet_shared_storage::ApprovedCitizenAddress::<Test>::put(vec![1]);
+		assert_ok!(ProfileValidation::appeal_identity(
+			RuntimeOrigin::signed(1),
+			name.clone(),
+			address.clone(),
+			photo_hash.clone()
+		));
+		assert_noop!(
+			ProfileValidation::appeal_identity(
+				RuntimeOrigin::signed(1),
+				name.clone(),
+				address.clone(),
+				photo_hash.clone()
+			),
+			Error::<Test>::IdentityAppealExists
+		);
+		assert_noop!(
+			ProfileValidation::resolve_identity_appeal(RuntimeOrigin::signed(2), 1, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(ProfileValidation::resolve_identity_appeal(RuntimeOrigin::root(), 1, true));
+		assert_eq!(
+			ProfileValidation::identity_data(1),
+			Some(IdentityData { name, address, photo_hash, validator_approved: false })
+		);
+		assert_eq!(ProfileValidation::identity_appeal(1), None);
+	});
+}
+
+#[test]
+fn citizenship_expires_unless_renewed() {
+	use frame_support::traits::Hooks;
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		let game_length = phase_data.evidence_length + phase_data.end_of_staking_time + 1;
+		System::set_block_number(game_length);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(ProfileValidation::citizenship_expiry(1), Some(1001));
+		assert_eq!(ProfileValidation::get_upcoming_expirations(1000, 10), vec![(1, 1001)]);
+
+		assert_noop!(
+			ProfileValidation::renew_citizenship(RuntimeOrigin::signed(1)),
+			Error::<Test>::CitizenshipNotExpired
+		);
+
+		// Renew once the citizenship expired, the grace period ends at 1201
+		System::set_block_number(1001);
+		assert_ok!(ProfileValidation::renew_citizenship(RuntimeOrigin::signed(1)));
+		assert_eq!(ProfileValidation::validation_block(1), 1001);
+		assert_noop!(
+			ProfileValidation::renew_citizenship(RuntimeOrigin::signed(1)),
+			Error::<Test>::RevalidationStarted
+		);
+		System::set_block_number(1001 + game_length);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(ProfileValidation::citizenship_expiry(1), Some(2001));
+		System::assert_last_event(Event::CitizenshipRenewed { citizen: 1, expiry: 2001 }.into());
+
+		ProfileValidation::on_initialize(1201);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+
+		// Not renewed, demoted after the grace period
+		ProfileValidation::on_initialize(2201);
+		assert_eq!(SharedStorage::approved_citizen_address(), Vec::<u64>::new());
+		System::assert_last_event(Event::CitizenshipExpired { citizen: 1 }.into());
+	});
+}
+
+#[test]
+fn migrate_citizenship_expiry() {
+	use crate::migrations::v1::InnerMigrateV0ToV1;
+	use frame_support::traits::{Hooks, UncheckedOnRuntimeUpgrade};
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		for citizen in 1..6 {
+			assert_ok!(ProfileValidation::add_citizen(
+				RuntimeOrigin::signed(citizen),
+				content.clone(),
+				location.clone()
+			));
+		}
+		// Approved before the expiry existed, 3 is not approved
+		pallet_shared_storage::ApprovedCitizenAddress::<Test>::put(vec![1, 2, 4, 5]);
+		crate::ValidationBlock::<Test>::insert(1, 500);
+
+		System::set_block_number(1300);
+		InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(ProfileValidation::citizenship_expiry(1), Some(1500));
+		assert_eq!(ProfileValidation::citizenship_expiry(2), Some(1000));
+		assert_eq!(ProfileValidation::citizenship_expiry(3), None);
+		assert_eq!(ProfileValidation::demotion_queue(1700).to_vec(), vec![1]);
+
+		// Grace period of 2, 4 and 5 is over, the full queue spills into the next block
+		assert_eq!(ProfileValidation::demotion_queue(1301).len(), 2);
+		assert_eq!(ProfileValidation::demotion_queue(1302).len(), 1);
+		ProfileValidation::on_initialize(1301);
+		assert_eq!(SharedStorage::approved_citizen_address().len(), 2);
+		ProfileValidation::on_initialize(1302);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+	});
+}
+
+#[test]
+fn migrate_citizen_account_index() {
+	use crate::migrations::v2::InnerMigrateV1ToV2;
+	use frame_support::traits::UncheckedOnRuntimeUpgrade;
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		for citizen in 1..4 {
+			assert_ok!(ProfileValidation::add_citizen(
+				RuntimeOrigin::signed(citizen),
+				content.clone(),
+				location.clone()
+			));
+		}
+		// Citizens added before the reverse index existed
+		let _ = crate::CitizenAccount::<Test>::clear(u32::MAX, None);
+		assert_eq!(ProfileValidation::citizen_account(1), None);
+
+		InnerMigrateV1ToV2::<Test>::on_runtime_upgrade();
+		for citizen in 1..4 {
+			let citizen_id = ProfileValidation::get_citizen_id(citizen).unwrap();
+			assert_eq!(ProfileValidation::citizen_account(citizen_id), Some(citizen));
+		}
+	});
+}
+
+#[test]
+fn crowdfunded_challenge() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location.clone()
+		));
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(2), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 2, 1000));
+		let challenge_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		let fees = ProfileValidation::profile_registration_challenge_fees();
+
+		// Challengers contribute during the evidence period
+		assert_noop!(
+			ProfileValidation::fund_challenge(RuntimeOrigin::signed(4), 1, 0),
+			Error::<Test>::ZeroChallengeFund
+		);
+		assert_ok!(ProfileValidation::fund_challenge(RuntimeOrigin::signed(4), 1, 40));
+		assert_ok!(ProfileValidation::fund_challenge(RuntimeOrigin::signed(4), 2, 40));
+		assert_noop!(
+			ProfileValidation::fund_challenge(RuntimeOrigin::signed(5), 1, fees),
+			Error::<Test>::AmountFundedGreaterThanRequired
+		);
+		assert_ok!(ProfileValidation::add_challenge_evidence(
+			RuntimeOrigin::signed(4),
+			1,
+			challenge_content.clone()
+		));
+		assert_noop!(
+			ProfileValidation::add_challenge_evidence(
+				RuntimeOrigin::signed(6),
+				1,
+				challenge_content.clone()
+			),
+			Error::<Test>::NotAChallenger
+		);
+
+		// Remaining fee completes the fund and starts the challenge
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(5),
+			1,
+			challenge_content.clone()
+		));
+		assert_eq!(Balances::free_balance(5), 300000 - (fees - 40));
+		assert_eq!(ProfileValidation::total_fund_for_challenge_collected(1), fees);
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Staking));
+		assert_noop!(
+			ProfileValidation::fund_challenge(RuntimeOrigin::signed(6), 1, 10),
+			Error::<Test>::ChallengeExits
+		);
+
+		// Fund that was not completed is returned once the time for staking is over
+		assert_noop!(
+			ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(4), 2),
+			<pallet_schelling_game_shared::Error<Test>>::TimeForStakingNotOver
+		);
+		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time + 1);
+		assert_noop!(
+			ProfileValidation::fund_challenge(RuntimeOrigin::signed(5), 2, 10),
+			Error::<Test>::ChallengeFundingClosed
+		);
+		assert_ok!(ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(4), 2));
+		assert_eq!(Balances::free_balance(4), 300000 - 40);
+		assert_noop!(
+			ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(4), 2),
+			Error::<Test>::ChallengeFundAlreadyReturned
+		);
+	});
+}
+
+#[test]
+fn settle_upheld_challenge() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let challenge_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_ok!(ProfileValidation::fund_challenge(RuntimeOrigin::signed(4), 1, 40));
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(5),
+			1,
+			challenge_content
+		));
+
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		System::set_block_number(phase_data.evidence_length + 1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
+		for (juror, _) in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(
+			phase_data.evidence_length + 1 + phase_data.staking_length + phase_data.commit_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				1,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Execution));
+
+		// Profile fund of 1000 without the juror share of 10% is shared by the challengers
+		assert_noop!(
+			ProfileValidation::return_profile_stake(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::ProfileFundLost
+		);
+		let balance = Balances::free_balance(4);
+		assert_ok!(ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(4), 1));
+		assert_eq!(Balances::free_balance(4), balance + 40 + 360);
+		System::assert_has_event(
+			Event::ChallengeSettled { profile: 1, challenge_upheld: true, juror_share: 100 }
+				.into(),
+		);
+		let balance = Balances::free_balance(5);
+		assert_ok!(ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(5), 1));
+		assert_eq!(Balances::free_balance(5), balance + 60 + 540);
+		System::assert_last_event(
+			Event::ChallengeStakeReturned { profile: 1, challenger: 5, amount: 600 }.into(),
+		);
+		assert_noop!(
+			ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::ChallengeFundAlreadyReturned
+		);
+	});
+}
+
+#[test]
+fn abstain_ruling_reopens_validation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let challenge_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(5),
+			1,
+			challenge_content
+		));
+
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		System::set_block_number(phase_data.evidence_length + 1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
+		for (juror, _) in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("2salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(
+			phase_data.evidence_length + 1 + phase_data.staking_length + phase_data.commit_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				2,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Execution));
+
+		// No winner, the funder and the challenger get their deposits back
+		let funder_balance = Balances::free_balance(3);
+		let challenger_balance = Balances::free_balance(5);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		System::assert_last_event(Event::ProfileValidationReopened { profile: 1 }.into());
+		assert_eq!(Balances::free_balance(3), funder_balance + 1000);
+		assert_eq!(Balances::free_balance(5), challenger_balance + 100);
+		assert_eq!(ProfileValidation::get_citizen_status(1), Some(CitizenStatus::Pending));
+		assert_noop!(
+			ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProfileValidationAlreadyReopened
+		);
+
+		// Profile is funded again for a new validation game
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		assert_eq!(ProfileValidation::validation_block(1), System::block_number());
+	});
+}
+
+#[test]
+fn update_profile_validation_params() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let mut params = ProfileValidation::validation_params();
+		assert_eq!(params.registration_fee, 1000);
+		assert_eq!(params.evidence_length, 50);
+		params.registration_fee = 2000;
+		params.registration_challenge_fee = 200;
+		params.evidence_length = 20;
+
+		assert_noop!(
+			ProfileValidation::set_profile_validation_params(
+				RuntimeOrigin::signed(1),
+				params.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		let mut invalid_params = params.clone();
+		invalid_params.commit_length = MAX_PHASE_LENGTH + 1;
+		assert_noop!(
+			ProfileValidation::set_profile_validation_params(RuntimeOrigin::root(), invalid_params),
+			Error::<Test>::InvalidProfileValidationParams
+		);
+		let mut invalid_params = params.clone();
+		invalid_params.registration_fee = 0;
+		assert_noop!(
+			ProfileValidation::set_profile_validation_params(RuntimeOrigin::root(), invalid_params),
+			Error::<Test>::InvalidProfileValidationParams
+		);
+		let mut invalid_params = params.clone();
+		invalid_params.min_number_juror_staked = invalid_params.max_draws - 1;
+		assert_noop!(
+			ProfileValidation::set_profile_validation_params(RuntimeOrigin::root(), invalid_params),
+			Error::<Test>::InvalidProfileValidationParams
+		);
+		let mut invalid_params = params.clone();
+		invalid_params.end_of_staking_time = invalid_params.staking_length + 1;
+		assert_noop!(
+			ProfileValidation::set_profile_validation_params(RuntimeOrigin::root(), invalid_params),
+			Error::<Test>::InvalidProfileValidationParams
+		);
+
+		assert_ok!(ProfileValidation::set_profile_validation_params(
+			RuntimeOrigin::root(),
+			params.clone()
+		));
+		assert_eq!(ProfileValidation::profile_registration_fees(), 2000);
+		assert_eq!(ProfileValidation::profile_registration_challenge_fees(), 200);
+		assert_eq!(ProfileValidation::validation_params().evidence_length, 20);
+
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_noop!(
+			ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 2001),
+			Error::<Test>::AmountFundedGreaterThanRequired
+		);
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1500));
+		assert_eq!(ProfileValidation::profile_fund_fee(1), Some(2000));
+
+		// The fee of the profile is kept when the fee is lowered during its funding
+		params.registration_fee = 1000;
+		assert_ok!(ProfileValidation::set_profile_validation_params(
+			RuntimeOrigin::root(),
+			params.clone()
+		));
+		assert_eq!(ProfileValidation::profile_fund_required(1), Some(500));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(4), 1, 500));
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		assert_eq!(SchellingGameShared::get_period(key.clone()), Some(Period::Evidence));
+		assert_noop!(
+			ProfileValidation::add_profile_stake(RuntimeOrigin::signed(4), 1, 0),
+			Error::<Test>::ProfileFundExists
+		);
+
+		// The game keeps the phase lengths from when it started
+		params.evidence_length = 40;
+		assert_ok!(ProfileValidation::set_profile_validation_params(RuntimeOrigin::root(), params));
+		assert_eq!(ProfileValidation::game_params(&key).unwrap().evidence_length, 20);
+		System::set_block_number(21);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(5),
+			1,
+			Content::None
+		));
+	});
+}
+
+#[test]
+fn edit_profile_keeps_history() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location.clone()
+		));
+		let new_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		assert_noop!(
+			ProfileValidation::edit_citizen_profile(
+				RuntimeOrigin::signed(1),
+				new_content.clone(),
+				location.clone()
+			),
+			<pallet_shared_storage::Error<Test>>::CitizenNotApproved
+		);
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let phase_data = ProfileValidation::validation_params();
+		let game_length = phase_data.evidence_length + phase_data.end_of_staking_time + 1;
+		System::set_block_number(game_length);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+
+		assert_ok!(ProfileValidation::edit_citizen_profile(
+			RuntimeOrigin::signed(1),
+			new_content.clone(),
+			location.clone()
+		));
+		assert_eq!(ProfileValidation::profile_version(1), 1);
+		let entry = ProfileValidation::profile_history(1, 0).unwrap();
+		assert_eq!(entry.content, content);
+		let profile = ProfileValidation::citizen_profile(1).unwrap();
+		assert_eq!(profile.content, new_content);
+		assert!(profile.edited);
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+		assert_ok!(SharedStorage::create_department(
+			RuntimeOrigin::root(),
+			BoundedVec::try_from("Odisha".as_bytes().to_vec()).unwrap(),
+			pallet_shared_storage::types::DepartmentType::District
+		));
+		assert_ok!(SharedStorage::add_member_to_department(RuntimeOrigin::signed(1), 0, 1));
+
+		// Location change requires a re-validation game
+		assert_ok!(ProfileValidation::set_revalidate_identity_edits(RuntimeOrigin::root(), true));
+		let new_location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Cuttack".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::edit_citizen_profile(
+			RuntimeOrigin::signed(1),
+			new_content.clone(),
+			new_location.clone()
+		));
+		System::assert_last_event(
+			Event::ProfileEdited { citizen: 1, version: 2, revalidation: true }.into(),
+		);
+		assert_eq!(ProfileValidation::profile_history(1, 1).unwrap().location, location);
+		assert_eq!(SharedStorage::approved_citizen_address(), Vec::<u64>::new());
+		assert!(!pallet_shared_storage::DepartmentMembers::<Test>::get(0).contains(&1));
+		assert_eq!(ProfileValidation::validation_block(1), game_length);
+
+		// Department memberships are restored with the approval
+		System::set_block_number(game_length * 2);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(SharedStorage::approved_citizen_address(), vec![1]);
+		assert!(pallet_shared_storage::DepartmentMembers::<Test>::get(0).contains(&1));
+
+		// Content change requires a re-validation game too
+		assert_ok!(ProfileValidation::edit_citizen_profile(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			new_location
+		));
+		System::assert_last_event(
+			Event::ProfileEdited { citizen: 1, version: 3, revalidation: true }.into(),
+		);
+		assert_eq!(ProfileValidation::validation_block(1), game_length * 2);
+	});
+}
+
+#[test]
+fn revalidation_settles_unreturned_deposits() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(1), content, location));
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		let challenge_content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhabc"
+				.as_bytes()
+				.to_vec(),
+		);
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + 1);
+		assert_ok!(ProfileValidation::challenge_profile(
+			RuntimeOrigin::signed(5),
+			1,
+			challenge_content.clone()
+		));
+
+		for j in 4..30 {
+			assert_ok!(ProfileValidation::apply_jurors(RuntimeOrigin::signed(j), 1, j * 100));
+		}
+		System::set_block_number(phase_data.evidence_length + 1 + phase_data.staking_length);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_ok!(ProfileValidation::draw_jurors(RuntimeOrigin::signed(5), 1, 5));
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 1 };
+		let drawn_jurors = SchellingGameShared::drawn_jurors(key.clone());
+		for (juror, _) in drawn_jurors.iter() {
+			let hash = sp_io::hashing::keccak_256("0salt".as_bytes());
+			assert_ok!(ProfileValidation::commit_vote(RuntimeOrigin::signed(*juror), 1, hash));
+		}
+		System::set_block_number(
+			phase_data.evidence_length + 1 + phase_data.staking_length + phase_data.commit_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		for (juror, _) in drawn_jurors.iter() {
+			assert_ok!(ProfileValidation::reveal_vote(
+				RuntimeOrigin::signed(*juror),
+				1,
+				0,
+				"salt".as_bytes().to_vec()
+			));
+		}
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		System::set_block_number(
+			phase_data.evidence_length
+				+ 1 + phase_data.staking_length
+				+ phase_data.commit_length
+				+ phase_data.vote_length
+				+ phase_data.appeal_length,
+		);
+		assert_ok!(ProfileValidation::pass_period(RuntimeOrigin::signed(5), 1));
+		assert_eq!(SchellingGameShared::get_period(key), Some(Period::Execution));
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+
+		// The funder didn't claim its winnings before the re-validation game, it is paid the
+		// deposit and the challenge fund of 100 without the juror share of 10%
+		assert_ok!(ProfileValidation::set_revalidate_identity_edits(RuntimeOrigin::root(), true));
+		let balance = Balances::free_balance(3);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Cuttack".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::edit_citizen_profile(
+			RuntimeOrigin::signed(1),
+			challenge_content,
+			location
+		));
+		assert_eq!(Balances::free_balance(3), balance + 1000 + 90);
+		System::assert_has_event(
+			Event::ProfileStakeReturned { profile: 1, funder: 3, amount: 1090 }.into(),
+		);
+		assert_noop!(
+			ProfileValidation::return_profile_stake(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::ProfileFundNotExists
+		);
+		assert_noop!(
+			ProfileValidation::return_challenge_stake(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::ChallengeFundNotExists
+		);
+	});
+}
+
+#[test]
+fn citizen_directory() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let content: Content = Content::IPFS(
+			"bafkreiaiq24be2iioasr6ftyaum3icmj7amtjkom2jeokov5k5ojwzhvqy"
+				.as_bytes()
+				.to_vec(),
+		);
+		let location: LocationDetails = LocationDetails {
+			country: "India".as_bytes().to_vec(),
+			state: "Odisha".as_bytes().to_vec(),
+			city: "Bhubaneswar".as_bytes().to_vec(),
+			street: None,
+		};
+		assert_ok!(ProfileValidation::add_citizen(
+			RuntimeOrigin::signed(1),
+			content.clone(),
+			location.clone()
+		));
+		assert_ok!(ProfileValidation::add_citizen(RuntimeOrigin::signed(2), content, location));
+		assert_eq!(ProfileValidation::citizen_account(1), Some(1));
+		assert_eq!(ProfileValidation::citizen_account(2), Some(2));
+		assert_eq!(ProfileValidation::get_citizen_accountid(2), Ok(2));
+		assert_eq!(ProfileValidation::get_citizen_status(3), None);
+
+		assert_ok!(ProfileValidation::add_profile_stake(RuntimeOrigin::signed(3), 1, 1000));
+		assert_eq!(
+			ProfileValidation::get_citizens(0, 10),
+			vec![(1, 1, CitizenStatus::InValidation), (2, 2, CitizenStatus::Pending)]
+		);
+
+		let phase_data = ProfileValidation::validation_params();
+		System::set_block_number(phase_data.evidence_length + phase_data.end_of_staking_time + 1);
+		assert_ok!(ProfileValidation::add_to_kyc_accounts(RuntimeOrigin::signed(1)));
+		assert_eq!(ProfileValidation::get_citizens(0, 1), vec![(1, 1, CitizenStatus::Approved)]);
+		assert_eq!(ProfileValidation::get_citizens(1, 10), vec![(2, 2, CitizenStatus::Pending)]);
+	});
+}
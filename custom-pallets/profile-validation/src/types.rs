@@ -6,6 +6,8 @@ use super::*;
 
 pub const FIRST_CITIZEN_ID: CitizenId = 1;
 pub const FIRST_CHALLENGE_POST_ID: ChallengePostId = 1;
+/// Upper bound of each phase length in `ProfileValidationParams`
+pub const MAX_PHASE_LENGTH: u64 = 1_000_000;
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -41,6 +43,17 @@ pub struct ProfileFundInfo<Balance, AccountId> {
 	pub deposit_returned: bool,
 }
 
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Default, Clone, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct ChallengeFundInfo<Balance, AccountId> {
+	pub challenger_account_id: AccountId,
+	pub validation_account_id: AccountId,
+	pub deposit: Balance,
+	pub deposit_returned: bool,
+}
+
 #[derive(
 	PartialEq, Eq, PartialOrd, Ord, Default, Clone, Encode, Decode, MaxEncodedLen, TypeInfo,
 )]
@@ -62,3 +75,72 @@ pub struct ChallengeEvidencePost<T: Config> {
 	pub post_id_if_comment: Option<ChallengePostId>,
 	pub is_comment: bool,
 }
+
+/// Guardians that can recover the citizen profile, `threshold` of them have to approve
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct RecoveryConfig<AccountId> {
+	pub guardians: Vec<AccountId>,
+	pub threshold: u32,
+}
+
+/// Recovery of a lost account to `new_account`, approvals are sorted
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ActiveRecovery<AccountId, BlockNumber> {
+	pub new_account: AccountId,
+	pub initiated: BlockNumber,
+	pub approvals: Vec<AccountId>,
+}
+
+/// Challenge of an approved citizen, `settled` once resolved after the game
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Default, Clone, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct FraudChallengeInfo<Balance, BlockNumber, AccountId> {
+	pub challenger: AccountId,
+	pub deposit: Balance,
+	pub start: BlockNumber,
+	pub evidence_post_id: ChallengePostId,
+	pub settled: bool,
+}
+
+pub type IdentityNameLength = ConstU32<100>;
+pub type IdentityAddressLength = ConstU32<200>;
+pub type PhotoHashLength = ConstU32<200>;
+
+/// Identity of the citizen written by the jurors of the profile validation after the video call
+/// `validator_approved` is false when governance stored it on appeal.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct IdentityData {
+	pub name: BoundedVec<u8, IdentityNameLength>,
+	pub address: BoundedVec<u8, IdentityAddressLength>,
+	pub photo_hash: BoundedVec<u8, PhotoHashLength>,
+	pub validator_approved: bool,
+}
+
+/// Fees and schelling game parameters of profile validation, set by `UpdateOrigin`
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ProfileValidationParams<Balance> {
+	pub registration_fee: Balance,
+	pub registration_challenge_fee: Balance,
+	pub revalidation_fee: Balance,
+	pub evidence_length: u64,
+	pub end_of_staking_time: u64,
+	pub staking_length: u64,
+	pub drawing_length: u64,
+	pub commit_length: u64,
+	pub vote_length: u64,
+	pub appeal_length: u64,
+	pub max_draws: u64,
+	pub min_number_juror_staked: u64,
+	pub min_juror_stake: u64,
+	pub juror_incentives: (u64, u64),
+}
+
+/// Content and location of a replaced version of the citizen profile
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProfileVersionEntry<BlockNumber> {
+	pub content: Content,
+	pub location: LocationDetails,
+	pub replaced_at: BlockNumber,
+}
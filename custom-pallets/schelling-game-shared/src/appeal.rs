@@ -0,0 +1,216 @@
+use crate::*;
+use frame_support::sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Appeal window is opened after the vote period of two choice games with an `appeal_length`
+	pub(super) fn appeal_enabled(key: &SumTreeNameType<T>, phase_data: &PhaseDataOf<T>) -> bool {
+		!phase_data.appeal_length.is_zero() && <VoteCommits<T>>::iter_prefix(key).next().is_some()
+	}
+
+	/// Phase data of the current round with the parameters of the game court,
+	/// the jury of every appeal round is `2n + 1` of the previous jury
+	pub(super) fn round_phase_data(key: &SumTreeNameType<T>, phase_data: PhaseDataOf<T>) -> PhaseDataOf<T> {
+		let round = <AppealRound<T>>::get(key);
+		let mut round_phase_data = Self::court_phase_data(key, phase_data);
+		round_phase_data.max_draws = Self::max_draws_in_round(round_phase_data.max_draws, round);
+		round_phase_data
+	}
+
+	pub(super) fn max_draws_in_round(max_draws: u64, round: u32) -> u64 {
+		max_draws.saturating_add(1).saturating_mul(2u64.saturating_pow(round)).saturating_sub(1)
+	}
+
+	/// Amount each side has to raise to appeal the current round
+	/// Appeal fee per juror of the court for every juror of the next round
+	pub(super) fn appeal_fee(key: &SumTreeNameType<T>, phase_data: &PhaseDataOf<T>) -> BalanceOf<T> {
+		let round = <AppealRound<T>>::get(key);
+		let next_round_draws = Self::max_draws_in_round(phase_data.max_draws, round.saturating_add(1));
+		Self::appeal_fee_per_juror(key).saturating_mul(Self::u64_to_balance_saturated(next_round_draws))
+	}
+
+	/// Contribute to the appeal fee of `choice` (0 or 1) during the appeal period
+	/// When a side is fully funded the jurors of the round are paid and a new round opens with staking period.
+	/// The appeal fee of the funded side goes to the reward budget to pay the jurors of the new round,
+	/// the remaining contributions are settled with the final ruling, see `withdraw_appeal_contribution_helper`.
+	pub(super) fn fund_appeal_helper(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		who: AccountIdOf<T>,
+		choice: u128,
+		amount: BalanceOf<T>,
+		now: BlockNumberOf<T>,
+	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Appeal, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let appeal_start_time = <AppealStartTime<T>>::get(&key);
+		ensure!(now < phase_data.appeal_length + appeal_start_time, Error::<T>::AppealPeriodOver);
+		ensure!(choice == 0 || choice == 1, Error::<T>::NotValidChoice);
+
+		let round = <AppealRound<T>>::get(&key);
+		let appeal_fee = Self::appeal_fee(&key, &phase_data);
+		let mut funds = <AppealFunds<T>>::get(&key, round);
+		let funded = if choice == 1 { funds.1 } else { funds.0 };
+		let required_fund = appeal_fee.saturating_sub(funded);
+		ensure!(!amount.is_zero() && amount <= required_fund, Error::<T>::AppealAmountGreaterThanRequired);
+
+		T::Currency::transfer(&who, &Self::reward_pool_account(), amount, ExistenceRequirement::KeepAlive)?;
+
+		<AppealContributions<T>>::mutate(&key, (round, who.clone()), |contribution| {
+			if choice == 1 {
+				contribution.1 = contribution.1.saturating_add(amount);
+			} else {
+				contribution.0 = contribution.0.saturating_add(amount);
+			}
+		});
+		if choice == 1 {
+			funds.1 = funds.1.saturating_add(amount);
+		} else {
+			funds.0 = funds.0.saturating_add(amount);
+		}
+		<AppealFunds<T>>::insert(&key, round, funds);
+		Self::deposit_event(Event::AppealFunded { key: key.clone(), round, who, choice, amount });
+
+		if amount == required_fund {
+			<RewardPoolBudget<T>>::mutate(|budget| *budget = budget.saturating_add(appeal_fee));
+			Self::open_appeal_round(key, phase_data, now)?;
+		}
+		Ok(())
+	}
+
+	/// Pay the jurors of the appealed round and reset the game for a new round with a larger jury
+	/// Stakers that were not drawn stay in the sortition tree, unstaked jurors are removed from it.
+	fn open_appeal_round(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		now: BlockNumberOf<T>,
+	) -> DispatchResult {
+		Self::pay_two_choice_round_jurors(key.clone(), phase_data)?;
+
+		for juror in <UnstakedJurors<T>>::get(&key) {
+			let _ = T::SortitionSumGameSource::set_link(key.clone(), 0, juror);
+		}
+		<DrawnJurors<T>>::remove(&key);
+		<UnstakedJurors<T>>::remove(&key);
+		<DrawsInRound<T>>::remove(&key);
+		let _ = <VoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+		<DecisionCount<T>>::remove(&key);
+		<AbstainCount<T>>::remove(&key);
+		<JurorsIncentiveDistributedAccounts<T>>::remove(&key);
+
+		let round = <AppealRound<T>>::get(&key).saturating_add(1);
+		<AppealRound<T>>::insert(&key, round);
+		<PeriodName<T>>::insert(&key, Period::Staking);
+		<StakingStartTime<T>>::insert(&key, now);
+		Self::deposit_event(Event::AppealRoundOpened { key, round });
+		Ok(())
+	}
+
+	/// Contributions of a round left after the appeal fee of an appealed round
+	/// Only one side is fully funded when the round is appealed, its funds are the appeal fee,
+	/// the funds of the other side are left.
+	fn appeal_remainder(funds: (BalanceOf<T>, BalanceOf<T>)) -> BalanceOf<T> {
+		funds.0.min(funds.1)
+	}
+
+	/// Amount returned for a contribution to `round`
+	/// <pre>
+	/// Round was not appealed: contribution is refunded
+	/// Appealed round with a final ruling side: contributors of the ruling side share the remainder
+	/// in proportion to their contribution, contributors of the other side get nothing
+	/// Appealed round without a ruling side: all contributors share the remainder in proportion
+	/// </pre>
+	fn appeal_contribution_amount(
+		key: &SumTreeNameType<T>,
+		round: u32,
+		contribution: (BalanceOf<T>, BalanceOf<T>),
+		ruling_side: Option<u128>,
+	) -> BalanceOf<T> {
+		let appealed = round < <AppealRound<T>>::get(key);
+		if !appealed {
+			return contribution.0.saturating_add(contribution.1);
+		}
+		let funds = <AppealFunds<T>>::get(key, round);
+		let remainder = Self::appeal_remainder(funds);
+		let (contributed, side_funds) = match ruling_side {
+			Some(1) => (contribution.1, funds.1),
+			Some(_) => (contribution.0, funds.0),
+			None => (contribution.0.saturating_add(contribution.1), funds.0.saturating_add(funds.1)),
+		};
+		contributed
+			.saturated_into::<u128>()
+			.saturating_mul(remainder.saturated_into::<u128>())
+			.checked_div(side_funds.saturated_into::<u128>())
+			.unwrap_or(0)
+			.saturated_into::<BalanceOf<T>>()
+	}
+
+	/// Side (0 or 1) of the final ruling, `None` for draw or abstain
+	fn final_ruling_side(key: &SumTreeNameType<T>) -> Option<u128> {
+		let decision_count = <DecisionCount<T>>::get(key);
+		let abstain_count = <AbstainCount<T>>::get(key);
+		match Self::get_winning_decision(decision_count, abstain_count) {
+			WinningDecision::WinnerYes => Some(1),
+			WinningDecision::WinnerNo => Some(0),
+			WinningDecision::Draw | WinningDecision::Abstain => None,
+		}
+	}
+
+	/// Withdraw the appeal contribution of a round once the game is in `Period::Execution`
+	/// See `appeal_contribution_amount` for the amount returned.
+	pub(super) fn withdraw_appeal_contribution_helper(
+		key: SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		round: u32,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		let contribution = <AppealContributions<T>>::take(&key, (round, who.clone()));
+		ensure!(
+			!contribution.0.is_zero() || !contribution.1.is_zero(),
+			Error::<T>::AppealContributionNotExists
+		);
+
+		let ruling_side = Self::final_ruling_side(&key);
+		let amount = Self::appeal_contribution_amount(&key, round, contribution, ruling_side);
+
+		if !amount.is_zero() {
+			T::Currency::transfer(
+				&Self::reward_pool_account(),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Self::deposit_event(Event::AppealContributionWithdrawn { key, round, who, amount });
+		Ok(())
+	}
+
+	/// Refund the appeal contributions of a cancelled game
+	/// Appeal fees of appealed rounds paid their jurors, the remainder is refunded in proportion.
+	pub(super) fn refund_appeal_contributions(key: &SumTreeNameType<T>) -> DispatchResult {
+		let contributions = <AppealContributions<T>>::drain_prefix(key).collect::<Vec<_>>();
+		for ((round, who), contribution) in contributions {
+			let amount = Self::appeal_contribution_amount(key, round, contribution, None);
+			if amount.is_zero() {
+				continue;
+			}
+			T::Currency::transfer(
+				&Self::reward_pool_account(),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		let _ = <AppealFunds<T>>::clear_prefix(key, u32::MAX, None);
+		Ok(())
+	}
+}
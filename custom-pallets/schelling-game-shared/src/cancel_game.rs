@@ -0,0 +1,98 @@
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Cancel the game and refund all staked jurors, drawn or not
+	/// <pre>
+	/// Governance: can cancel the game before `Period::Execution`
+	/// NotEnoughJurorsStaked: staking period is over and less jurors staked than the `max_draws` of the round
+	/// </pre>
+	/// Appeal contributions are refunded, without the appeal fees of appealed rounds.
+	/// Commits, reveals and the sortition tree are cleared and the period is set to `Period::Cancelled`.
+	/// The owning pallet is responsible to refund its own deposits after the call.
+	pub(super) fn cancel_game_helper(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		now: BlockNumberOf<T>,
+		reason: CancellationReason,
+	) -> DispatchResult {
+		let phase_data = Self::round_phase_data(&key, phase_data);
+		let period = <PeriodName<T>>::get(&key).ok_or(Error::<T>::PeriodDoesNotExists)?;
+		ensure!(
+			period != Period::Execution && period != Period::Cancelled,
+			Error::<T>::CannotCancelGame
+		);
+
+		// Tree doesn't exist before staking period, so there is no staker
+		// Stakers of a pool game are a snapshot of the pool, their stake is in the pool
+		let juror_pool = <GameJurorPool<T>>::get(&key);
+		let stakers = match juror_pool {
+			Some(_) => Vec::new(),
+			None => T::SortitionSumGameSource::stakers_link(key.clone()).unwrap_or_default(),
+		};
+		let drawn_jurors = <DrawnJurors<T>>::get(&key);
+		let unstaked_jurors = <UnstakedJurors<T>>::get(&key);
+
+		match reason {
+			CancellationReason::Governance => {},
+			CancellationReason::NotEnoughJurorsStaked => {
+				ensure!(
+					period == Period::Staking || period == Period::Drawing,
+					Error::<T>::CancelConditionNotMet
+				);
+				let staking_start_time = <StakingStartTime<T>>::get(&key);
+				ensure!(
+					now >= phase_data.staking_length + staking_start_time,
+					Error::<T>::StakingPeriodNotOver
+				);
+				let jurors_staked = match juror_pool {
+					Some(department_id) => {
+						Self::eligible_pool_jurors(&key, department_id, &phase_data).len() as u64
+					},
+					None => {
+						let undrawn = stakers.iter().filter(|(_, stake)| *stake > 0).count();
+						(undrawn + drawn_jurors.len()) as u64
+					},
+				};
+				// The jury of the round can't be drawn, appeal rounds draw more jurors
+				ensure!(
+					jurors_staked < phase_data.max_draws,
+					Error::<T>::CancelConditionNotMet
+				);
+			},
+		}
+
+		// Drawn jurors stake is set to zero in the tree, they are refunded from `DrawnJurors`
+		for (juror, stake) in stakers.into_iter().chain(drawn_jurors) {
+			if stake > 0 && unstaked_jurors.binary_search(&juror).is_err() {
+				Self::settle_juror_stake(&key, &juror, stake, stake, 0)?;
+			}
+		}
+		Self::refund_appeal_contributions(&key)?;
+
+		// Remove SorititionSumTrees in `sortition-sum-game` pallet
+		let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+		<DrawnJurors<T>>::remove(&key);
+		<UnstakedJurors<T>>::remove(&key);
+		<DrawsInRound<T>>::remove(&key);
+		let _ = <VoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <ScoreVoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <RubricVoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+		<RevealRubricValues<T>>::remove(&key);
+		let _ = <JurorPairs<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <PairwiseVoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+		<PairwiseComparisons<T>>::remove(&key);
+		<DecisionCount<T>>::remove(&key);
+		<AbstainCount<T>>::remove(&key);
+		<RevealScoreValues<T>>::remove(&key);
+		<AppealStartTime<T>>::remove(&key);
+		<AppealRound<T>>::remove(&key);
+		<SelfSelectionSeed<T>>::remove(&key);
+		let _ = <GameVrfKeys<T>>::clear_prefix(&key, u32::MAX, None);
+
+		<PeriodName<T>>::insert(&key, Period::Cancelled);
+		<GameCancellationReason<T>>::insert(&key, reason.clone());
+		Self::deposit_event(Event::GameCancelled { key, reason });
+
+		Ok(())
+	}
+}
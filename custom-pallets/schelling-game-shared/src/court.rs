@@ -0,0 +1,267 @@
+use crate::*;
+use crate::types::GENERAL_COURT_ID;
+use frame_support::sp_runtime::traits::{Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Create a court, the first court is the General court and has no parent
+	pub(super) fn create_court_helper(
+		parent: Option<CourtId>,
+		name: Vec<u8>,
+		params: CourtParams,
+	) -> Result<CourtId, DispatchError> {
+		let court_id = <NextCourtId<T>>::get();
+		match parent {
+			Some(parent_id) => {
+				ensure!(<Courts<T>>::contains_key(parent_id), Error::<T>::CourtDoesNotExists);
+			},
+			None => {
+				ensure!(court_id == GENERAL_COURT_ID, Error::<T>::GeneralCourtExists);
+			},
+		}
+		<Courts<T>>::insert(court_id, Court { parent, name, params });
+		<NextCourtId<T>>::put(court_id.saturating_add(1));
+		Self::deposit_event(Event::CourtCreated { court_id, parent });
+		Ok(court_id)
+	}
+
+	pub(super) fn update_court_params_helper(court_id: CourtId, params: CourtParams) -> DispatchResult {
+		<Courts<T>>::try_mutate(court_id, |court| -> DispatchResult {
+			let court = court.as_mut().ok_or(Error::<T>::CourtDoesNotExists)?;
+			court.params = params;
+			Ok(())
+		})?;
+		Self::deposit_event(Event::CourtParamsUpdated { court_id });
+		Ok(())
+	}
+
+	/// Court and its ancestors, starting from the court
+	/// Parent always has a lower id, so the path ends at the General court.
+	pub(super) fn court_path(court_id: CourtId) -> Vec<CourtId> {
+		let mut path = Vec::new();
+		let mut current = Some(court_id);
+		while let Some(id) = current {
+			match <Courts<T>>::get(id) {
+				Some(court) => {
+					path.push(id);
+					current = court.parent.filter(|parent| *parent < id);
+				},
+				None => break,
+			}
+		}
+		path
+	}
+
+	/// Parameters of the court with the parameters inherited from its ancestors
+	pub fn court_params(court_id: CourtId) -> CourtParams {
+		Self::court_path(court_id).into_iter().fold(CourtParams::default(), |params, id| {
+			match <Courts<T>>::get(id) {
+				Some(court) => params.inherit(&court.params),
+				None => params,
+			}
+		})
+	}
+
+	/// Game is created in the court, can only be set before jurors stake in the game
+	pub(super) fn set_game_court_helper(key: SumTreeNameType<T>, court_id: CourtId) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Evidence, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		ensure!(<Courts<T>>::contains_key(court_id), Error::<T>::CourtDoesNotExists);
+		<GameCourt<T>>::insert(&key, court_id);
+		<GameCourtParams<T>>::insert(&key, Self::court_params(court_id));
+		Ok(())
+	}
+
+	/// Court parameters of the game, as they were when the game was created in the court
+	/// Games created before the parameters were kept read the current parameters of their court.
+	fn game_court_params_of(key: &SumTreeNameType<T>) -> Option<CourtParams> {
+		<GameCourtParams<T>>::get(key).or_else(|| <GameCourt<T>>::get(key).map(Self::court_params))
+	}
+
+	/// Phase data of the game with the parameters of its court
+	/// Games without court use the phase data of the owning pallet.
+	pub(super) fn court_phase_data(key: &SumTreeNameType<T>, phase_data: PhaseDataOf<T>) -> PhaseDataOf<T> {
+		let params = match Self::game_court_params_of(key) {
+			Some(params) => params,
+			None => return phase_data,
+		};
+		let block = |value: Option<u64>, default: BlockNumberOf<T>| {
+			value.map(|value| value.saturated_into::<BlockNumberOf<T>>()).unwrap_or(default)
+		};
+		PhaseData {
+			evidence_length: block(params.evidence_length, phase_data.evidence_length),
+			end_of_staking_time: block(params.end_of_staking_time, phase_data.end_of_staking_time),
+			staking_length: block(params.staking_length, phase_data.staking_length),
+			drawing_length: block(params.drawing_length, phase_data.drawing_length),
+			commit_length: block(params.commit_length, phase_data.commit_length),
+			vote_length: block(params.vote_length, phase_data.vote_length),
+			appeal_length: block(params.appeal_length, phase_data.appeal_length),
+			max_draws: params.max_draws.unwrap_or(phase_data.max_draws),
+			min_number_juror_staked: params
+				.min_number_juror_staked
+				.unwrap_or(phase_data.min_number_juror_staked),
+			min_juror_stake: params
+				.min_juror_stake
+				.map(Self::u64_to_balance_saturated)
+				.unwrap_or(phase_data.min_juror_stake),
+			juror_incentives: params.juror_incentives.unwrap_or(phase_data.juror_incentives),
+		}
+	}
+
+	/// Appeal fee per juror of the game court, `AppealFeePerJuror` when no court sets it
+	pub(super) fn appeal_fee_per_juror(key: &SumTreeNameType<T>) -> BalanceOf<T> {
+		Self::game_court_params_of(key)
+			.and_then(|params| params.appeal_fee_per_juror)
+			.map(Self::u64_to_balance_saturated)
+			.unwrap_or_else(T::AppealFeePerJuror::get)
+	}
+
+	/// Stakes of the game tree are weighted by seniority when the game court sets it
+	pub(super) fn apply_court_seniority_weighting(key: &SumTreeNameType<T>) -> DispatchResult {
+		let seniority_weighting =
+			Self::game_court_params_of(key).and_then(|params| params.seniority_weighting);
+		if let Some((full_weight_after, initial_weight_percent)) = seniority_weighting {
+			T::SortitionSumGameSource::set_seniority_weighting_link(
+				key.clone(),
+				full_weight_after,
+				initial_weight_percent,
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Seniority of the juror in the game, the age of its oldest court stake in the court path or pool stake
+	/// Stakes are aged to the scheduled end of the staking period, so jurors staking early or late in the game
+	/// get the same seniority.
+	pub(super) fn juror_seniority(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		phase_data: &PhaseDataOf<T>,
+	) -> u64 {
+		let end_of_staking =
+			<StakingStartTime<T>>::get(key).saturating_add(phase_data.staking_length);
+		let court_since = <GameCourt<T>>::get(key)
+			.map(Self::court_path)
+			.unwrap_or_default()
+			.into_iter()
+			.filter_map(|id| <CourtStakedSince<T>>::get(id, who));
+		let pool_since = <GameJurorPool<T>>::get(key)
+			.and_then(|department_id| <JurorPoolStakedSince<T>>::get(department_id, who));
+		court_since
+			.chain(pool_since)
+			.min()
+			.map(|since| end_of_staking.saturating_sub(since).saturated_into::<u64>())
+			.unwrap_or(0)
+	}
+
+	/// Juror can apply to a game of a court with enough stake in the court or one of its ancestors
+	pub(super) fn ensure_eligible_for_court(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		min_juror_stake: BalanceOf<T>,
+	) -> DispatchResult {
+		let court_id = match <GameCourt<T>>::get(key) {
+			Some(court_id) => court_id,
+			None => return Ok(()),
+		};
+		let eligible = Self::court_path(court_id).into_iter().any(|id| {
+			let stake = <CourtStakes<T>>::get(id, who);
+			!stake.is_zero() && stake >= min_juror_stake
+		});
+		ensure!(eligible, Error::<T>::NotEligibleForCourt);
+		Ok(())
+	}
+
+	pub(super) fn stake_in_court_helper(
+		court_id: CourtId,
+		who: AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(<Courts<T>>::contains_key(court_id), Error::<T>::CourtDoesNotExists);
+		T::Currency::reserve(&who, amount)?;
+		if !<CourtStakedSince<T>>::contains_key(court_id, &who) {
+			<CourtStakedSince<T>>::insert(court_id, &who, <frame_system::Pallet<T>>::block_number());
+		}
+		<CourtStakes<T>>::mutate(court_id, &who, |stake| *stake = stake.saturating_add(amount));
+		Self::deposit_event(Event::CourtStaked { court_id, who, amount });
+		Ok(())
+	}
+
+	/// Game of a court the juror serves in, its court stake is locked until the game is over
+	pub(super) fn register_court_service(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		min_juror_stake: BalanceOf<T>,
+	) {
+		if !<GameCourt<T>>::contains_key(key) {
+			return;
+		}
+		<JurorCourtGames<T>>::mutate(who, |games| {
+			games.retain(|(game, _)| Self::game_running(game) && game != key);
+			games.push((key.clone(), min_juror_stake));
+		});
+	}
+
+	/// Game has not reached `Period::Execution` or `Period::Cancelled`
+	pub(super) fn game_running(key: &SumTreeNameType<T>) -> bool {
+		!matches!(
+			<PeriodName<T>>::get(key),
+			Some(Period::Execution) | Some(Period::Cancelled) | None
+		)
+	}
+
+	/// Juror stays eligible for every running game it serves in with `remaining` stake in `court_id`
+	fn ensure_court_stake_unlocked(
+		court_id: CourtId,
+		who: &AccountIdOf<T>,
+		remaining: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut games = <JurorCourtGames<T>>::get(who);
+		games.retain(|(game, _)| Self::game_running(game));
+		for (game, min_juror_stake) in games.iter() {
+			let path = match <GameCourt<T>>::get(game) {
+				Some(game_court_id) => Self::court_path(game_court_id),
+				None => continue,
+			};
+			if !path.contains(&court_id) {
+				continue;
+			}
+			let eligible = path.into_iter().any(|id| {
+				let stake =
+					if id == court_id { remaining } else { <CourtStakes<T>>::get(id, who) };
+				!stake.is_zero() && stake >= *min_juror_stake
+			});
+			ensure!(eligible, Error::<T>::CourtStakeLocked);
+		}
+		if games.is_empty() {
+			<JurorCourtGames<T>>::remove(who);
+		} else {
+			<JurorCourtGames<T>>::insert(who, games);
+		}
+		Ok(())
+	}
+
+	/// Withdraw court stake, stake needed for games the juror serves in is locked
+	pub(super) fn unstake_from_court_helper(
+		court_id: CourtId,
+		who: AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let stake = <CourtStakes<T>>::get(court_id, &who);
+		ensure!(stake >= amount, Error::<T>::CourtStakeNotEnough);
+		let remaining = stake.saturating_sub(amount);
+		Self::ensure_court_stake_unlocked(court_id, &who, remaining)?;
+		if remaining.is_zero() {
+			<CourtStakes<T>>::remove(court_id, &who);
+			<CourtStakedSince<T>>::remove(court_id, &who);
+		} else {
+			<CourtStakes<T>>::insert(court_id, &who, remaining);
+		}
+		T::Currency::unreserve(&who, amount);
+		Self::deposit_event(Event::CourtUnstaked { court_id, who, amount });
+		Ok(())
+	}
+}
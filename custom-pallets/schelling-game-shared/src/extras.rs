@@ -1,32 +1,8 @@
 use crate::*;
 
 impl<T: Config> Pallet<T> {
-	pub(super) fn create_phase_with_all_data(
-		evidence_length: u64,
-		end_of_staking_time: u64,
-		staking_length: u64,
-		drawing_length: u64,
-		commit_length: u64,
-		vote_length: u64,
-		appeal_length: u64,
-		max_draws: u64,
-		min_number_juror_staked: u64,
-		min_juror_stake: u64,
-		juror_incentives: (u64, u64),
-	) -> PhaseDataOf<T> {
-		PhaseData::create_phase_with_all_data(
-			evidence_length,
-			end_of_staking_time,
-			staking_length,
-			drawing_length,
-			commit_length,
-			vote_length,
-			appeal_length,
-			max_draws,
-			min_number_juror_staked,
-			min_juror_stake,
-			juror_incentives,
-		)
+	pub(super) fn create_phase_with_all_data(params: PhaseParams) -> PhaseDataOf<T> {
+		PhaseData::create_phase_with_all_data(params)
 	}
 	pub(super) fn create_phase_data(
 		block_length: u64,
@@ -73,6 +49,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		if let Some(Period::Evidence) = <PeriodName<T>>::get(&key) {
 			let evidence_stake_block_number = <EvidenceStartTime<T>>::get(&key);
 			let time = now.checked_sub(&evidence_stake_block_number).expect("Overflow");
@@ -102,6 +79,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let evidence_stake_block_number = <EvidenceStartTime<T>>::get(&key);
 		let time = now.checked_sub(&evidence_stake_block_number).expect("Overflow");
 		let evidence_length = phase_data.evidence_length;
@@ -129,6 +107,7 @@ impl<T: Config> Pallet<T> {
 
 	pub(super) fn create_tree_link_helper(key: SumTreeNameType<T>, k: u64) -> DispatchResult {
 		T::SortitionSumGameSource::create_tree_link(key.clone(), k)?;
+		Self::apply_court_seniority_weighting(&key)?;
 		Ok(())
 	}
 
@@ -158,22 +137,41 @@ impl<T: Config> Pallet<T> {
 	///   // Change `Period::Vote` to `Period::Execution`
 	/// }
 	/// ```
+	/// Two choice games with an `appeal_length` go to `Period::Appeal` instead
+	///
+	/// `Period::Appeal` to `Period::Execution`
+	/// ```ignore
+	/// if now >= appeal_length + appeal_start_time {
+	///   // Change `Period::Appeal` to `Period::Execution`
+	/// }
+	/// ```
 	pub(super) fn change_period(
 		key: SumTreeNameType<T>,
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> DispatchResult {
+		let phase_data = Self::round_phase_data(&key, phase_data);
 		match <PeriodName<T>>::get(&key) {
 			Some(period) => {
 				match period {
-					Period::Evidence => todo!(),
+					// Evidence period ends when the game is set to staking
+					Period::Evidence => Err(Error::<T>::PeriodDontMatch)?,
 					Period::Staking => {
 						// Also check has min number of jurors has staked
 						let staking_start_time = <StakingStartTime<T>>::get(&key);
 						let staking_length = phase_data.staking_length;
 						if now >= staking_length + staking_start_time {
-							let new_period = Period::Drawing;
-							<PeriodName<T>>::insert(&key, new_period);
+							Self::snapshot_juror_pool(&key, &phase_data)?;
+							if <SelfSelectionEnabled<T>>::get(&key) {
+								// Jurors select themselves in commit period, there is no drawing
+								Self::set_self_selection_seed(&key)?;
+								<CommitStartTime<T>>::insert(&key, now);
+								let new_period = Period::Commit;
+								<PeriodName<T>>::insert(&key, new_period);
+							} else {
+								let new_period = Period::Drawing;
+								<PeriodName<T>>::insert(&key, new_period);
+							}
 						} else {
 							Err(Error::<T>::StakingPeriodNotOver)?
 						}
@@ -183,6 +181,9 @@ impl<T: Config> Pallet<T> {
 						let max_draws = phase_data.max_draws;
 						let draws_in_round = <DrawsInRound<T>>::get(&key);
 						if draws_in_round >= max_draws {
+							if <PairwiseGames<T>>::contains_key(&key) {
+								Self::assign_pairs(&key)?;
+							}
 							<CommitStartTime<T>>::insert(&key, now);
 							let new_period = Period::Commit;
 							<PeriodName<T>>::insert(&key, new_period);
@@ -205,14 +206,30 @@ impl<T: Config> Pallet<T> {
 						let vote_start_time = <VoteStartTime<T>>::get(&key);
 						let vote_length = phase_data.vote_length;
 						if now >= vote_length + vote_start_time {
+							if Self::appeal_enabled(&key, &phase_data) {
+								<AppealStartTime<T>>::insert(&key, now);
+								let new_period = Period::Appeal;
+								<PeriodName<T>>::insert(&key, new_period);
+							} else {
+								let new_period = Period::Execution;
+								<PeriodName<T>>::insert(&key, new_period);
+							}
+						} else {
+							Err(Error::<T>::VotePeriodNotOver)?
+						}
+					},
+					Period::Appeal => {
+						let appeal_start_time = <AppealStartTime<T>>::get(&key);
+						let appeal_length = phase_data.appeal_length;
+						if now >= appeal_length + appeal_start_time {
 							let new_period = Period::Execution;
 							<PeriodName<T>>::insert(&key, new_period);
 						} else {
-							Err(Error::<T>::VotePeriodNotOver)?
+							Err(Error::<T>::AppealPeriodNotOver)?
 						}
 					},
-					Period::Appeal => todo!(),
-					Period::Execution => todo!(),
+					Period::Execution => Err(Error::<T>::GameAlreadyInExecution)?,
+					Period::Cancelled => Err(Error::<T>::GameCancelled)?,
 				}
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
@@ -227,16 +244,21 @@ impl<T: Config> Pallet<T> {
 		stake: BalanceOf<T>,
 		// To do! Include reputation stake
 	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		match <PeriodName<T>>::get(&key) {
 			Some(period) => {
 				ensure!(period == Period::Staking, Error::<T>::PeriodDontMatch);
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		Self::ensure_not_juror_pool_game(&key)?;
 		let min_stake = phase_data.min_juror_stake;
 
 		// To do! Add reputation stake
 		ensure!(stake >= min_stake, Error::<T>::JurorStakeLessThanMin);
+		Self::ensure_eligible_for_court(&key, &who, min_stake)?;
+		Self::snapshot_juror_vrf_key(&key, &who)?;
+		Self::register_court_service(&key, &who, min_stake);
 
 		// To do! Store reputation stake in key value storage
 		// To do! Also write a function to get reputation stake, that can be accessed in all pallets using key and who
@@ -250,8 +272,7 @@ impl<T: Config> Pallet<T> {
 
 		// T::Currency::resolve_creating(&Self::juror_stake_account(), imb);
 
-		let imbalance = T::Currency::slash(&who, stake).0;
-		T::Slash::on_unbalanced(imbalance);
+		Self::stake_into_reward_pool(&who, stake)?;
 
 		// let stake_of = Self::stake_of(key.clone(), profile_citizenid)?;
 
@@ -262,7 +283,10 @@ impl<T: Config> Pallet<T> {
 		match stake_of {
 			Some(_stake) => Err(Error::<T>::AlreadyStaked)?,
 			None => {
-				let result = T::SortitionSumGameSource::set_link(key, stake_u64, who);
+				let seniority = Self::juror_seniority(&key, &who, &phase_data);
+				let result = T::SortitionSumGameSource::set_with_seniority_link(
+					key, stake_u64, who, seniority,
+				);
 				result
 			},
 		}
@@ -288,7 +312,9 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	// Improvements: Set stake to zero after a juror is drawn, so that they are not drawn again. Store the stake in storage map in DrawnJurors, and use it in get_incentives_helper
+	/// Draws the jurors of the round in one pass with `draw_many_link`, drawn jurors are removed from the tree.
+	/// Pool jurors that have no free stake to lock are put back with `restore_link`,
+	/// another game may unlock their stake before the next draw.
 	pub(super) fn draw_jurors_helper(
 		key: SumTreeNameType<T>,
 		phase_data: PhaseDataOf<T>,
@@ -300,6 +326,7 @@ impl<T: Config> Pallet<T> {
 			},
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
+		let phase_data = Self::round_phase_data(&key, phase_data);
 		let max_draws = phase_data.max_draws;
 		let draws_in_round = <DrawsInRound<T>>::get(&key);
 		ensure!(draws_in_round < max_draws.into(), Error::<T>::MaxDrawExceeded);
@@ -307,32 +334,39 @@ impl<T: Config> Pallet<T> {
 		if draws_in_round + iterations >= max_draws {
 			end_index = max_draws;
 		}
-		let mut draw_increment = draws_in_round.clone();
 
-		for _ in draws_in_round..end_index {
-			let nonce = Self::get_and_increment_nonce();
-			let random_seed = T::RandomnessSource::random(&nonce).encode();
-			let random_number = u64::decode(&mut random_seed.as_ref())
-				.expect("secure hashes should always be bigger than u64; qed");
-			// let mut rng = rand::thread_rng();
-			// let random_number: u64 = rng.gen();
-			// log::info!("Random number: {:?}", random_number);
-			let accountid = T::SortitionSumGameSource::draw_link(key.clone(), random_number)?;
-			let stake = T::SortitionSumGameSource::stake_of_link(key.clone(), accountid.clone())?;
-
-			let mut drawn_juror = <DrawnJurors<T>>::get(&key);
+		let nonce = Self::get_and_increment_nonce();
+		let random_seed = T::RandomnessSource::random(&nonce).encode();
+		let seed = <[u8; 32]>::decode(&mut random_seed.as_ref())
+			.expect("secure hashes should always be 32 bytes; qed");
+		let drawn = T::SortitionSumGameSource::draw_many_link(
+			key.clone(),
+			seed,
+			end_index - draws_in_round,
+		)?;
+
+		let mut drawn_juror = <DrawnJurors<T>>::get(&key);
+		let mut draw_increment = draws_in_round;
+		let mut not_serving = Vec::new();
+		for (accountid, stake) in drawn {
 			match drawn_juror.binary_search_by(|(c, _)| c.cmp(&accountid)) {
 				Ok(_) => {},
 				Err(index) => {
-					drawn_juror.insert(index, (accountid.clone(), stake.unwrap()));
-					<DrawnJurors<T>>::insert(&key, drawn_juror);
-					draw_increment = draw_increment + 1;
-					// println!("draw_increment, {:?}", draw_increment);
-					T::SortitionSumGameSource::set_link(key.clone(), 0, accountid)?;
+					let serving_stake = Self::serving_stake(&key, &accountid, stake, &phase_data);
+					if serving_stake > 0 {
+						drawn_juror.insert(index, (accountid, serving_stake));
+						draw_increment += 1;
+					} else {
+						not_serving.push((accountid, stake));
+					}
 				},
 			}
-			<DrawsInRound<T>>::insert(&key, draw_increment);
 		}
+		if !not_serving.is_empty() {
+			T::SortitionSumGameSource::restore_link(key.clone(), not_serving)?;
+		}
+		<DrawnJurors<T>>::insert(&key, drawn_juror);
+		<DrawsInRound<T>>::insert(&key, draw_increment);
 		Ok(())
 	}
 
@@ -350,6 +384,7 @@ impl<T: Config> Pallet<T> {
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
 
+		Self::ensure_not_juror_pool_game(&key)?;
 		let drawn_juror = <DrawnJurors<T>>::get(&key);
 		match drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 			Ok(_) => Err(Error::<T>::SelectedAsJuror)?,
@@ -360,7 +395,6 @@ impl<T: Config> Pallet<T> {
 
 		match stake_of {
 			Some(stake) => {
-				let balance = Self::u64_to_balance_saturated(stake);
 				let mut unstaked_jurors = <UnstakedJurors<T>>::get(&key);
 				match unstaked_jurors.binary_search(&who) {
 					Ok(_) => Err(Error::<T>::AlreadyUnstaked)?,
@@ -378,8 +412,7 @@ impl<T: Config> Pallet<T> {
 						// );
 
 						// To do! Substract reputation stake and return the token
-						let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-						T::Reward::on_unbalanced(r);
+						Self::pay_juror_from_pool(&who, stake, stake, 0)?;
 					},
 				}
 			},
@@ -453,6 +486,9 @@ impl<T: Config> Pallet<T> {
 						decision_tuple.0 = decision_tuple.0 + 1;
 						<DecisionCount<T>>::insert(&key, decision_tuple);
 						commit_struct.revealed_vote = Some(RevealedVote::No);
+					} else if choice == 2 {
+						<AbstainCount<T>>::mutate(&key, |count| *count += 1);
+						commit_struct.revealed_vote = Some(RevealedVote::Abstain);
 					} else {
 						Err(Error::<T>::NotValidChoice)?
 					}
@@ -480,6 +516,26 @@ impl<T: Config> Pallet<T> {
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
 
+		Self::pay_two_choice_round_jurors(key.clone(), phase_data)?;
+
+		// Remove SorititionSumTrees in `sortition-sum-game` pallet
+		let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+
+		// Remove DrawnJurors
+		<DrawnJurors<T>>::remove(&key);
+
+		// Remove VoteCommits
+		let _ = <VoteCommits<T>>::clear_prefix(key.clone(), u32::MAX, None);
+
+		Ok(())
+	}
+
+	/// Pay the drawn jurors of the current round according to the ruling of the round
+	pub(super) fn pay_two_choice_round_jurors(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let drawn_jurors = <DrawnJurors<T>>::get(&key);
 		let reveal_votes_iterator = <VoteCommits<T>>::iter_prefix(&key);
 
@@ -488,49 +544,29 @@ impl<T: Config> Pallet<T> {
 			.collect::<Vec<(_, _)>>();
 		reveal_votes.sort_by(|a, b| a.0.cmp(&b.0));
 		let decision_count = <DecisionCount<T>>::get(&key);
+		let abstain_count = <AbstainCount<T>>::get(&key);
 		let incentives = phase_data.juror_incentives;
 		let (winning_decision, winning_incentives) =
-			Self::get_winning_incentives(decision_count, incentives);
+			Self::get_winning_incentives(decision_count, abstain_count, incentives);
 		for juror in drawn_jurors {
 			match reveal_votes.binary_search_by(|(c, _)| c.cmp(&juror.0)) {
 				Ok(index) => {
 					let account_n_vote = reveal_votes[index].clone();
 					if let Some(vote) = account_n_vote.1 {
-						match winning_decision {
-							WinningDecision::WinnerYes => match vote {
-								RevealedVote::Yes => {
-									let result = Self::winner_getting_incentives2(
-										juror.0.clone(),
-										winning_incentives,
-										juror.1,
-									)?;
-									result
-								},
-								RevealedVote::No => {
-									let result =
-										Self::looser_getting_incentives2(juror.0.clone(), juror.1)?;
-									result
-								},
+						match Self::get_juror_game_result(&winning_decision, &vote) {
+							JurorGameResult::Won => {
+								Self::winner_getting_incentives2(
+									&key,
+									juror.0.clone(),
+									winning_incentives,
+									juror.1,
+								)?;
 							},
-							WinningDecision::WinnerNo => match vote {
-								RevealedVote::Yes => {
-									let result =
-										Self::looser_getting_incentives2(juror.0.clone(), juror.1)?;
-									result
-								},
-								RevealedVote::No => {
-									let result = Self::winner_getting_incentives2(
-										juror.0.clone(),
-										winning_incentives,
-										juror.1,
-									)?;
-									result
-								},
+							JurorGameResult::Lost => {
+								Self::looser_getting_incentives2(&key, juror.0.clone(), juror.1)?;
 							},
-							WinningDecision::Draw => {
-								let result =
-									Self::getting_incentives_draw2(juror.0.clone(), juror.1)?;
-								result
+							JurorGameResult::Draw => {
+								Self::getting_incentives_draw2(&key, juror.0.clone(), juror.1)?;
 							},
 						}
 					}
@@ -538,19 +574,6 @@ impl<T: Config> Pallet<T> {
 				Err(_) => {},
 			}
 		}
-		// Remove SorititionSumTrees in `sortition-sum-game` pallet
-		let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
-
-		// Remove DrawnJurors
-		<DrawnJurors<T>>::remove(&key);
-
-		// Remove VoteCommits
-		<VoteCommits<T>>::remove_prefix(key.clone(), None); // Deprecated: Use clear_prefix instead
-													  // let reveal_votes_iterator2 = <VoteCommits<T>>::iter_prefix(&key);
-													  // reveal_votes_iterator2.for_each(|(account_id, _)|{
-													  // 	<VoteCommits<T>>::remove(key.clone(), account_id);
-													  // });
-
 		Ok(())
 	}
 
@@ -560,6 +583,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		who: AccountIdOf<T>,
 	) -> DispatchResult {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		match <PeriodName<T>>::get(&key) {
 			Some(period) => {
 				ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
@@ -576,57 +600,34 @@ impl<T: Config> Pallet<T> {
 				match vote_option {
 					Some(vote) => {
 						let decision_count: (u64, u64) = <DecisionCount<T>>::get(&key);
+						let abstain_count = <AbstainCount<T>>::get(&key);
 						let incentives = phase_data.juror_incentives;
 						let (winning_decision, winning_incentives) =
-							Self::get_winning_incentives(decision_count, incentives);
+							Self::get_winning_incentives(decision_count, abstain_count, incentives);
 						if let Ok(i) = drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 							let stake = drawn_juror[i].1;
-							match winning_decision {
-								WinningDecision::WinnerYes => match vote {
-									RevealedVote::Yes => {
-										let result = Self::winner_getting_incentives(
-											key.clone(),
-											who.clone(),
-											winning_incentives,
-											stake,
-										)?;
-										result
-									},
-									RevealedVote::No => {
-										let result = Self::looser_getting_incentives(
-											key.clone(),
-											who.clone(),
-											stake,
-										)?;
-										result
-									},
+							match Self::get_juror_game_result(&winning_decision, &vote) {
+								JurorGameResult::Won => {
+									Self::winner_getting_incentives(
+										key.clone(),
+										who.clone(),
+										winning_incentives,
+										stake,
+									)?;
 								},
-								WinningDecision::WinnerNo => match vote {
-									RevealedVote::Yes => {
-										let result = Self::looser_getting_incentives(
-											key.clone(),
-											who.clone(),
-											stake,
-										)?;
-										result
-									},
-									RevealedVote::No => {
-										let result = Self::winner_getting_incentives(
-											key.clone(),
-											who.clone(),
-											winning_incentives,
-											stake,
-										)?;
-										result
-									},
+								JurorGameResult::Lost => {
+									Self::looser_getting_incentives(
+										key.clone(),
+										who.clone(),
+										stake,
+									)?;
 								},
-								WinningDecision::Draw => {
-									let result = Self::getting_incentives_draw(
+								JurorGameResult::Draw => {
+									Self::getting_incentives_draw(
 										key.clone(),
 										who.clone(),
 										stake.clone(),
 									)?;
-									result
 								},
 							}
 						} else {
@@ -661,21 +662,13 @@ impl<T: Config> Pallet<T> {
 				match vote_option {
 					Some(vote) => {
 						let decision_count: (u64, u64) = <DecisionCount<T>>::get(&key);
-						let winning_decision = Self::get_winning_decision(decision_count);
+						let abstain_count = <AbstainCount<T>>::get(&key);
+						let winning_decision =
+							Self::get_winning_decision(decision_count, abstain_count);
 						if let Ok(i) = drawn_juror.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
 							let stake = drawn_juror[i].1;
 
-							match winning_decision {
-								WinningDecision::WinnerYes => match vote {
-									RevealedVote::Yes => Ok((JurorGameResult::Won, stake)),
-									RevealedVote::No => Ok((JurorGameResult::Lost, stake)),
-								},
-								WinningDecision::WinnerNo => match vote {
-									RevealedVote::Yes => Ok((JurorGameResult::Lost, stake)),
-									RevealedVote::No => Ok((JurorGameResult::Won, stake)),
-								},
-								WinningDecision::Draw => Ok((JurorGameResult::Draw, stake)),
-							}
+							Ok((Self::get_juror_game_result(&winning_decision, &vote), stake))
 						} else {
 							Err(Error::<T>::StakeDoesNotExists)?
 						}
@@ -707,26 +700,25 @@ impl<T: Config> Pallet<T> {
 		who: AccountIdOf<T>,
 		stake: u64,
 	) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake);
 		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
 		match juror_got_incentives.binary_search(&who) {
 			Ok(_) => Err(Error::<T>::AlreadyGotIncentives)?,
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				Self::settle_juror_stake(&key, &who, stake, stake, 0)?;
 			},
 		}
 
 		Ok(())
 	}
 
-	pub(super) fn getting_incentives_draw2(who: AccountIdOf<T>, stake: u64) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake);
-
-		let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+	pub(super) fn getting_incentives_draw2(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+	) -> DispatchResult {
+		Self::settle_juror_stake(key, &who, stake, stake, 0)?;
 
 		Ok(())
 	}
@@ -736,25 +728,25 @@ impl<T: Config> Pallet<T> {
 		who: AccountIdOf<T>,
 		stake: u64,
 	) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
 		let mut juror_got_incentives = <JurorsIncentiveDistributedAccounts<T>>::get(&key);
 		match juror_got_incentives.binary_search(&who) {
 			Ok(_) => Err(Error::<T>::AlreadyGotIncentives)?,
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				// A quarter of the stake goes to the reward pool
+				Self::settle_juror_stake(&key, &who, stake, stake * 3 / 4, 0)?;
 			},
 		}
 		Ok(())
 	}
 
-	pub(super) fn looser_getting_incentives2(who: AccountIdOf<T>, stake: u64) -> DispatchResult {
-		let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
-
-		let r = T::Currency::deposit_into_existing(&who, balance).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+	pub(super) fn looser_getting_incentives2(
+		key: &SumTreeNameType<T>,
+		who: AccountIdOf<T>,
+		stake: u64,
+	) -> DispatchResult {
+		Self::settle_juror_stake(key, &who, stake, stake * 3 / 4, 0)?;
 
 		Ok(())
 	}
@@ -771,10 +763,7 @@ impl<T: Config> Pallet<T> {
 			Err(index) => {
 				juror_got_incentives.insert(index, who.clone());
 				<JurorsIncentiveDistributedAccounts<T>>::insert(&key, juror_got_incentives);
-				let total_incentives = stake.checked_add(winning_incentives).expect("overflow");
-				let incentives = Self::u64_to_balance_saturated(total_incentives);
-				let r = T::Currency::deposit_into_existing(&who, incentives).ok().unwrap();
-				T::Reward::on_unbalanced(r);
+				Self::settle_juror_stake(&key, &who, stake, stake, winning_incentives)?;
 			},
 		};
 
@@ -782,20 +771,25 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub(super) fn winner_getting_incentives2(
+		key: &SumTreeNameType<T>,
 		who: AccountIdOf<T>,
 		winning_incentives: u64,
 		stake: u64,
 	) -> DispatchResult {
-		let total_incentives = stake.checked_add(winning_incentives).expect("overflow");
-		let incentives = Self::u64_to_balance_saturated(total_incentives);
-		let r = T::Currency::deposit_into_existing(&who, incentives).ok().unwrap();
-		T::Reward::on_unbalanced(r);
+		Self::settle_juror_stake(key, &who, stake, stake, winning_incentives)?;
 
 		Ok(())
 	}
 
-	pub(super) fn get_winning_decision(decision_tuple: (u64, u64)) -> WinningDecision {
-		if decision_tuple.1 > decision_tuple.0 {
+	/// Abstain wins if abstain votes are more than yes and no votes together,
+	/// otherwise abstain votes are ignored and yes is compared with no
+	pub(super) fn get_winning_decision(
+		decision_tuple: (u64, u64),
+		abstain_count: u64,
+	) -> WinningDecision {
+		if abstain_count > decision_tuple.0 + decision_tuple.1 {
+			WinningDecision::Abstain
+		} else if decision_tuple.1 > decision_tuple.0 {
 			WinningDecision::WinnerYes // Decision 1 won
 		} else if decision_tuple.0 > decision_tuple.1 {
 			WinningDecision::WinnerNo // Decision 0 won
@@ -804,6 +798,23 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Result of a juror from their revealed vote
+	/// Abstain voters neither win nor lose unless abstain is the winning decision
+	pub(super) fn get_juror_game_result(
+		winning_decision: &WinningDecision,
+		vote: &RevealedVote,
+	) -> JurorGameResult {
+		match (winning_decision, vote) {
+			(WinningDecision::Draw, _) => JurorGameResult::Draw,
+			(WinningDecision::WinnerYes, RevealedVote::Yes) => JurorGameResult::Won,
+			(WinningDecision::WinnerNo, RevealedVote::No) => JurorGameResult::Won,
+			(WinningDecision::Abstain, RevealedVote::Abstain) => JurorGameResult::Won,
+			(WinningDecision::Abstain, _) => JurorGameResult::Lost,
+			(_, RevealedVote::Abstain) => JurorGameResult::Draw,
+			_ => JurorGameResult::Lost,
+		}
+	}
+
 	pub(super) fn get_winning_decision_value(
 		key: SumTreeNameType<T>,
 	) -> Result<WinningDecision, DispatchError> {
@@ -814,15 +825,17 @@ impl<T: Config> Pallet<T> {
 			None => Err(Error::<T>::PeriodDoesNotExists)?,
 		}
 		let decision_tuple: (u64, u64) = <DecisionCount<T>>::get(&key);
-		let winning_decision = Self::get_winning_decision(decision_tuple);
+		let abstain_count = <AbstainCount<T>>::get(&key);
+		let winning_decision = Self::get_winning_decision(decision_tuple, abstain_count);
 		Ok(winning_decision)
 	}
 
 	pub(super) fn get_winning_incentives(
 		decision_tuple: (u64, u64),
+		abstain_count: u64,
 		incentive_tuple: (u64, u64),
 	) -> (WinningDecision, u64) {
-		let winning_decision = Self::get_winning_decision(decision_tuple);
+		let winning_decision = Self::get_winning_decision(decision_tuple, abstain_count);
 		match winning_decision {
 			WinningDecision::WinnerYes => {
 				let winning_incentives =
@@ -835,6 +848,11 @@ impl<T: Config> Pallet<T> {
 				(WinningDecision::WinnerNo, winning_incentives)
 			},
 			WinningDecision::Draw => (WinningDecision::Draw, 0),
+			WinningDecision::Abstain => {
+				let winning_incentives =
+					(incentive_tuple.1).checked_div(abstain_count).expect("Overflow");
+				(WinningDecision::Abstain, winning_incentives)
+			},
 		}
 	}
 
@@ -862,6 +880,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> Option<u32> {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let start_block_number = <EvidenceStartTime<T>>::get(&key);
 		let evidence_length = phase_data.evidence_length;
 		let end_block = start_block_number.checked_add(&evidence_length).expect("Overflow");
@@ -880,6 +899,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> Option<u32> {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let staking_start_time = <StakingStartTime<T>>::get(&key);
 		let staking_length = phase_data.staking_length;
 		let end_block = staking_start_time.checked_add(&staking_length).expect("Overflow");
@@ -897,6 +917,7 @@ impl<T: Config> Pallet<T> {
 		key: SumTreeNameType<T>,
 		phase_data: PhaseDataOf<T>,
 	) -> (u64, u64, bool) {
+		let phase_data = Self::round_phase_data(&key, phase_data);
 		let max_draws = phase_data.max_draws;
 		let draws_in_round = <DrawsInRound<T>>::get(&key);
 		if draws_in_round >= max_draws.into() {
@@ -911,6 +932,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> Option<u32> {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let commit_start_time = <CommitStartTime<T>>::get(&key);
 		let commit_length = phase_data.commit_length;
 		let end_block = commit_start_time.checked_add(&commit_length).expect("Overflow");
@@ -929,6 +951,7 @@ impl<T: Config> Pallet<T> {
 		phase_data: PhaseDataOf<T>,
 		now: BlockNumberOf<T>,
 	) -> Option<u32> {
+		let phase_data = Self::court_phase_data(&key, phase_data);
 		let vote_start_time = <VoteStartTime<T>>::get(&key);
 		let vote_length = phase_data.vote_length;
 		let end_block = vote_start_time.checked_add(&vote_length).expect("Overflow");
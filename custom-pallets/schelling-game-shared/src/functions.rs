@@ -75,40 +75,19 @@ impl<T: Config> PhaseData<T> {
 		}
 	}
 
-	pub fn create_phase_with_all_data(
-		evidence_length: u64,
-		end_of_staking_time: u64,
-		staking_length: u64,
-		drawing_length: u64,
-		commit_length: u64,
-		vote_length: u64,
-		appeal_length: u64,
-		max_draws: u64,
-		min_number_juror_staked: u64,
-		min_juror_stake: u64,
-		juror_incentives: (u64, u64),
-	) -> Self {
-		let evidence_length = evidence_length.saturated_into::<BlockNumberOf<T>>();
-		let end_of_staking_time = end_of_staking_time.saturated_into::<BlockNumberOf<T>>();
-		let staking_length = staking_length.saturated_into::<BlockNumberOf<T>>();
-		let drawing_length = drawing_length.saturated_into::<BlockNumberOf<T>>();
-		let commit_length = commit_length.saturated_into::<BlockNumberOf<T>>();
-		let vote_length = vote_length.saturated_into::<BlockNumberOf<T>>();
-		let appeal_length = appeal_length.saturated_into::<BlockNumberOf<T>>();
-
-		let min_juror_stake = min_juror_stake.saturated_into::<BalanceOf<T>>();
+	pub fn create_phase_with_all_data(params: PhaseParams) -> Self {
 		PhaseData {
-			evidence_length,
-			end_of_staking_time,
-			staking_length,
-			drawing_length,
-			commit_length,
-			vote_length,
-			appeal_length,
-			max_draws,
-			min_number_juror_staked,
-			min_juror_stake,
-			juror_incentives,
+			evidence_length: params.evidence_length.saturated_into::<BlockNumberOf<T>>(),
+			end_of_staking_time: params.end_of_staking_time.saturated_into::<BlockNumberOf<T>>(),
+			staking_length: params.staking_length.saturated_into::<BlockNumberOf<T>>(),
+			drawing_length: params.drawing_length.saturated_into::<BlockNumberOf<T>>(),
+			commit_length: params.commit_length.saturated_into::<BlockNumberOf<T>>(),
+			vote_length: params.vote_length.saturated_into::<BlockNumberOf<T>>(),
+			appeal_length: params.appeal_length.saturated_into::<BlockNumberOf<T>>(),
+			max_draws: params.max_draws,
+			min_number_juror_staked: params.min_number_juror_staked,
+			min_juror_stake: params.min_juror_stake.saturated_into::<BalanceOf<T>>(),
+			juror_incentives: params.juror_incentives,
 		}
 	}
 }
@@ -0,0 +1,188 @@
+use crate::*;
+use frame_support::sp_runtime::traits::Zero;
+
+impl<T: Config> Pallet<T> {
+	/// Stake in the juror pool of a department, the stake is held in the reward pool account
+	pub(super) fn join_juror_pool_helper(
+		department_id: DepartmentId,
+		who: AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::JurorStakeLessThanMin);
+		if !<JurorPoolStakes<T>>::contains_key(department_id, &who) {
+			let pool_size = <JurorPoolSize<T>>::get(department_id);
+			ensure!(pool_size < T::MaxJurorPoolSize::get(), Error::<T>::JurorPoolFull);
+			<JurorPoolSize<T>>::insert(department_id, pool_size.saturating_add(1));
+			<JurorPoolStakedSince<T>>::insert(
+				department_id,
+				&who,
+				<frame_system::Pallet<T>>::block_number(),
+			);
+		}
+		Self::stake_into_reward_pool(&who, amount)?;
+		let amount_u64 = Self::balance_to_u64_saturated(amount);
+		<JurorPoolStakes<T>>::mutate(department_id, &who, |pool_stake| {
+			pool_stake.total = pool_stake.total.saturating_add(amount_u64);
+		});
+		Self::deposit_event(Event::JurorPoolJoined { department_id, who, amount });
+		Ok(())
+	}
+
+	/// Withdraw stake from the juror pool, stake locked in running games can't be withdrawn
+	pub(super) fn leave_juror_pool_helper(
+		department_id: DepartmentId,
+		who: AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut pool_stake = <JurorPoolStakes<T>>::get(department_id, &who);
+		let amount_u64 = Self::balance_to_u64_saturated(amount);
+		ensure!(
+			!amount.is_zero() && amount_u64 <= pool_stake.available(),
+			Error::<T>::PoolStakeNotEnough
+		);
+		pool_stake.total = pool_stake.total.saturating_sub(amount_u64);
+		Self::insert_pool_stake(department_id, &who, pool_stake);
+		T::Currency::transfer(
+			&Self::reward_pool_account(),
+			&who,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		Self::deposit_event(Event::JurorPoolLeft { department_id, who, amount });
+		Ok(())
+	}
+
+	fn insert_pool_stake(department_id: DepartmentId, who: &AccountIdOf<T>, pool_stake: PoolStake) {
+		if pool_stake.total == 0 && pool_stake.locked == 0 {
+			if <JurorPoolStakes<T>>::contains_key(department_id, who) {
+				<JurorPoolSize<T>>::mutate(department_id, |size| *size = size.saturating_sub(1));
+			}
+			<JurorPoolStakes<T>>::remove(department_id, who);
+			<JurorPoolStakedSince<T>>::remove(department_id, who);
+		} else {
+			<JurorPoolStakes<T>>::insert(department_id, who, pool_stake);
+		}
+	}
+
+	/// Jurors of the game are drawn from the department juror pool instead of staking in the game
+	/// Can only be set before the drawing period
+	pub(super) fn use_juror_pool_helper(
+		key: SumTreeNameType<T>,
+		department_id: DepartmentId,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(
+					period == Period::Evidence || period == Period::Staking,
+					Error::<T>::PeriodDontMatch
+				);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		<GameJurorPool<T>>::insert(&key, department_id);
+		Ok(())
+	}
+
+	pub(super) fn ensure_not_juror_pool_game(key: &SumTreeNameType<T>) -> DispatchResult {
+		ensure!(!<GameJurorPool<T>>::contains_key(key), Error::<T>::JurorPoolGame);
+		Ok(())
+	}
+
+	/// Pool jurors that can serve in the game, with the stake that is not locked in other games
+	pub(super) fn eligible_pool_jurors(
+		key: &SumTreeNameType<T>,
+		department_id: DepartmentId,
+		phase_data: &PhaseDataOf<T>,
+	) -> Vec<(AccountIdOf<T>, u64)> {
+		let min_stake = Self::balance_to_u64_saturated(phase_data.min_juror_stake);
+		<JurorPoolStakes<T>>::iter_prefix(department_id)
+			.map(|(who, pool_stake)| (who, pool_stake.available()))
+			.filter(|(who, available)| {
+				*available > 0 &&
+					*available >= min_stake &&
+					Self::ensure_eligible_for_court(key, who, phase_data.min_juror_stake).is_ok()
+			})
+			.collect()
+	}
+
+	/// Snapshot of the juror pool into the sortition tree of the game, taken when the staking period is over
+	/// Pool size is bounded by `MaxJurorPoolSize`, so is the snapshot. Pool stakes count with their seniority.
+	/// Values of an earlier snapshot, e.g. of an appealed round, are replaced.
+	pub(super) fn snapshot_juror_pool(key: &SumTreeNameType<T>, phase_data: &PhaseDataOf<T>) -> DispatchResult {
+		let department_id = match <GameJurorPool<T>>::get(key) {
+			Some(department_id) => department_id,
+			None => return Ok(()),
+		};
+		for (who, _) in T::SortitionSumGameSource::stakers_link(key.clone())? {
+			T::SortitionSumGameSource::set_link(key.clone(), 0, who)?;
+		}
+		for (who, available) in Self::eligible_pool_jurors(key, department_id, phase_data) {
+			// Pool jurors without VRF key can't select themselves
+			if Self::snapshot_juror_vrf_key(key, &who).is_ok() {
+				let seniority = Self::juror_seniority(key, &who, phase_data);
+				T::SortitionSumGameSource::set_with_seniority_link(
+					key.clone(),
+					available,
+					who,
+					seniority,
+				)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Stake of a drawn juror in the game
+	/// Jurors drawn from a pool lock `min_juror_stake` of their pool stake while serving,
+	/// zero when the juror has no stake left to lock.
+	pub(super) fn serving_stake(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		stake: u64,
+		phase_data: &PhaseDataOf<T>,
+	) -> u64 {
+		let department_id = match <GameJurorPool<T>>::get(key) {
+			Some(department_id) => department_id,
+			None => return stake,
+		};
+		let min_stake = Self::balance_to_u64_saturated(phase_data.min_juror_stake);
+		let mut pool_stake = <JurorPoolStakes<T>>::get(department_id, who);
+		let locked = if min_stake == 0 { pool_stake.available() } else { min_stake };
+		if locked == 0 || locked > pool_stake.available() {
+			return 0;
+		}
+		pool_stake.locked = pool_stake.locked.saturating_add(locked);
+		<JurorPoolStakes<T>>::insert(department_id, who, pool_stake);
+		Self::register_court_service(key, who, phase_data.min_juror_stake);
+		locked
+	}
+
+	/// Settle the stake of a drawn juror, see `pay_juror_from_pool`
+	/// For pool games the stake is unlocked in the pool and the forfeited part is taken from the pool stake,
+	/// only the reward is paid to the juror.
+	pub(super) fn settle_juror_stake(
+		key: &SumTreeNameType<T>,
+		who: &AccountIdOf<T>,
+		stake: u64,
+		returned: u64,
+		reward: u64,
+	) -> DispatchResult {
+		let department_id = match <GameJurorPool<T>>::get(key) {
+			Some(department_id) => department_id,
+			None => return Self::pay_juror_from_pool(who, stake, returned, reward),
+		};
+		let paid = Self::reward_from_budget(who, stake, returned, reward);
+		let mut pool_stake = <JurorPoolStakes<T>>::get(department_id, who);
+		pool_stake.locked = pool_stake.locked.saturating_sub(stake);
+		pool_stake.total = pool_stake.total.saturating_sub(stake.saturating_sub(returned));
+		Self::insert_pool_stake(department_id, who, pool_stake);
+		if !paid.is_zero() {
+			T::Currency::transfer(
+				&Self::reward_pool_account(),
+				who,
+				paid,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(())
+	}
+}
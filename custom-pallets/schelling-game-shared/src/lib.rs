@@ -11,26 +11,41 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+mod appeal;
+mod cancel_game;
+mod court;
 mod extras;
 mod functions;
+mod juror_pool;
+pub mod migrations;
+mod pairwise_game;
+mod reward_pool;
+mod rubric_game;
 mod score_game;
+mod self_selection;
 mod share_link;
 pub mod types;
 
 use crate::types::{
-    CommitVote, JurorGameResult, Period, PhaseData, RangePoint, RevealedVote, SchellingGameType,
-    ScoreCommitVote, VoteStatus, WinningDecision,
+    CancellationReason, CommitVote, Court, CourtId, CourtParams, DepartmentId, PoolStake, JurorGameResult, PairwiseCommitVote, PairwiseGame, Period,
+    PhaseData, RangePoint, RevealedVote, RubricCommitVote, RubricDimension, SchellingGameType, ScoreCommitVote, VoteStatus,
+    WinningDecision,
 };
 use frame_support::pallet_prelude::*;
 use frame_support::sp_runtime::traits::{CheckedAdd, CheckedSub};
 use frame_support::sp_runtime::SaturatedConversion;
 use frame_support::traits::Randomness;
-use frame_support::traits::{Currency, OnUnbalanced, ReservableCurrency};
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency,
+};
+use frame_support::PalletId;
 use frame_system::pallet_prelude::*;
-use num_integer::Roots;
 use pallet_sortition_sum_game::types::SumTreeName;
 use scale_info::prelude::format;
+use sp_arithmetic::{FixedI64, FixedPointNumber, FixedU128};
+use sp_core::sr25519;
 use sp_std::prelude::*;
+use trait_schelling_game_shared::PhaseParams;
 use trait_sortition_sum_game::SortitionSumGameLink;
 
 pub type BlockNumberOf<T> = BlockNumberFor<T>;
@@ -39,9 +54,6 @@ type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balan
 type PositiveImbalanceOf<T> = <<T as Config>::Currency as Currency<
     <T as frame_system::Config>::AccountId,
 >>::PositiveImbalance;
-type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
-    <T as frame_system::Config>::AccountId,
->>::NegativeImbalance;
 type SumTreeNameType<T> = SumTreeName<AccountIdOf<T>, BlockNumberOf<T>>;
 type PhaseDataOf<T> = PhaseData<T>;
 
@@ -49,7 +61,11 @@ type PhaseDataOf<T> = PhaseData<T>;
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -68,11 +84,33 @@ pub mod pallet {
 
         type RandomnessSource: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
-        /// Handler for the unbalanced increment when rewarding (minting rewards)
+        /// Handler for the unbalanced increment when minting the epoch inflation into the reward pool
         type Reward: OnUnbalanced<PositiveImbalanceOf<Self>>;
 
-        /// Handler for the unbalanced decrement when slashing (burning collateral)
-        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+        /// Juror reward pool, holds the juror stakes and the reward budget
+        #[pallet::constant]
+        type RewardPoolPalletId: Get<PalletId>;
+
+        /// Number of blocks in a reward epoch
+        #[pallet::constant]
+        type RewardEpochLength: Get<BlockNumberFor<Self>>;
+
+        /// Amount minted into the reward pool at the start of every epoch
+        #[pallet::constant]
+        type RewardEpochInflation: Get<BalanceOf<Self>>;
+
+        /// Maximum rewards paid from the reward pool in an epoch
+        #[pallet::constant]
+        type MaxRewardPerEpoch: Get<BalanceOf<Self>>;
+
+        /// Appeal fee a side has to raise for every juror of the appeal round
+        #[pallet::constant]
+        type AppealFeePerJuror: Get<BalanceOf<Self>>;
+
+        /// Maximum number of jurors in the juror pool of a department
+        /// The whole pool is snapshotted when the staking period of a pool game is over.
+        #[pallet::constant]
+        type MaxJurorPoolSize: Get<u32>;
     }
 
     #[pallet::storage]
@@ -118,6 +156,43 @@ pub mod pallet {
     pub type UnstakedJurors<T: Config> =
         StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<T::AccountId>, ValueQuery>;
 
+    /// Games where stakers select themselves with a VRF instead of being drawn
+    #[pallet::storage]
+    #[pallet::getter(fn self_selection_enabled)]
+    pub type SelfSelectionEnabled<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, bool, ValueQuery>;
+
+    /// Seed of the round and total stake of the tree at the end of staking period, (seed, total stake)
+    #[pallet::storage]
+    #[pallet::getter(fn self_selection_seed)]
+    pub type SelfSelectionSeed<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, ([u8; 32], u64)>;
+
+    /// VRF key of a juror, used to prove self selection
+    #[pallet::storage]
+    #[pallet::getter(fn juror_vrf_key)]
+    pub type JurorVrfKeys<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, sr25519::Public>;
+
+    /// VRF key of a juror when it staked in a self selection game
+    #[pallet::storage]
+    #[pallet::getter(fn game_vrf_key)]
+    pub type GameVrfKeys<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        sr25519::Public,
+    >;
+
+    /// Self selection games with a snapshot of the juror VRF key
+    /// The key can't be changed until the games are over.
+    #[pallet::storage]
+    #[pallet::getter(fn juror_vrf_key_games)]
+    pub type JurorVrfKeyGames<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<SumTreeNameType<T>>, ValueQuery>;
+
     /// VoteCommits for Yes or No voting
     #[pallet::storage]
     #[pallet::getter(fn vote_commits)]
@@ -142,18 +217,92 @@ pub mod pallet {
         ScoreCommitVote,
     >;
 
-    /// Reveal values of score schelling game as Vec<i64>
+    /// Reveal values of score schelling game as Vec<FixedI64>
     #[pallet::storage]
     #[pallet::getter(fn reveal_score_values)]
     pub type RevealScoreValues<T: Config> =
-        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<i64>, ValueQuery>;
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<FixedI64>, ValueQuery>;
 
     /// New mean from the reveal values in score schelling game
     /// Improvement: This step will not be required if all jurors incentives are distributed at one time
     #[pallet::storage]
     #[pallet::getter(fn new_mean_reveal_score)]
     pub type IncentiveMeanRevealScore<T: Config> =
-        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, i64>;
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, FixedI64>;
+
+    /// Rubric of a rubric score game, set by the pallet that starts the game
+    #[pallet::storage]
+    #[pallet::getter(fn game_rubric)]
+    pub type GameRubric<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<RubricDimension>>;
+
+    /// Vote Commits for Rubric Schelling
+    #[pallet::storage]
+    #[pallet::getter(fn vote_commits_rubric)]
+    pub type RubricVoteCommits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        RubricCommitVote,
+    >;
+
+    /// Reveal values of rubric schelling game, one Vec<FixedI64> for each dimension
+    #[pallet::storage]
+    #[pallet::getter(fn reveal_rubric_values)]
+    pub type RevealRubricValues<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<Vec<FixedI64>>, ValueQuery>;
+
+    /// Mean of every dimension of the rubric, aggregated separately like the score schelling game
+    /// The weighted composite is stored in `IncentiveMeanRevealScore`
+    #[pallet::storage]
+    #[pallet::getter(fn rubric_dimension_means)]
+    pub type RubricDimensionMeans<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<FixedI64>>;
+
+    /// Pairwise comparison game, set by the pallet that starts the game
+    #[pallet::storage]
+    #[pallet::getter(fn pairwise_game)]
+    pub type PairwiseGames<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, PairwiseGame>;
+
+    /// Pairs assigned to a drawn juror when the commit period starts
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pairs)]
+    pub type JurorPairs<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(u64, u64)>,
+        ValueQuery,
+    >;
+
+    /// Vote Commits for Pairwise Schelling
+    #[pallet::storage]
+    #[pallet::getter(fn vote_commits_pairwise)]
+    pub type PairwiseVoteCommits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        T::AccountId,
+        PairwiseCommitVote,
+    >;
+
+    /// Revealed comparisons as Vec<(winner, loser)>
+    #[pallet::storage]
+    #[pallet::getter(fn pairwise_comparisons)]
+    pub type PairwiseComparisons<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<(u64, u64)>, ValueQuery>;
+
+    /// Bradley-Terry strength of the candidates, ordered from the strongest
+    #[pallet::storage]
+    #[pallet::getter(fn pairwise_ranking)]
+    pub type PairwiseRanking<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, Vec<(u64, FixedU128)>>;
 
     /// Decision count for two choices after reveal vote:  (count for 0, count for 1)
     #[pallet::storage]
@@ -161,6 +310,12 @@ pub mod pallet {
     pub type DecisionCount<T> =
         StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, (u64, u64), ValueQuery>; // Count for 0, Count for 1
 
+    /// Count of abstain votes (choice 2) for two choices, tallied separately from `DecisionCount`
+    #[pallet::storage]
+    #[pallet::getter(fn abstain_count)]
+    pub type AbstainCount<T> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, u64, ValueQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn juror_incentive_distribution)]
     pub type JurorsIncentiveDistributedAccounts<T: Config> =
@@ -174,6 +329,160 @@ pub mod pallet {
     // #[pallet::storage]
     // #[pallet::getter(fn )]
 
+    /// Reward budget of the juror reward pool, filled by slashed stakes and epoch inflation
+    /// The pool account also holds the juror stakes, they are not part of the budget
+    #[pallet::storage]
+    #[pallet::getter(fn reward_pool_budget)]
+    pub type RewardPoolBudget<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Current reward epoch, `None` until the first epoch is opened
+    #[pallet::storage]
+    #[pallet::getter(fn reward_epoch)]
+    pub type RewardEpoch<T: Config> = StorageValue<_, BlockNumberOf<T>>;
+
+    /// Rewards paid from the reward pool in the current epoch
+    #[pallet::storage]
+    #[pallet::getter(fn epoch_rewards_paid)]
+    pub type EpochRewardsPaid<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Reason of the cancellation of a game
+    #[pallet::storage]
+    #[pallet::getter(fn cancellation_reason)]
+    pub type GameCancellationReason<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, CancellationReason>;
+
+    /// Number of appeal rounds opened, round 0 is the first jury
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_round)]
+    pub type AppealRound<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_start_time)]
+    pub type AppealStartTime<T> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, BlockNumberOf<T>, ValueQuery>;
+
+    /// Appeal funds raised in a round: (funds for 0, funds for 1)
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_funds)]
+    pub type AppealFunds<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        u32,
+        (BalanceOf<T>, BalanceOf<T>),
+        ValueQuery,
+    >;
+
+    /// Appeal contribution of an account in a round: (contribution for 0, contribution for 1)
+    /// The contributions are held in the reward pool account until they are withdrawn
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_contributions)]
+    pub type AppealContributions<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        SumTreeNameType<T>,
+        Blake2_128Concat,
+        (u32, T::AccountId),
+        (BalanceOf<T>, BalanceOf<T>),
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_court_id)]
+    pub type NextCourtId<T> = StorageValue<_, CourtId, ValueQuery>;
+
+    /// Courts of the court tree, the parent of a court always has a lower id
+    #[pallet::storage]
+    #[pallet::getter(fn courts)]
+    pub type Courts<T> = StorageMap<_, Blake2_128Concat, CourtId, Court>;
+
+    /// Court in which the game is created
+    #[pallet::storage]
+    #[pallet::getter(fn game_court)]
+    pub type GameCourt<T: Config> = StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, CourtId>;
+
+    /// Parameters of the game court with the inherited parameters, when the game was created in it
+    #[pallet::storage]
+    #[pallet::getter(fn game_court_params)]
+    pub type GameCourtParams<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, CourtParams>;
+
+    /// Stake of a juror in a court, reserved from the juror balance
+    /// Juror is eligible for games of the court and of its descendants
+    #[pallet::storage]
+    #[pallet::getter(fn court_stakes)]
+    pub type CourtStakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CourtId,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// Block from which the juror has staked in the court, the seniority of its court stake
+    #[pallet::storage]
+    #[pallet::getter(fn court_staked_since)]
+    pub type CourtStakedSince<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        CourtId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberOf<T>,
+    >;
+
+    /// Court games a juror serves in with the min juror stake of the game
+    /// Court stake the juror needs for these games can't be withdrawn until they are over.
+    #[pallet::storage]
+    #[pallet::getter(fn juror_court_games)]
+    pub type JurorCourtGames<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Vec<(SumTreeNameType<T>, BalanceOf<T>)>,
+        ValueQuery,
+    >;
+
+    /// Stake of jurors in the juror pool of a department, held in the reward pool account
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool_stake)]
+    pub type JurorPoolStakes<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DepartmentId,
+        Blake2_128Concat,
+        T::AccountId,
+        PoolStake,
+        ValueQuery,
+    >;
+
+    /// Block from which the juror has staked in the juror pool of a department, the seniority of its pool stake
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool_staked_since)]
+    pub type JurorPoolStakedSince<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        DepartmentId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberOf<T>,
+    >;
+
+    /// Number of jurors in the juror pool of a department
+    #[pallet::storage]
+    #[pallet::getter(fn juror_pool_size)]
+    pub type JurorPoolSize<T> = StorageMap<_, Blake2_128Concat, DepartmentId, u32, ValueQuery>;
+
+    /// Department juror pool from which the jurors of the game are drawn
+    #[pallet::storage]
+    #[pallet::getter(fn game_juror_pool)]
+    pub type GameJurorPool<T: Config> =
+        StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, DepartmentId>;
+
     // Pallets use events to inform users when important changes are made.
     // https://docs.substrate.io/main-docs/build/events-errors/
     #[pallet::event]
@@ -182,6 +491,43 @@ pub mod pallet {
         /// Event documentation should end with an array that provides descriptive names for event
         /// parameters. [something, who]
         SomethingStored { something: u32, who: T::AccountId },
+        /// Epoch inflation minted into the reward pool. [epoch, amount]
+        RewardPoolFunded { epoch: BlockNumberOf<T>, amount: BalanceOf<T> },
+        /// Funds forfeited in another pallet added to the reward pool. [amount]
+        RewardPoolDeposited { amount: BalanceOf<T> },
+        /// Reward pool could not pay the full reward. [who, requested, paid]
+        RewardPoolInsufficient { who: T::AccountId, requested: BalanceOf<T>, paid: BalanceOf<T> },
+        /// Game cancelled and juror stakes refunded. [key, reason]
+        GameCancelled { key: SumTreeNameType<T>, reason: CancellationReason },
+        /// Appeal fee contributed for a side. [key, round, who, choice, amount]
+        AppealFunded {
+            key: SumTreeNameType<T>,
+            round: u32,
+            who: T::AccountId,
+            choice: u128,
+            amount: BalanceOf<T>,
+        },
+        /// A side is fully funded and a new jury round is opened. [key, round]
+        AppealRoundOpened { key: SumTreeNameType<T>, round: u32 },
+        /// Appeal contribution settled. [key, round, who, amount]
+        AppealContributionWithdrawn {
+            key: SumTreeNameType<T>,
+            round: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// Court created. [court_id, parent]
+        CourtCreated { court_id: CourtId, parent: Option<CourtId> },
+        /// Court parameters updated. [court_id]
+        CourtParamsUpdated { court_id: CourtId },
+        /// Stake added to a court. [court_id, who, amount]
+        CourtStaked { court_id: CourtId, who: T::AccountId, amount: BalanceOf<T> },
+        /// Stake removed from a court. [court_id, who, amount]
+        CourtUnstaked { court_id: CourtId, who: T::AccountId, amount: BalanceOf<T> },
+        /// Stake added to a department juror pool. [department_id, who, amount]
+        JurorPoolJoined { department_id: DepartmentId, who: T::AccountId, amount: BalanceOf<T> },
+        /// Stake withdrawn from a department juror pool. [department_id, who, amount]
+        JurorPoolLeft { department_id: DepartmentId, who: T::AccountId, amount: BalanceOf<T> },
     }
 
     // Errors inform users that something went wrong.
@@ -218,5 +564,146 @@ pub mod pallet {
         TimeForStakingOver,
         TimeForStakingNotOver,
         NewMeanNotInserted,
+        /// Score arithmetic overflowed the fixed point range.
+        ScoreOverflow,
+        /// No revealed score to aggregate.
+        NoRevealedScores,
+        /// Game is cancelled.
+        GameCancelled,
+        /// Game can't be cancelled in its current period.
+        CannotCancelGame,
+        /// Condition for the cancellation reason is not met.
+        CancelConditionNotMet,
+        AppealPeriodNotOver,
+        /// Appeal period is over, the side can't be funded.
+        AppealPeriodOver,
+        /// Appeal contribution is zero or greater than the fund required for the side.
+        AppealAmountGreaterThanRequired,
+        AppealContributionNotExists,
+        /// Rubric is empty or its weights add up to zero.
+        InvalidRubric,
+        RubricNotSet,
+        /// Number of revealed scores is not the number of rubric dimensions.
+        RubricLengthMismatch,
+        /// Pairwise game needs at least two candidates and one pair per juror.
+        InvalidPairwiseGame,
+        PairwiseGameNotSet,
+        /// Number of revealed choices is not the number of assigned pairs.
+        PairsLengthMismatch,
+        PairwiseRankingNotSet,
+        SelfSelectionNotEnabled,
+        VrfKeyNotSet,
+        InvalidVrfProof,
+        /// VRF output is above the stake weighted threshold.
+        JurorNotSelected,
+        CourtDoesNotExists,
+        /// General court is the only court without parent.
+        GeneralCourtExists,
+        /// Juror has not enough stake in the court of the game or its ancestors.
+        NotEligibleForCourt,
+        CourtStakeNotEnough,
+        /// Jurors of the game are drawn from a department juror pool, they don't stake in the game.
+        JurorPoolGame,
+        /// Withdrawal is more than the stake that is not locked in games.
+        PoolStakeNotEnough,
+        /// Game is in its last period, there is no period after execution.
+        GameAlreadyInExecution,
+        /// VRF key can't change while the juror is staked in a self selection game that is not over.
+        VrfKeyLocked,
+        /// Court stake is needed for games the juror serves in.
+        CourtStakeLocked,
+        /// Juror pool of the department has `MaxJurorPoolSize` jurors.
+        JurorPoolFull,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// # Create court
+        ///
+        /// Create a court under `parent`, the General court is created without parent.
+        /// Parameters that are `None` are inherited from the parent court.
+        #[pallet::call_index(0)]
+        #[pallet::weight(0)]
+        pub fn create_court(
+            origin: OriginFor<T>,
+            parent: Option<CourtId>,
+            name: Vec<u8>,
+            params: CourtParams,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::create_court_helper(parent, name, params)?;
+            Ok(())
+        }
+
+        /// # Update court parameters
+        ///
+        /// New parameters apply to the games created in the court and in its descendants after the
+        /// update, running games keep the parameters of the time they were created.
+        #[pallet::call_index(1)]
+        #[pallet::weight(0)]
+        pub fn update_court_params(
+            origin: OriginFor<T>,
+            court_id: CourtId,
+            params: CourtParams,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::update_court_params_helper(court_id, params)
+        }
+
+        /// # Stake in court
+        ///
+        /// Reserve `amount` as stake in the court, the juror can apply to the games of the court
+        /// and of its descendants.
+        #[pallet::call_index(2)]
+        #[pallet::weight(0)]
+        pub fn stake_in_court(
+            origin: OriginFor<T>,
+            court_id: CourtId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::stake_in_court_helper(court_id, who, amount)
+        }
+
+        /// # Unstake from court
+        #[pallet::call_index(3)]
+        #[pallet::weight(0)]
+        pub fn unstake_from_court(
+            origin: OriginFor<T>,
+            court_id: CourtId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::unstake_from_court_helper(court_id, who, amount)
+        }
+
+        /// # Join juror pool
+        ///
+        /// Stake `amount` in the juror pool of the department, the juror is eligible for all games
+        /// of the department without staking in every game.
+        #[pallet::call_index(4)]
+        #[pallet::weight(0)]
+        pub fn join_juror_pool(
+            origin: OriginFor<T>,
+            department_id: DepartmentId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::join_juror_pool_helper(department_id, who, amount)
+        }
+
+        /// # Leave juror pool
+        ///
+        /// Withdraw `amount` of the pool stake that is not locked in running games.
+        #[pallet::call_index(5)]
+        #[pallet::weight(0)]
+        pub fn leave_juror_pool(
+            origin: OriginFor<T>,
+            department_id: DepartmentId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::leave_juror_pool_helper(department_id, who, amount)
+        }
     }
 }
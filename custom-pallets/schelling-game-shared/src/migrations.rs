@@ -0,0 +1,38 @@
+use crate::*;
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+    /// Scores before storage version 1 are integers scaled by 1000.
+    fn score_from_scaled(score: i64) -> FixedI64 {
+        FixedI64::saturating_from_rational(score, 1000)
+    }
+
+    /// Converts the revealed scores and the new means of score schelling games to fixed point.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            <RevealScoreValues<T>>::translate::<Vec<i64>, _>(|_, scores| {
+                translated += 1;
+                Some(scores.into_iter().map(score_from_scaled).collect())
+            });
+            <IncentiveMeanRevealScore<T>>::translate::<i64, _>(|_, mean| {
+                translated += 1;
+                Some(score_from_scaled(mean))
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Migrates the scores to fixed point, runs only when the on-chain storage version is 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,5 +1,5 @@
 use crate as pallet_template;
-use frame_support::derive_impl;
+use frame_support::{derive_impl, parameter_types, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::BuildStorage;
 use sp_std::vec;
@@ -60,17 +60,32 @@ impl frame_system::Config for Test {
     type AccountData = pallet_balances::AccountData<u64>;
 }
 
+parameter_types! {
+    pub const JurorRewardPoolPalletId: PalletId = PalletId(*b"sg/jrpol");
+    pub const RewardEpochLength: u64 = 1000;
+    pub const RewardEpochInflation: u64 = 1_000_000;
+    pub const MaxRewardPerEpoch: u64 = 1_000_000;
+    pub const AppealFeePerJuror: u64 = 10;
+    pub const MaxJurorPoolSize: u32 = 5;
+}
+
 impl pallet_template::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances; // New code
     type RandomnessSource = TestRandomness<Self>;
-    type Slash = ();
     type Reward = ();
     type SortitionSumGameSource = SortitionSumGame;
+    type RewardPoolPalletId = JurorRewardPoolPalletId;
+    type RewardEpochLength = RewardEpochLength;
+    type RewardEpochInflation = RewardEpochInflation;
+    type MaxRewardPerEpoch = MaxRewardPerEpoch;
+    type AppealFeePerJuror = AppealFeePerJuror;
+    type MaxJurorPoolSize = MaxJurorPoolSize;
 }
 
 impl pallet_sortition_sum_game::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -0,0 +1,314 @@
+use crate::*;
+use frame_support::sp_runtime::traits::{One, Saturating, Zero};
+use scale_info::prelude::vec;
+
+/// Iterations of the Bradley-Terry minorization-maximization update
+const BRADLEY_TERRY_ITERATIONS: u32 = 20;
+
+impl<T: Config> Pallet<T> {
+    /// Set the candidates of a pairwise comparison game
+    /// Can only be set before the commit period, pairs are assigned to the drawn jurors when commit period starts
+    pub(super) fn set_pairwise_game_helper(
+        key: SumTreeNameType<T>,
+        candidates: Vec<u64>,
+        pairs_per_juror: u32,
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(
+                    period == Period::Evidence
+                        || period == Period::Staking
+                        || period == Period::Drawing,
+                    Error::<T>::PeriodDontMatch
+                );
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let mut candidates = candidates;
+        candidates.sort();
+        candidates.dedup();
+        ensure!(
+            candidates.len() >= 2 && pairs_per_juror > 0,
+            Error::<T>::InvalidPairwiseGame
+        );
+        <PairwiseGames<T>>::insert(
+            &key,
+            PairwiseGame {
+                candidates,
+                pairs_per_juror,
+            },
+        );
+        Ok(())
+    }
+
+    /// Assign `pairs_per_juror` random pairs of distinct candidates to every drawn juror
+    pub(super) fn assign_pairs(key: &SumTreeNameType<T>) -> DispatchResult {
+        let game = <PairwiseGames<T>>::get(key).ok_or(Error::<T>::PairwiseGameNotSet)?;
+        let candidates_len = game.candidates.len() as u64;
+        ensure!(candidates_len >= 2, Error::<T>::InvalidPairwiseGame);
+        let drawn_jurors = <DrawnJurors<T>>::get(key);
+        for (juror, _) in drawn_jurors {
+            let mut pairs = vec![];
+            for _ in 0..game.pairs_per_juror {
+                let nonce = Self::get_and_increment_nonce();
+                let random_seed = T::RandomnessSource::random(&nonce).encode();
+                let random_number = u64::decode(&mut random_seed.as_ref())
+                    .expect("secure hashes should always be bigger than u64; qed");
+                let first = random_number % candidates_len;
+                // Offset in 1..candidates_len, so that the second candidate is never the first
+                let offset = 1 + (random_number / candidates_len) % (candidates_len - 1);
+                let second = (first + offset) % candidates_len;
+                pairs.push((
+                    game.candidates[first as usize],
+                    game.candidates[second as usize],
+                ));
+            }
+            <JurorPairs<T>>::insert(key, juror, pairs);
+        }
+        Ok(())
+    }
+
+    pub(super) fn get_juror_pairs(key: SumTreeNameType<T>, who: AccountIdOf<T>) -> Vec<(u64, u64)> {
+        <JurorPairs<T>>::get(key, who)
+    }
+
+    /// Commit your pairwise vote
+    /// The commit is keccak_256 of the choices joined by `,` followed by the salt, e.g. "0,1,1salt"
+    pub(super) fn commit_vote_for_pairwise_helper(
+        key: SumTreeNameType<T>,
+        who: AccountIdOf<T>,
+        vote_commit: [u8; 32],
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Commit, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        ensure!(
+            <PairwiseGames<T>>::contains_key(&key),
+            Error::<T>::PairwiseGameNotSet
+        );
+        let drawn_jurors = <DrawnJurors<T>>::get(&key);
+        match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
+            Ok(_) => {
+                let vote_commit_struct = PairwiseCommitVote {
+                    commit: vote_commit,
+                    votestatus: VoteStatus::Commited,
+                    revealed_vote: None,
+                };
+                <PairwiseVoteCommits<T>>::insert(&key, &who, vote_commit_struct);
+            }
+            Err(_) => Err(Error::<T>::JurorDoesNotExists)?,
+        }
+        Ok(())
+    }
+
+    /// Reveal a choice for every assigned pair, in the order of `JurorPairs`
+    /// 0: first item of the pair is better, 1: second item of the pair is better
+    pub(super) fn reveal_vote_pairwise_helper(
+        key: SumTreeNameType<T>,
+        who: AccountIdOf<T>,
+        choices: Vec<u8>,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Vote, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let pairs = <JurorPairs<T>>::get(&key, &who);
+        ensure!(choices.len() == pairs.len(), Error::<T>::PairsLengthMismatch);
+        ensure!(
+            choices.iter().all(|choice| *choice == 0 || *choice == 1),
+            Error::<T>::NotValidChoice
+        );
+        let who_commit_vote = <PairwiseVoteCommits<T>>::get(&key, &who);
+        match who_commit_vote {
+            Some(mut commit_struct) => {
+                ensure!(
+                    commit_struct.votestatus == VoteStatus::Commited,
+                    Error::<T>::VoteStatusNotCommited
+                );
+                let choices_string = choices
+                    .iter()
+                    .map(|choice| format!("{}", choice))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let mut vote = choices_string.as_bytes().to_vec();
+                let mut salt_a = salt.clone();
+                vote.append(&mut salt_a);
+                let vote_bytes: &[u8] = &vote;
+                let hash = sp_io::hashing::keccak_256(vote_bytes);
+                let commit: &[u8] = &commit_struct.commit;
+                if hash == commit {
+                    let mut comparisons = <PairwiseComparisons<T>>::get(&key);
+                    for (pair, choice) in pairs.iter().zip(choices.iter()) {
+                        comparisons.push(Self::pair_outcome(pair, *choice));
+                    }
+                    <PairwiseComparisons<T>>::insert(&key, comparisons);
+                    commit_struct.revealed_vote = Some(choices);
+                    commit_struct.votestatus = VoteStatus::Revealed;
+                    <PairwiseVoteCommits<T>>::insert(&key, &who, commit_struct);
+                } else {
+                    Err(Error::<T>::CommitDoesNotMatch)?
+                }
+            }
+            None => Err(Error::<T>::CommitDoesNotExists)?,
+        }
+
+        Ok(())
+    }
+
+    /// (winner, loser) of a pair for a choice
+    fn pair_outcome(pair: &(u64, u64), choice: u8) -> (u64, u64) {
+        if choice == 1 {
+            (pair.1, pair.0)
+        } else {
+            (pair.0, pair.1)
+        }
+    }
+
+    /// Bradley-Terry strength of the candidates from the revealed comparisons
+    /// <pre>
+    /// Strength is found with the minorization-maximization update:
+    /// p_i = (w_i + 1) / (Σ_j n_ij / (p_i + p_j) + 2 / (p_i + 1))
+    /// w_i: wins of i, n_ij: comparisons of i and j
+    /// Every candidate gets a virtual win and loss against a reference of strength 1,
+    /// so that candidates that never won or never lost have a finite strength.
+    /// </pre>
+    pub(super) fn bradley_terry_strength(
+        candidates: &[u64],
+        comparisons: &[(u64, u64)],
+    ) -> Result<Vec<FixedU128>, DispatchError> {
+        let candidates_len = candidates.len();
+        let mut wins = vec![0u64; candidates_len];
+        let mut matches = vec![];
+        for (winner, loser) in comparisons.iter() {
+            let winner_index = candidates
+                .binary_search(winner)
+                .map_err(|_| Error::<T>::InvalidPairwiseGame)?;
+            let loser_index = candidates
+                .binary_search(loser)
+                .map_err(|_| Error::<T>::InvalidPairwiseGame)?;
+            wins[winner_index] = wins[winner_index].saturating_add(1);
+            matches.push((winner_index, loser_index));
+        }
+
+        let one = FixedU128::one();
+        let two = FixedU128::saturating_from_integer(2u64);
+        let mut strength = vec![one; candidates_len];
+        for _ in 0..BRADLEY_TERRY_ITERATIONS {
+            let mut denominators = vec![FixedU128::zero(); candidates_len];
+            for (index, denominator) in denominators.iter_mut().enumerate() {
+                *denominator = two
+                    .checked_div(&strength[index].saturating_add(one))
+                    .ok_or(Error::<T>::ScoreOverflow)?;
+            }
+            for (a, b) in matches.iter() {
+                let share = one
+                    .checked_div(&strength[*a].saturating_add(strength[*b]))
+                    .ok_or(Error::<T>::ScoreOverflow)?;
+                denominators[*a] = denominators[*a].saturating_add(share);
+                denominators[*b] = denominators[*b].saturating_add(share);
+            }
+            let mut new_strength = vec![];
+            for (index, denominator) in denominators.iter().enumerate() {
+                let numerator = FixedU128::saturating_from_integer(wins[index].saturating_add(1));
+                let value = numerator
+                    .checked_div(denominator)
+                    .ok_or(Error::<T>::ScoreOverflow)?;
+                new_strength.push(value);
+            }
+            strength = new_strength;
+        }
+        Ok(strength)
+    }
+
+    /// Compute the ranking of the candidates in execution period
+    pub(super) fn set_pairwise_ranking_helper(key: SumTreeNameType<T>) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let game = <PairwiseGames<T>>::get(&key).ok_or(Error::<T>::PairwiseGameNotSet)?;
+        let comparisons = <PairwiseComparisons<T>>::get(&key);
+        ensure!(!comparisons.is_empty(), Error::<T>::NoRevealedScores);
+        let strength = Self::bradley_terry_strength(&game.candidates, &comparisons)?;
+        let mut ranking: Vec<(u64, FixedU128)> =
+            game.candidates.into_iter().zip(strength).collect();
+        ranking.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        <PairwiseRanking<T>>::insert(&key, ranking);
+        Ok(())
+    }
+
+    pub(super) fn get_pairwise_ranking(
+        key: SumTreeNameType<T>,
+    ) -> Result<Vec<(u64, FixedU128)>, DispatchError> {
+        let ranking = <PairwiseRanking<T>>::get(key).ok_or(Error::<T>::PairwiseRankingNotSet)?;
+        Ok(ranking)
+    }
+
+    /// Distribute incentives to all jurors in execution period in pairwise schelling game
+    /// A juror wins when more than half of their comparisons agree with the ranking
+    pub(super) fn get_incentives_pairwise_helper(
+        key: SumTreeNameType<T>,
+        phase_data: PhaseDataOf<T>,
+    ) -> DispatchResult {
+        let phase_data = Self::court_phase_data(&key, phase_data);
+        Self::set_pairwise_ranking_helper(key.clone())?;
+        let ranking = Self::get_pairwise_ranking(key.clone())?;
+        let strength_of = |candidate: u64| {
+            ranking
+                .iter()
+                .find(|(id, _)| *id == candidate)
+                .map(|(_, strength)| *strength)
+                .unwrap_or_else(FixedU128::zero)
+        };
+
+        let drawn_jurors = <DrawnJurors<T>>::get(&key);
+        let mut winners = vec![];
+        for juror in drawn_jurors {
+            let revealed_vote = <PairwiseVoteCommits<T>>::get(&key, &juror.0)
+                .and_then(|commit| commit.revealed_vote);
+            if let Some(choices) = revealed_vote {
+                let pairs = <JurorPairs<T>>::get(&key, &juror.0);
+                let agreed = pairs
+                    .iter()
+                    .zip(choices.iter())
+                    .filter(|(pair, choice)| {
+                        let (winner, loser) = Self::pair_outcome(pair, **choice);
+                        strength_of(winner) > strength_of(loser)
+                    })
+                    .count();
+                if agreed * 2 > choices.len() {
+                    winners.push((juror.0.clone(), juror.1));
+                } else {
+                    // deduct incentives, a quarter of the stake goes to the reward pool
+                    let stake = juror.1;
+                    Self::settle_juror_stake(&key, &juror.0, stake, stake * 3 / 4, 0)?;
+                }
+            }
+        }
+
+        let winners_len = winners.len() as u64;
+        let incentives_tuple = phase_data.juror_incentives;
+        // No winners, nothing to share
+        let winning_incentives = incentives_tuple.1.checked_div(winners_len).unwrap_or(0);
+        for winner in winners {
+            Self::settle_juror_stake(&key, &winner.0, winner.1, winner.1, winning_incentives)?;
+        }
+
+        // Remove all data, the game and the ranking are kept for the result
+        let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+        <DrawnJurors<T>>::remove(&key);
+        let _ = <JurorPairs<T>>::clear_prefix(&key, u32::MAX, None);
+        let _ = <PairwiseVoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+        <PairwiseComparisons<T>>::remove(&key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+use frame_support::sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Account of the juror reward pool
+	pub fn reward_pool_account() -> T::AccountId {
+		T::RewardPoolPalletId::get().into_account_truncating()
+	}
+
+	/// Move the juror stake into the reward pool
+	pub(super) fn stake_into_reward_pool(who: &AccountIdOf<T>, stake: BalanceOf<T>) -> DispatchResult {
+		T::Currency::transfer(
+			who,
+			&Self::reward_pool_account(),
+			stake,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		Ok(())
+	}
+
+	/// Open a new epoch once the current one is over
+	/// The inflation of every epoch elapsed since the last update is minted into the pool and the
+	/// epoch rewards paid are reset
+	pub(super) fn update_reward_epoch() {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let epoch_length = T::RewardEpochLength::get();
+		let epoch = if epoch_length.is_zero() { Zero::zero() } else { now / epoch_length };
+		let elapsed_epochs = match <RewardEpoch<T>>::get() {
+			Some(last_epoch) if last_epoch >= epoch => return,
+			Some(last_epoch) => (epoch - last_epoch).saturated_into::<u64>(),
+			None => 1,
+		};
+		<RewardEpoch<T>>::put(epoch);
+		<EpochRewardsPaid<T>>::kill();
+
+		let inflation = T::RewardEpochInflation::get()
+			.saturating_mul(Self::u64_to_balance_saturated(elapsed_epochs));
+		let imbalance = T::Currency::deposit_creating(&Self::reward_pool_account(), inflation);
+		let amount = imbalance.peek();
+		T::Reward::on_unbalanced(imbalance);
+		<RewardPoolBudget<T>>::mutate(|budget| *budget = budget.saturating_add(amount));
+		Self::deposit_event(Event::RewardPoolFunded { epoch, amount });
+	}
+
+	/// Pay a juror from the reward pool
+	/// `returned` of the juror `stake` is given back, the rest goes to the reward budget.
+	/// The `reward` is paid from the budget, limited by the budget and `MaxRewardPerEpoch`,
+	/// `RewardPoolInsufficient` is emitted when it can't be paid in full.
+	pub(super) fn pay_juror_from_pool(
+		who: &AccountIdOf<T>,
+		stake: u64,
+		returned: u64,
+		reward: u64,
+	) -> DispatchResult {
+		let paid = Self::reward_from_budget(who, stake, returned, reward);
+		let amount = Self::u64_to_balance_saturated(returned).saturating_add(paid);
+		T::Currency::transfer(
+			&Self::reward_pool_account(),
+			who,
+			amount,
+			ExistenceRequirement::AllowDeath,
+		)?;
+		Ok(())
+	}
+
+	/// Move the forfeited part of the stake into the reward budget and take the reward from it
+	/// Returns the reward that can be paid
+	pub(super) fn reward_from_budget(
+		who: &AccountIdOf<T>,
+		stake: u64,
+		returned: u64,
+		reward: u64,
+	) -> BalanceOf<T> {
+		Self::update_reward_epoch();
+
+		let forfeited = Self::u64_to_balance_saturated(stake.saturating_sub(returned));
+		let budget = <RewardPoolBudget<T>>::get().saturating_add(forfeited);
+		let epoch_left = T::MaxRewardPerEpoch::get().saturating_sub(<EpochRewardsPaid<T>>::get());
+		let requested = Self::u64_to_balance_saturated(reward);
+		let paid = requested.min(budget).min(epoch_left);
+		if paid < requested {
+			Self::deposit_event(Event::RewardPoolInsufficient {
+				who: who.clone(),
+				requested,
+				paid,
+			});
+		}
+		<RewardPoolBudget<T>>::put(budget.saturating_sub(paid));
+		<EpochRewardsPaid<T>>::mutate(|total| *total = total.saturating_add(paid));
+		paid
+	}
+
+	/// Pay a reward of another pallet from the reward budget, limited as the juror rewards
+	pub(super) fn pay_from_reward_pool(
+		who: &AccountIdOf<T>,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let reward = Self::balance_to_u64_saturated(amount);
+		let paid = Self::reward_from_budget(who, 0, 0, reward);
+		if !paid.is_zero() {
+			T::Currency::transfer(
+				&Self::reward_pool_account(),
+				who,
+				paid,
+				ExistenceRequirement::AllowDeath,
+			)?;
+		}
+		Ok(paid)
+	}
+
+	/// Add funds forfeited in another pallet to the reward budget
+	pub(super) fn deposit_into_reward_pool(amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			return;
+		}
+		let imbalance = T::Currency::deposit_creating(&Self::reward_pool_account(), amount);
+		let amount = imbalance.peek();
+		T::Reward::on_unbalanced(imbalance);
+		<RewardPoolBudget<T>>::mutate(|budget| *budget = budget.saturating_add(amount));
+		Self::deposit_event(Event::RewardPoolDeposited { amount });
+	}
+}
@@ -0,0 +1,221 @@
+use crate::*;
+use frame_support::sp_runtime::traits::{CheckedDiv, CheckedMul, Zero};
+use scale_info::prelude::vec;
+
+impl<T: Config> Pallet<T> {
+    /// Set the rubric of the game, jurors reveal a score for every dimension
+    /// Can only be set before the commit period
+    pub(super) fn set_rubric_helper(
+        key: SumTreeNameType<T>,
+        rubric: Vec<RubricDimension>,
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(
+                    period == Period::Evidence
+                        || period == Period::Staking
+                        || period == Period::Drawing,
+                    Error::<T>::PeriodDontMatch
+                );
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let total_weight = rubric
+            .iter()
+            .fold(0u32, |total, dimension| total.saturating_add(dimension.weight));
+        ensure!(!rubric.is_empty() && total_weight > 0, Error::<T>::InvalidRubric);
+        <GameRubric<T>>::insert(&key, rubric);
+        Ok(())
+    }
+
+    /// Commit your rubric vote
+    /// The commit is keccak_256 of the scores joined by `,` followed by the salt, e.g. "3,4,5,2salt"
+    pub(super) fn commit_vote_for_rubric_helper(
+        key: SumTreeNameType<T>,
+        who: AccountIdOf<T>,
+        vote_commit: [u8; 32],
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Commit, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        ensure!(<GameRubric<T>>::contains_key(&key), Error::<T>::RubricNotSet);
+        let drawn_jurors = <DrawnJurors<T>>::get(&key);
+        match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who.clone())) {
+            Ok(_) => {
+                let vote_commit_struct = RubricCommitVote {
+                    commit: vote_commit,
+                    votestatus: VoteStatus::Commited,
+                    revealed_vote: None,
+                };
+                <RubricVoteCommits<T>>::insert(&key, &who, vote_commit_struct);
+            }
+            Err(_) => Err(Error::<T>::JurorDoesNotExists)?,
+        }
+        Ok(())
+    }
+
+    /// choices are i64, one for every dimension. Validate the range of the choices while using the function
+    pub(super) fn reveal_vote_rubric_helper(
+        key: SumTreeNameType<T>,
+        who: AccountIdOf<T>,
+        choices: Vec<i64>,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Vote, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let rubric = <GameRubric<T>>::get(&key).ok_or(Error::<T>::RubricNotSet)?;
+        ensure!(choices.len() == rubric.len(), Error::<T>::RubricLengthMismatch);
+        let who_commit_vote = <RubricVoteCommits<T>>::get(&key, &who);
+        match who_commit_vote {
+            Some(mut commit_struct) => {
+                ensure!(
+                    commit_struct.votestatus == VoteStatus::Commited,
+                    Error::<T>::VoteStatusNotCommited
+                );
+                let choices_string = choices
+                    .iter()
+                    .map(|choice| format!("{}", choice))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let mut vote = choices_string.as_bytes().to_vec();
+                let mut salt_a = salt.clone();
+                vote.append(&mut salt_a);
+                let vote_bytes: &[u8] = &vote;
+                let hash = sp_io::hashing::keccak_256(vote_bytes);
+                let commit: &[u8] = &commit_struct.commit;
+                if hash == commit {
+                    let mut reveal_rubric_values = <RevealRubricValues<T>>::get(&key);
+                    if reveal_rubric_values.is_empty() {
+                        reveal_rubric_values = vec![vec![]; rubric.len()];
+                    }
+                    for (index, choice) in choices.iter().enumerate() {
+                        let score = Self::score_to_fixed(*choice)?;
+                        reveal_rubric_values[index].push(score);
+                    }
+                    <RevealRubricValues<T>>::insert(&key, reveal_rubric_values);
+                    commit_struct.revealed_vote = Some(choices);
+                    commit_struct.votestatus = VoteStatus::Revealed;
+                    <RubricVoteCommits<T>>::insert(&key, &who, commit_struct);
+                } else {
+                    Err(Error::<T>::CommitDoesNotMatch)?
+                }
+            }
+            None => Err(Error::<T>::CommitDoesNotExists)?,
+        }
+
+        Ok(())
+    }
+
+    /// Weighted mean of the dimension scores
+    pub(super) fn rubric_composite(
+        rubric: &[RubricDimension],
+        scores: &[FixedI64],
+    ) -> Result<FixedI64, DispatchError> {
+        ensure!(scores.len() == rubric.len(), Error::<T>::RubricLengthMismatch);
+        let mut weighted_sum = FixedI64::zero();
+        let mut total_weight = 0u32;
+        for (dimension, score) in rubric.iter().zip(scores.iter()) {
+            let weighted = score
+                .checked_mul(&FixedI64::from_u32(dimension.weight))
+                .ok_or(Error::<T>::ScoreOverflow)?;
+            weighted_sum = weighted_sum
+                .checked_add(&weighted)
+                .ok_or(Error::<T>::ScoreOverflow)?;
+            total_weight = total_weight.saturating_add(dimension.weight);
+        }
+        ensure!(total_weight > 0, Error::<T>::InvalidRubric);
+        let composite = weighted_sum
+            .checked_div(&FixedI64::from_u32(total_weight))
+            .ok_or(Error::<T>::ScoreOverflow)?;
+        Ok(composite)
+    }
+
+    /// Every dimension is aggregated separately with the mean within one standard deviation,
+    /// the weighted composite of the dimension means is stored in `IncentiveMeanRevealScore`
+    pub(super) fn set_new_rubric_mean_value(key: SumTreeNameType<T>) -> DispatchResult {
+        match <PeriodName<T>>::get(&key) {
+            Some(period) => {
+                ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
+            }
+            None => Err(Error::<T>::PeriodDoesNotExists)?,
+        }
+        let rubric = <GameRubric<T>>::get(&key).ok_or(Error::<T>::RubricNotSet)?;
+        let reveal_rubric_values = <RevealRubricValues<T>>::get(&key);
+        ensure!(!reveal_rubric_values.is_empty(), Error::<T>::NoRevealedScores);
+        let mut dimension_means = vec![];
+        for reveal_values in reveal_rubric_values.iter() {
+            let sd_and_mean = Self::std_deviation_fixed(reveal_values)?;
+            let new_mean = Self::calculate_new_mean(reveal_values, sd_and_mean)?;
+            dimension_means.push(new_mean);
+        }
+        let composite = Self::rubric_composite(&rubric, &dimension_means)?;
+        <RubricDimensionMeans<T>>::insert(&key, dimension_means);
+        <IncentiveMeanRevealScore<T>>::insert(&key, composite);
+        Ok(())
+    }
+
+    pub(super) fn get_rubric_dimension_means(
+        key: SumTreeNameType<T>,
+    ) -> Result<Vec<FixedI64>, DispatchError> {
+        let means = <RubricDimensionMeans<T>>::get(key).ok_or(Error::<T>::NewMeanNotInserted)?;
+        Ok(means)
+    }
+
+    /// Distribute incentives to all jurors in execution period in rubric schelling game
+    /// A juror wins when the weighted composite of their scores is within the incentives range of the composite mean
+    pub(super) fn get_incentives_rubric_schelling_helper(
+        key: SumTreeNameType<T>,
+        phase_data: PhaseDataOf<T>,
+        range_point: RangePoint,
+    ) -> DispatchResult {
+        let phase_data = Self::court_phase_data(&key, phase_data);
+        Self::set_new_rubric_mean_value(key.clone())?;
+        let rubric = <GameRubric<T>>::get(&key).ok_or(Error::<T>::RubricNotSet)?;
+        let composite_mean = Self::get_mean_value(key.clone())?;
+        let (lower, upper) = Self::get_incentives_window(composite_mean, range_point)?;
+
+        let drawn_jurors = <DrawnJurors<T>>::get(&key);
+        let mut winners = vec![];
+        for juror in drawn_jurors {
+            let revealed_vote =
+                <RubricVoteCommits<T>>::get(&key, &juror.0).and_then(|commit| commit.revealed_vote);
+            if let Some(choices) = revealed_vote {
+                let mut scores = vec![];
+                for choice in choices {
+                    scores.push(Self::score_to_fixed(choice)?);
+                }
+                let vote = Self::rubric_composite(&rubric, &scores)?;
+                if vote >= lower && vote <= upper {
+                    winners.push((juror.0.clone(), juror.1));
+                } else {
+                    // deduct incentives, a quarter of the stake goes to the reward pool
+                    let stake = juror.1;
+                    Self::settle_juror_stake(&key, &juror.0, stake, stake * 3 / 4, 0)?;
+                }
+            }
+        }
+
+        let winners_len = winners.len() as u64;
+        let incentives_tuple = phase_data.juror_incentives;
+        // No winners, nothing to share
+        let winning_incentives = incentives_tuple.1.checked_div(winners_len).unwrap_or(0);
+        for winner in winners {
+            Self::settle_juror_stake(&key, &winner.0, winner.1, winner.1, winning_incentives)?;
+        }
+
+        // Remove all data, the rubric and the means are kept for the result
+        let _result = T::SortitionSumGameSource::remove_tree_link(key.clone());
+        <DrawnJurors<T>>::remove(&key);
+        let _ = <RubricVoteCommits<T>>::clear_prefix(&key, u32::MAX, None);
+        <RevealRubricValues<T>>::remove(&key);
+
+        Ok(())
+    }
+}
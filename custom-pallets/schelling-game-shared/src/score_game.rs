@@ -1,4 +1,5 @@
 use crate::*;
+use frame_support::sp_runtime::traits::{CheckedDiv, CheckedMul, Zero};
 use scale_info::prelude::vec;
 
 impl<T: Config> Pallet<T> {
@@ -69,6 +70,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// choice is i64. Validate the range of the choice while using the function
+    /// The revealed choice is stored as `FixedI64`
     pub(super) fn reveal_vote_score_helper(
         key: SumTreeNameType<T>,
         who: AccountIdOf<T>,
@@ -96,8 +98,9 @@ impl<T: Config> Pallet<T> {
                 let hash = sp_io::hashing::keccak_256(vote_bytes);
                 let commit: &[u8] = &commit_struct.commit;
                 if hash == commit {
+                    let score = Self::score_to_fixed(choice)?;
                     let mut reveal_score_values = <RevealScoreValues<T>>::get(&key);
-                    reveal_score_values.push(choice * 1000); // Choice is multiplied by 1000 to get mean and sd in 1000's
+                    reveal_score_values.push(score);
                     <RevealScoreValues<T>>::insert(&key, reveal_score_values);
                     commit_struct.revealed_vote = Some(choice);
                     commit_struct.votestatus = VoteStatus::Revealed;
@@ -118,6 +121,7 @@ impl<T: Config> Pallet<T> {
         phase_data: PhaseDataOf<T>,
         range_point: RangePoint,
     ) -> DispatchResult {
+        let phase_data = Self::court_phase_data(&key, phase_data);
         match <PeriodName<T>>::get(&key) {
             Some(period) => {
                 ensure!(period == Period::Execution, Error::<T>::PeriodDontMatch);
@@ -128,11 +132,11 @@ impl<T: Config> Pallet<T> {
         let drawn_jurors = <DrawnJurors<T>>::get(&key);
         let reveal_votes_iterator = <ScoreVoteCommits<T>>::iter_prefix(&key);
         let reveal_values = <RevealScoreValues<T>>::get(&key);
-        let sd_and_mean = Self::std_deviation_interger(&reveal_values);
-        let new_mean = Self::calculate_new_mean(&reveal_values, sd_and_mean).unwrap();
+        let sd_and_mean = Self::std_deviation_fixed(&reveal_values)?;
+        let new_mean = Self::calculate_new_mean(&reveal_values, sd_and_mean)?;
         // println!("new mean: {:?}", new_mean);
         <IncentiveMeanRevealScore<T>>::insert(key.clone(), new_mean);
-        let incentives_range = Self::get_incentives_range(range_point);
+        let (lower, upper) = Self::get_incentives_window(new_mean, range_point)?;
         let mut reveal_votes = reveal_votes_iterator
             .map(|(account_id, score_commit_vote)| (account_id, score_commit_vote.revealed_vote))
             .collect::<Vec<(_, _)>>();
@@ -147,19 +151,14 @@ impl<T: Config> Pallet<T> {
                     let account_n_vote = &reveal_votes[index];
                     if let Some(i) = account_n_vote.1 {
                         // println!("vote {:?}", i);
-                        if i * 1000 >= new_mean.checked_sub(incentives_range).unwrap() // i is mupliplied by 1000 as new_mean is in 1000's as choice is mupltiple by 1000, and incentives range is also in 1000's
-							&& i * 1000 <= new_mean.checked_add(incentives_range).unwrap()
-                        {
+                        let vote = Self::score_to_fixed(i)?;
+                        if vote >= lower && vote <= upper {
                             // get incentives
                             winners.push((juror.0.clone(), juror.1.clone()));
                         } else {
-                            // deduct incentives
+                            // deduct incentives, a quarter of the stake goes to the reward pool
                             let stake = juror.1;
-                            let balance = Self::u64_to_balance_saturated(stake * 3 / 4);
-                            let r = T::Currency::deposit_into_existing(&juror.0, balance)
-                                .ok()
-                                .unwrap();
-                            T::Reward::on_unbalanced(r);
+                            Self::settle_juror_stake(&key, &juror.0, stake, stake * 3 / 4, 0)?;
                         }
                     }
                 }
@@ -172,17 +171,10 @@ impl<T: Config> Pallet<T> {
         let winners_len = winners.len() as u64;
         // println!("winners_len {}", winners_len);
         let incentives_tuple = phase_data.juror_incentives;
-        let winning_incentives = incentives_tuple
-            .1
-            .checked_div(winners_len)
-            .expect("oveflow");
+        // No winners, nothing to share
+        let winning_incentives = incentives_tuple.1.checked_div(winners_len).unwrap_or(0);
         for winner in winners {
-            let total_incentives = winner.1.checked_add(winning_incentives).expect("overflow");
-            let incentives = Self::u64_to_balance_saturated(total_incentives);
-            let r = T::Currency::deposit_into_existing(&winner.0, incentives)
-                .ok()
-                .unwrap();
-            T::Reward::on_unbalanced(r);
+            Self::settle_juror_stake(&key, &winner.0, winner.1, winner.1, winning_incentives)?;
         }
 
         // Remove all data
@@ -229,16 +221,15 @@ impl<T: Config> Pallet<T> {
             Err(Error::<T>::StakeDoesNotExists)?
         }
 
-        let incentives_range = Self::get_incentives_range(range_point);
+        let (lower, upper) = Self::get_incentives_window(new_mean, range_point)?;
         let reveal_votes = <ScoreVoteCommits<T>>::get(&key, &who);
         match reveal_votes {
             Some(commit_struct) => {
                 let vote_option = commit_struct.revealed_vote;
                 match vote_option {
                     Some(vote) => {
-                        if vote * 1000 >= new_mean.checked_sub(incentives_range).unwrap()
-                            && vote * 1000 <= new_mean.checked_add(incentives_range).unwrap()
-                        {
+                        let vote = Self::score_to_fixed(vote)?;
+                        if vote >= lower && vote <= upper {
                             // get incentives
                             Ok((JurorGameResult::Won, stake))
                         } else {
@@ -260,15 +251,15 @@ impl<T: Config> Pallet<T> {
             None => Err(Error::<T>::PeriodDoesNotExists)?,
         }
         let reveal_values = <RevealScoreValues<T>>::get(&key);
-        let sd_and_mean = Self::std_deviation_interger(&reveal_values);
-        let new_mean = Self::calculate_new_mean(&reveal_values, sd_and_mean).unwrap();
+        let sd_and_mean = Self::std_deviation_fixed(&reveal_values)?;
+        let new_mean = Self::calculate_new_mean(&reveal_values, sd_and_mean)?;
         // println!("new mean: {:?}", new_mean);
         <IncentiveMeanRevealScore<T>>::insert(key.clone(), new_mean);
 
         Ok(())
     }
 
-    pub(super) fn get_mean_value(key: SumTreeNameType<T>) -> Result<i64, DispatchError> {
+    pub(super) fn get_mean_value(key: SumTreeNameType<T>) -> Result<FixedI64, DispatchError> {
         let mean_option = <IncentiveMeanRevealScore<T>>::get(key);
         match mean_option {
             Some(mean) => Ok(mean),
@@ -276,57 +267,93 @@ impl<T: Config> Pallet<T> {
         }
     }
 
-    /// Calculate the mean of integer
-    pub(super) fn mean_integer(data: &Vec<i64>) -> Option<i64> {
-        let data_mul_sum = data.iter().sum::<i64>();
-        let count = data.len();
+    /// Convert a revealed choice to `FixedI64`
+    pub(super) fn score_to_fixed(choice: i64) -> Result<FixedI64, DispatchError> {
+        let score = FixedI64::checked_from_integer(choice).ok_or(Error::<T>::ScoreOverflow)?;
+        Ok(score)
+    }
 
-        match count {
-            positive if positive > 0 => Some(data_mul_sum / count as i64),
-            _ => None,
+    /// Calculate the mean of fixed point scores
+    /// Division rounds towards zero at the last decimal of `FixedI64`
+    pub(super) fn mean_fixed(data: &Vec<FixedI64>) -> Result<FixedI64, DispatchError> {
+        ensure!(!data.is_empty(), Error::<T>::NoRevealedScores);
+        let mut sum = FixedI64::zero();
+        for value in data {
+            sum = sum.checked_add(value).ok_or(Error::<T>::ScoreOverflow)?;
         }
+        let count =
+            FixedI64::checked_from_integer(data.len() as i64).ok_or(Error::<T>::ScoreOverflow)?;
+        let mean = sum.checked_div(&count).ok_or(Error::<T>::ScoreOverflow)?;
+        Ok(mean)
     }
 
-    pub(super) fn std_deviation_interger(data: &Vec<i64>) -> Option<(i64, i64)> {
-        let mean = Self::mean_integer(data);
-        match (mean, data.len()) {
-            (Some(data_mean), count) if count > 0 => {
-                let variance = data
-                    .iter()
-                    .map(|value| {
-                        let diff = data_mean.checked_sub(*value as i64).unwrap();
-                        diff * diff
-                    })
-                    .sum::<i64>()
-                    / count as i64;
-
-                Some((variance.sqrt(), mean.unwrap()))
-            }
-            _ => None,
+    /// Population standard deviation and mean of the scores as (sd, mean)
+    /// Variance is accumulated in `FixedU128` and its square root is rounded down
+    pub(super) fn std_deviation_fixed(
+        data: &Vec<FixedI64>,
+    ) -> Result<(FixedU128, FixedI64), DispatchError> {
+        let mean = Self::mean_fixed(data)?;
+        let mut sum_of_squares = FixedU128::zero();
+        for value in data {
+            let diff = mean.checked_sub(value).ok_or(Error::<T>::ScoreOverflow)?;
+            let diff = Self::fixed_i64_abs_to_u128(diff);
+            let square = diff.checked_mul(&diff).ok_or(Error::<T>::ScoreOverflow)?;
+            sum_of_squares = sum_of_squares.checked_add(&square).ok_or(Error::<T>::ScoreOverflow)?;
         }
+        let count =
+            FixedU128::checked_from_integer(data.len() as u128).ok_or(Error::<T>::ScoreOverflow)?;
+        let variance = sum_of_squares.checked_div(&count).ok_or(Error::<T>::ScoreOverflow)?;
+        let sd = variance.try_sqrt().ok_or(Error::<T>::ScoreOverflow)?;
+        Ok((sd, mean))
     }
 
+    /// Mean of the scores that lie within one standard deviation of the mean (inclusive)
+    /// The standard deviation is truncated to `FixedI64` precision before building the window
     pub(super) fn calculate_new_mean(
-        data: &Vec<i64>,
-        sd_and_mean: Option<(i64, i64)>,
-    ) -> Option<i64> {
-        let mut new_items = vec![];
-        let mean = sd_and_mean.unwrap().1;
-        let sd = sd_and_mean.unwrap().0;
-        for x in data {
-            if *x >= mean.checked_sub(sd).unwrap() && *x <= mean.checked_add(sd).unwrap() {
-                new_items.push(*x);
-            }
-        }
-        let new_mean = Self::mean_integer(&new_items);
-        new_mean
+        data: &[FixedI64],
+        sd_and_mean: (FixedU128, FixedI64),
+    ) -> Result<FixedI64, DispatchError> {
+        let (sd, mean) = sd_and_mean;
+        let sd = Self::fixed_u128_to_i64(sd)?;
+        let lower = mean.checked_sub(&sd).ok_or(Error::<T>::ScoreOverflow)?;
+        let upper = mean.checked_add(&sd).ok_or(Error::<T>::ScoreOverflow)?;
+        let new_items = data
+            .iter()
+            .filter(|x| **x >= lower && **x <= upper)
+            .cloned()
+            .collect::<Vec<FixedI64>>();
+        Self::mean_fixed(&new_items)
     }
 
-    pub(super) fn get_incentives_range(range_point: RangePoint) -> i64 {
+    pub(super) fn get_incentives_range(range_point: RangePoint) -> FixedI64 {
         match range_point {
-            RangePoint::ZeroToTen => 1500, //3 points,  1.5 ± mean, multiply by 1000 to make it integer
-            RangePoint::MinusTenToPlusTen => 3000, //6 points, 3 ± mean
-            RangePoint::ZeroToFive => 750, //1.5 points, 0.75 ± mean
+            RangePoint::ZeroToTen => FixedI64::from_rational(3, 2), //3 points,  1.5 ± mean
+            RangePoint::MinusTenToPlusTen => FixedI64::from_u32(3), //6 points, 3 ± mean
+            RangePoint::ZeroToFive => FixedI64::from_rational(3, 4), //1.5 points, 0.75 ± mean
         }
     }
+
+    /// Scores within (lower, upper), both ends inclusive, get incentives
+    pub(super) fn get_incentives_window(
+        mean: FixedI64,
+        range_point: RangePoint,
+    ) -> Result<(FixedI64, FixedI64), DispatchError> {
+        let incentives_range = Self::get_incentives_range(range_point);
+        let lower = mean.checked_sub(&incentives_range).ok_or(Error::<T>::ScoreOverflow)?;
+        let upper = mean.checked_add(&incentives_range).ok_or(Error::<T>::ScoreOverflow)?;
+        Ok((lower, upper))
+    }
+
+    /// Absolute value as `FixedU128`, exact as `FixedU128` has more decimals than `FixedI64`
+    fn fixed_i64_abs_to_u128(value: FixedI64) -> FixedU128 {
+        let inner = value.into_inner().unsigned_abs() as u128;
+        FixedU128::from_rational(inner, FixedI64::DIV as u128)
+    }
+
+    /// Convert `FixedU128` to `FixedI64`, extra decimals are rounded down
+    fn fixed_u128_to_i64(value: FixedU128) -> Result<FixedI64, DispatchError> {
+        let scale = FixedU128::DIV / (FixedI64::DIV as u128);
+        let inner = i64::try_from(value.into_inner() / scale).map_err(|_| Error::<T>::ScoreOverflow)?;
+        Ok(FixedI64::from_inner(inner))
+    }
 }
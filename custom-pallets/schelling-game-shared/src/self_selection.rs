@@ -0,0 +1,152 @@
+use crate::*;
+use sp_core::crypto::VrfPublic;
+use sp_core::sr25519::vrf::{VrfSignData, VrfSignature, VrfTranscript};
+
+/// Label of the VRF transcript for juror self selection
+const SELF_SELECTION_VRF_LABEL: &[u8] = b"shivarthu-juror-selection";
+
+impl<T: Config> Pallet<T> {
+	/// Stakers of the game select themselves with their VRF key instead of being drawn
+	/// Can only be set before anyone staked, so the key of every staker is snapshotted
+	pub(super) fn enable_self_selection_helper(key: SumTreeNameType<T>) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(Period::Evidence) => {},
+			Some(Period::Staking) => {
+				let stakers = T::SortitionSumGameSource::stakers_link(key.clone()).unwrap_or_default();
+				ensure!(stakers.is_empty(), Error::<T>::AlreadyStaked);
+			},
+			Some(_) => Err(Error::<T>::PeriodDontMatch)?,
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		<SelfSelectionEnabled<T>>::insert(&key, true);
+		Ok(())
+	}
+
+	/// Set the VRF key that proves self selection of `who`
+	/// The key can't change while the juror is staked in a self selection game that is not over,
+	/// the seed of the game is known after staking and a new key could be ground against it.
+	pub(super) fn set_juror_vrf_key_helper(who: AccountIdOf<T>, vrf_key: sr25519::Public) -> DispatchResult {
+		let running_games = <JurorVrfKeyGames<T>>::get(&who)
+			.into_iter()
+			.filter(|key| {
+				!matches!(
+					<PeriodName<T>>::get(key),
+					Some(Period::Execution) | Some(Period::Cancelled) | None
+				)
+			})
+			.count();
+		ensure!(running_games == 0, Error::<T>::VrfKeyLocked);
+		<JurorVrfKeyGames<T>>::remove(&who);
+		<JurorVrfKeys<T>>::insert(&who, vrf_key);
+		Ok(())
+	}
+
+	/// Snapshot of the VRF key of a juror that stakes in a self selection game
+	/// Self selection of the juror is verified against the snapshot.
+	pub(super) fn snapshot_juror_vrf_key(key: &SumTreeNameType<T>, who: &AccountIdOf<T>) -> DispatchResult {
+		if !<SelfSelectionEnabled<T>>::get(key) {
+			return Ok(());
+		}
+		let vrf_key = <JurorVrfKeys<T>>::get(who).ok_or(Error::<T>::VrfKeyNotSet)?;
+		<GameVrfKeys<T>>::insert(key, who, vrf_key);
+		<JurorVrfKeyGames<T>>::mutate(who, |games| {
+			if !games.contains(key) {
+				games.push(key.clone());
+			}
+		});
+		Ok(())
+	}
+
+	/// Seed of the round and total stake, set when staking period is over
+	pub(super) fn set_self_selection_seed(key: &SumTreeNameType<T>) -> DispatchResult {
+		let nonce = Self::get_and_increment_nonce();
+		let random_seed = T::RandomnessSource::random(&nonce).encode();
+		let seed = sp_io::hashing::blake2_256(&random_seed);
+		let total_stake = T::SortitionSumGameSource::stakers_link(key.clone())?
+			.iter()
+			.fold(0u64, |total, (_, stake)| total.saturating_add(*stake));
+		<SelfSelectionSeed<T>>::insert(key, (seed, total_stake));
+		Ok(())
+	}
+
+	/// Data signed by the juror VRF key, the seed of the round in a transcript
+	pub fn self_selection_sign_data(seed: [u8; 32]) -> VrfSignData {
+		let transcript = VrfTranscript::new(SELF_SELECTION_VRF_LABEL, &[(b"seed", &seed)]);
+		VrfSignData::new(transcript)
+	}
+
+	/// Juror is selected when the VRF output is within the stake weighted threshold
+	/// <pre>
+	/// output <= u128::MAX / total_stake * stake * max_draws
+	/// </pre>
+	/// So `max_draws` jurors are expected to be selected.
+	pub(super) fn within_self_selection_threshold(
+		output: u128,
+		stake: u64,
+		total_stake: u64,
+		max_draws: u64,
+	) -> bool {
+		if total_stake == 0 {
+			return false;
+		}
+		let threshold = (u128::MAX / total_stake as u128)
+			.saturating_mul((stake as u128).saturating_mul(max_draws as u128));
+		output <= threshold
+	}
+
+	/// Commit the vote together with the VRF proof of selection
+	/// The juror becomes a drawn juror only now, the stake is set to zero in the tree as in drawing.
+	pub(super) fn commit_vote_self_selected_helper(
+		key: SumTreeNameType<T>,
+		phase_data: PhaseDataOf<T>,
+		who: AccountIdOf<T>,
+		vote_commit: [u8; 32],
+		vrf_signature: VrfSignature,
+	) -> DispatchResult {
+		match <PeriodName<T>>::get(&key) {
+			Some(period) => {
+				ensure!(period == Period::Commit, Error::<T>::PeriodDontMatch);
+			},
+			None => Err(Error::<T>::PeriodDoesNotExists)?,
+		}
+		ensure!(<SelfSelectionEnabled<T>>::get(&key), Error::<T>::SelfSelectionNotEnabled);
+		let (seed, total_stake) =
+			<SelfSelectionSeed<T>>::get(&key).ok_or(Error::<T>::SelfSelectionNotEnabled)?;
+		let phase_data = Self::round_phase_data(&key, phase_data);
+		let draws_in_round = <DrawsInRound<T>>::get(&key);
+		ensure!(draws_in_round < phase_data.max_draws, Error::<T>::MaxDrawExceeded);
+
+		let mut drawn_jurors = <DrawnJurors<T>>::get(&key);
+		let index = match drawn_jurors.binary_search_by(|(c, _)| c.cmp(&who)) {
+			Ok(_) => Err(Error::<T>::SelectedAsJuror)?,
+			Err(index) => index,
+		};
+		ensure!(
+			<UnstakedJurors<T>>::get(&key).binary_search(&who).is_err(),
+			Error::<T>::AlreadyUnstaked
+		);
+		let stake = T::SortitionSumGameSource::stake_of_link(key.clone(), who.clone())?
+			.ok_or(Error::<T>::StakeDoesNotExists)?;
+		ensure!(stake > 0, Error::<T>::StakeDoesNotExists);
+
+		let vrf_key = <GameVrfKeys<T>>::get(&key, &who).ok_or(Error::<T>::VrfKeyNotSet)?;
+		let sign_data = Self::self_selection_sign_data(seed);
+		ensure!(vrf_key.vrf_verify(&sign_data, &vrf_signature), Error::<T>::InvalidVrfProof);
+		let output_hash = sp_io::hashing::blake2_256(&vrf_signature.pre_output.encode());
+		let output = u128::decode(&mut output_hash.as_ref())
+			.expect("secure hashes should always be bigger than u128; qed");
+		ensure!(
+			Self::within_self_selection_threshold(output, stake, total_stake, phase_data.max_draws),
+			Error::<T>::JurorNotSelected
+		);
+
+		let stake = Self::serving_stake(&key, &who, stake, &phase_data);
+		ensure!(stake > 0, Error::<T>::PoolStakeNotEnough);
+		drawn_jurors.insert(index, (who.clone(), stake));
+		<DrawnJurors<T>>::insert(&key, drawn_jurors);
+		<DrawsInRound<T>>::insert(&key, draws_in_round + 1);
+		T::SortitionSumGameSource::set_link(key.clone(), 0, who.clone())?;
+
+		Self::commit_vote_helper(key, who, vote_commit)
+	}
+}
@@ -13,6 +13,10 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	type PhaseData = PhaseDataOf<T>;
 	type WinningDecision = WinningDecision;
 	type JurorGameResult = JurorGameResult;
+	type CancellationReason = CancellationReason;
+	type RubricDimension = RubricDimension;
+	type VrfPublic = sr25519::Public;
+	type VrfSignature = sr25519::vrf::VrfSignature;
 
 	fn create_phase_data(
 		block_length: u64,
@@ -30,32 +34,8 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 		)
 	}
 
-	fn create_phase_with_all_data(
-		evidence_length: u64,
-		end_of_staking_time: u64,
-		staking_length: u64,
-		drawing_length: u64,
-		commit_length: u64,
-		vote_length: u64,
-		appeal_length: u64,
-		max_draws: u64,
-		min_number_juror_staked: u64,
-		min_juror_stake: u64,
-		juror_incentives: (u64, u64),
-	) -> Self::PhaseData {
-		Self::create_phase_with_all_data(
-			evidence_length,
-			end_of_staking_time,
-			staking_length,
-			drawing_length,
-			commit_length,
-			vote_length,
-			appeal_length,
-			max_draws,
-			min_number_juror_staked,
-			min_juror_stake,
-			juror_incentives,
-		)
+	fn create_phase_with_all_data(params: PhaseParams) -> Self::PhaseData {
+		Self::create_phase_with_all_data(params)
 	}
 
 	/// Get the Period
@@ -73,6 +53,18 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 		Self::set_to_evidence_period(key, now)
 	}
 
+	/// Create the game in a court, the court parameters override the phase data
+	/// Called in evidence period
+	fn set_game_court_link(key: Self::SumTreeName, court_id: u64) -> DispatchResult {
+		Self::set_game_court_helper(key, court_id)
+	}
+
+	/// Draw the jurors of the game from the juror pool of the department
+	/// Called before the drawing period, jurors don't stake in the game
+	fn use_juror_pool_link(key: Self::SumTreeName, department_id: u64) -> DispatchResult {
+		Self::use_juror_pool_helper(key, department_id)
+	}
+
 	/// Create a sortition sum tree   
 	fn create_tree_helper_link(key: Self::SumTreeName, k: u64) -> DispatchResult {
 		Self::create_tree_link_helper(key, k)
@@ -188,6 +180,17 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 		Self::unstaking_helper(key, who)
 	}
 
+	fn set_stake_weight_link() -> Weight {
+		T::SortitionSumGameSource::set_weight_link()
+	}
+
+	/// Pool jurors that can't serve are restored, a set for each
+	fn draw_jurors_weight_link(iterations: u64) -> Weight {
+		T::SortitionSumGameSource::draw_many_weight_link(iterations).saturating_add(
+			T::SortitionSumGameSource::set_weight_link().saturating_mul(iterations),
+		)
+	}
+
 	/// Commit vote   
 	fn commit_vote_helper_link(
 		key: Self::SumTreeName,
@@ -300,10 +303,134 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	}
 
 	/// Get new mean in score schelling game
-	fn get_mean_value_link(key: Self::SumTreeName) -> Result<i64, DispatchError> {
+	fn get_mean_value_link(key: Self::SumTreeName) -> Result<FixedI64, DispatchError> {
 		Self::get_mean_value(key)
 	}
 
+	/// Set the rubric of a rubric schelling game before the commit period
+	fn set_rubric_link(key: Self::SumTreeName, rubric: Vec<RubricDimension>) -> DispatchResult {
+		Self::set_rubric_helper(key, rubric)
+	}
+
+	/// Get the rubric, `None` if the game is not a rubric schelling game
+	fn get_rubric_link(key: Self::SumTreeName) -> Option<Vec<RubricDimension>> {
+		Self::game_rubric(key)
+	}
+
+	/// Commit vote for rubric schelling game
+	fn commit_vote_for_rubric_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult {
+		Self::commit_vote_for_rubric_helper(key, who, vote_commit)
+	}
+
+	/// Reveal vote for rubric schelling game, a score for every dimension
+	fn reveal_vote_rubric_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choices: Vec<i64>,
+		salt: Vec<u8>,
+	) -> DispatchResult {
+		Self::reveal_vote_rubric_helper(key, who, choices, salt)
+	}
+
+	/// Aggregate every dimension and set the weighted composite as the mean value
+	fn set_new_rubric_mean_value_link(key: Self::SumTreeName) -> DispatchResult {
+		Self::set_new_rubric_mean_value(key)
+	}
+
+	/// Distribute incentives to all rubric schelling game jurors
+	fn get_incentives_rubric_schelling_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		range_point: Self::RangePoint,
+	) -> DispatchResult {
+		Self::get_incentives_rubric_schelling_helper(key, phase_data, range_point)
+	}
+
+	/// Get the mean of every dimension in rubric schelling game
+	fn get_rubric_dimension_means_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<FixedI64>, DispatchError> {
+		Self::get_rubric_dimension_means(key)
+	}
+
+	/// Stakers select themselves with a VRF instead of being drawn
+	fn enable_self_selection_link(key: Self::SumTreeName) -> DispatchResult {
+		Self::enable_self_selection_helper(key)
+	}
+
+	/// Set the VRF key of a juror
+	fn set_juror_vrf_key_link(who: Self::AccountId, vrf_key: Self::VrfPublic) -> DispatchResult {
+		Self::set_juror_vrf_key_helper(who, vrf_key)
+	}
+
+	/// Commit vote with the VRF proof that the juror is selected
+	fn commit_vote_self_selected_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+		vrf_signature: Self::VrfSignature,
+	) -> DispatchResult {
+		Self::commit_vote_self_selected_helper(key, phase_data, who, vote_commit, vrf_signature)
+	}
+
+	/// Set the candidates of a pairwise comparison game before the commit period
+	fn set_pairwise_game_link(
+		key: Self::SumTreeName,
+		candidates: Vec<u64>,
+		pairs_per_juror: u32,
+	) -> DispatchResult {
+		Self::set_pairwise_game_helper(key, candidates, pairs_per_juror)
+	}
+
+	/// Pairs assigned to a juror, assigned when commit period starts
+	fn get_juror_pairs_link(key: Self::SumTreeName, who: Self::AccountId) -> Vec<(u64, u64)> {
+		Self::get_juror_pairs(key, who)
+	}
+
+	/// Commit vote of pairwise schelling game
+	fn commit_vote_for_pairwise_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult {
+		Self::commit_vote_for_pairwise_helper(key, who, vote_commit)
+	}
+
+	/// Reveal a choice (0 or 1) for every assigned pair
+	fn reveal_vote_pairwise_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choices: Vec<u8>,
+		salt: Vec<u8>,
+	) -> DispatchResult {
+		Self::reveal_vote_pairwise_helper(key, who, choices, salt)
+	}
+
+	/// Compute the Bradley-Terry ranking of the candidates
+	fn set_pairwise_ranking_link(key: Self::SumTreeName) -> DispatchResult {
+		Self::set_pairwise_ranking_helper(key)
+	}
+
+	/// Distribute incentives to all jurors of pairwise schelling game
+	fn get_incentives_pairwise_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+	) -> DispatchResult {
+		Self::get_incentives_pairwise_helper(key, phase_data)
+	}
+
+	/// Candidates with their strength, ordered from the strongest
+	fn get_pairwise_ranking_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<(u64, FixedU128)>, DispatchError> {
+		Self::get_pairwise_ranking(key)
+	}
+
 	/// Distribute incentives to all two choice shelling game jurors
 	fn get_all_incentives_two_choice_helper(
 		key: Self::SumTreeName,
@@ -344,4 +471,49 @@ impl<T: Config> SchellingGameSharedLink for Pallet<T> {
 	fn add_to_incentives_count(key: Self::SumTreeName, who: Self::AccountId) -> DispatchResult {
 		Self::add_to_incentives_count(key, who)
 	}
+
+	/// Cancel the game and refund all staked jurors
+	fn cancel_game_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		now: Self::BlockNumber,
+		reason: Self::CancellationReason,
+	) -> DispatchResult {
+		Self::cancel_game_helper(key, phase_data, now, reason)
+	}
+
+	/// Contribute to the appeal fee of a side (0 or 1) in `Period::Appeal`
+	/// A new jury round opens when the side is fully funded
+	fn fund_appeal_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		choice: u128,
+		amount: Self::Balance,
+		now: Self::BlockNumber,
+	) -> DispatchResult {
+		Self::fund_appeal_helper(key, phase_data, who, choice, amount, now)
+	}
+
+	/// Settle the appeal contribution of a round in `Period::Execution`
+	fn withdraw_appeal_contribution_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		round: u32,
+	) -> DispatchResult {
+		Self::withdraw_appeal_contribution_helper(key, who, round)
+	}
+
+	/// Add funds forfeited in the calling pallet to the juror reward pool
+	fn deposit_into_reward_pool_link(amount: Self::Balance) {
+		Self::deposit_into_reward_pool(amount)
+	}
+
+	/// Pay a reward of the calling pallet from the juror reward pool, returns the amount paid
+	fn pay_from_reward_pool_link(
+		who: Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::pay_from_reward_pool(&who, amount)
+	}
 }
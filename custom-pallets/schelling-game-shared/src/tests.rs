@@ -1,11 +1,17 @@
 use crate::{
     mock::*,
-    types::{JurorGameResult, Period, PhaseData, RangePoint, SchellingGameType},
-    Error,
+    types::{
+        CancellationReason, CourtParams, JurorGameResult, Period, PhaseData, PoolStake, RangePoint,
+        RubricDimension, SchellingGameType, WinningDecision,
+    },
+    AppealRound, DecisionCount, Error, Event, PeriodName, RewardPoolBudget,
 };
 use frame_support::{assert_noop, assert_ok};
 
 use pallet_sortition_sum_game::types::SumTreeName;
+use sp_arithmetic::{FixedI64, FixedU128};
+use sp_core::crypto::{Pair as _, VrfSecret};
+use sp_core::sr25519;
 
 type CitizenId = u64;
 
@@ -54,6 +60,10 @@ fn evidence_period_test() {
         assert_eq!(TemplateModule::get_period(&key).unwrap(), Period::Evidence);
         let phase_data = get_the_phase_data();
         let now2 = now + phase_data.evidence_length;
+        assert_noop!(
+            TemplateModule::change_period(key.clone(), phase_data.clone(), now2),
+            Error::<Test>::PeriodDontMatch
+        );
         assert_ok!(TemplateModule::set_to_staking_period(
             key.clone(),
             phase_data,
@@ -192,7 +202,7 @@ fn challenger_win_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -206,15 +216,15 @@ fn challenger_win_test() {
         let balance = Balances::free_balance(5);
         assert_eq!(300000, balance);
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 17, hash));
         let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 23, hash));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 25, hash));
         let hash = sp_io::hashing::keccak_256("0salt5".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 15, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 29, hash));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
         let new_now = commit_start_time + phase_data.commit_length;
         assert_ok!(TemplateModule::change_period(
@@ -226,31 +236,31 @@ fn challenger_win_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            13,
+            23,
             1,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            15,
+            29,
             0,
             "salt5".as_bytes().to_vec()
         ));
@@ -264,54 +274,66 @@ fn challenger_win_test() {
             new_now.clone()
         ));
         let period = TemplateModule::get_period(key.clone());
+        assert_eq!(Some(Period::Appeal), period);
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
+        assert_noop!(
+            TemplateModule::change_period(key.clone(), phase_data.clone(), new_now + 1),
+            Error::<Test>::GameAlreadyInExecution
+        );
 
-        let balance = Balances::free_balance(4);
-        assert_eq!(299600, balance);
+        let balance = Balances::free_balance(13);
+        assert_eq!(298700, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            4
+            13
         ));
-        let balance = Balances::free_balance(4);
+        let balance = Balances::free_balance(13);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         // println!("{:?}", balance);
-        assert_eq!(299300, balance);
+        assert_eq!(298300, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            7
+            17
         ));
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(13);
-        assert_eq!(298700, balance);
+        let balance = Balances::free_balance(23);
+        assert_eq!(297700, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            13
+            23
         ));
-        let balance = Balances::free_balance(13);
+        let balance = Balances::free_balance(23);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(14);
-        assert_eq!(298600, balance);
+        let balance = Balances::free_balance(25);
+        assert_eq!(297500, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            14
+            25
         ));
-        let balance = Balances::free_balance(14);
+        let balance = Balances::free_balance(25);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(297100, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            15
+            29
         ));
-        let balance = Balances::free_balance(15);
-        assert_eq!(299625, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(299275, balance);
     });
 }
 
@@ -362,7 +384,7 @@ fn challenger_win_value_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -376,15 +398,15 @@ fn challenger_win_value_test() {
         let balance = Balances::free_balance(5);
         assert_eq!(300000, balance);
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 17, hash));
         let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 23, hash));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 25, hash));
         let hash = sp_io::hashing::keccak_256("0salt5".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 15, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 29, hash));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
         let new_now = commit_start_time + phase_data.commit_length;
         assert_ok!(TemplateModule::change_period(
@@ -396,31 +418,31 @@ fn challenger_win_value_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            13,
+            23,
             1,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            15,
+            29,
             0,
             "salt5".as_bytes().to_vec()
         ));
@@ -434,20 +456,28 @@ fn challenger_win_value_test() {
             new_now.clone()
         ));
         let period = TemplateModule::get_period(key.clone());
+        assert_eq!(Some(Period::Appeal), period);
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
-        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 4);
+        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 13);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Won);
-        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 7);
+        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 17);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Won);
-        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 13);
+        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 23);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Won);
-        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 14);
+        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 25);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Won);
-        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 15);
+        let result_stake = TemplateModule::get_result_of_juror(key.clone(), 29);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Lost);
     });
@@ -505,7 +535,7 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -519,15 +549,15 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
         let balance = Balances::free_balance(5);
         assert_eq!(300000, balance);
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 17, hash));
         let hash = sp_io::hashing::keccak_256("1salt3".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 23, hash));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 25, hash));
         let hash = sp_io::hashing::keccak_256("0salt5".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 15, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 29, hash));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
         let new_now = commit_start_time + phase_data.commit_length;
         assert_ok!(TemplateModule::change_period(
@@ -539,31 +569,31 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            13,
+            23,
             1,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            15,
+            29,
             0,
             "salt5".as_bytes().to_vec()
         ));
@@ -577,32 +607,40 @@ fn challenger_win_test_jurors_incentive_in_one_go() {
             new_now.clone()
         ));
         let period = TemplateModule::get_period(key.clone());
+        assert_eq!(Some(Period::Appeal), period);
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
-        let balance = Balances::free_balance(4);
-        assert_eq!(299600, balance);
-        let balance = Balances::free_balance(7);
-        // println!("{:?}", balance);
-        assert_eq!(299300, balance);
         let balance = Balances::free_balance(13);
         assert_eq!(298700, balance);
-        let balance = Balances::free_balance(14);
-        assert_eq!(298600, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(17);
+        // println!("{:?}", balance);
+        assert_eq!(298300, balance);
+        let balance = Balances::free_balance(23);
+        assert_eq!(297700, balance);
+        let balance = Balances::free_balance(25);
+        assert_eq!(297500, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(297100, balance);
         assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone()
         ));
-        let balance = Balances::free_balance(4);
+        let balance = Balances::free_balance(13);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(13);
+        let balance = Balances::free_balance(23);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(14);
+        let balance = Balances::free_balance(25);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(299625, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(299275, balance);
     });
 }
 
@@ -656,7 +694,7 @@ fn challenger_lost_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -670,15 +708,15 @@ fn challenger_lost_test() {
         let balance = Balances::free_balance(5);
         assert_eq!(300000, balance);
         let hash = sp_io::hashing::keccak_256("0salt".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 4, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
         let hash = sp_io::hashing::keccak_256("0salt2".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 7, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 17, hash));
         let hash = sp_io::hashing::keccak_256("0salt3".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 13, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 23, hash));
         let hash = sp_io::hashing::keccak_256("0salt4".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 14, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 25, hash));
         let hash = sp_io::hashing::keccak_256("1salt5".as_bytes());
-        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 15, hash));
+        assert_ok!(TemplateModule::commit_vote_helper(key.clone(), 29, hash));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
         let new_now = commit_start_time + phase_data.commit_length;
         assert_ok!(TemplateModule::change_period(
@@ -690,31 +728,31 @@ fn challenger_lost_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            4,
+            13,
             0,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            7,
+            17,
             0,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            13,
+            23,
             0,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            14,
+            25,
             0,
             "salt4".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
             key.clone(),
-            15,
+            29,
             1,
             "salt5".as_bytes().to_vec()
         ));
@@ -728,54 +766,62 @@ fn challenger_lost_test() {
             new_now.clone()
         ));
         let period = TemplateModule::get_period(key.clone());
+        assert_eq!(Some(Period::Appeal), period);
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
 
-        let balance = Balances::free_balance(4);
-        assert_eq!(299600, balance);
+        let balance = Balances::free_balance(13);
+        assert_eq!(298700, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            4
+            13
         ));
-        let balance = Balances::free_balance(4);
+        let balance = Balances::free_balance(13);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         // println!("{:?}", balance);
-        assert_eq!(299300, balance);
+        assert_eq!(298300, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            7
+            17
         ));
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(13);
-        assert_eq!(298700, balance);
+        let balance = Balances::free_balance(23);
+        assert_eq!(297700, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            13
+            23
         ));
-        let balance = Balances::free_balance(13);
+        let balance = Balances::free_balance(23);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(14);
-        assert_eq!(298600, balance);
+        let balance = Balances::free_balance(25);
+        assert_eq!(297500, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            14
+            25
         ));
-        let balance = Balances::free_balance(14);
+        let balance = Balances::free_balance(25);
         assert_eq!(300025, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(297100, balance);
         assert_ok!(TemplateModule::get_incentives_two_choice_helper(
             key.clone(),
             phase_data.clone(),
-            15
+            29
         ));
-        let balance = Balances::free_balance(15);
-        assert_eq!(299625, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(299275, balance);
     });
 }
 
@@ -829,7 +875,7 @@ fn score_schelling_game_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -845,31 +891,31 @@ fn score_schelling_game_test() {
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            4,
+            13,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            7,
+            17,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("5salt3".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            13,
+            23,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            14,
+            25,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("7salt5".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            15,
+            29,
             hash
         ));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
@@ -883,32 +929,32 @@ fn score_schelling_game_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            13,
+            23,
             5,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_noop!(
             TemplateModule::reveal_vote_score_helper(
                 key.clone(),
-                15,
+                29,
                 8,
                 "salt5".as_bytes().to_vec()
             ),
@@ -916,7 +962,7 @@ fn score_schelling_game_test() {
         );
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            15,
+            29,
             7,
             "salt5".as_bytes().to_vec()
         ));
@@ -930,36 +976,45 @@ fn score_schelling_game_test() {
         let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
         let reveal_score = TemplateModule::reveal_score_values(key.clone());
-        assert_eq!(vec![1000, 1000, 5000, 1000, 7000], reveal_score);
-        let balance = Balances::free_balance(4);
-        assert_eq!(299600, balance);
-        let balance = Balances::free_balance(7);
-        // println!("{:?}", balance);
-        assert_eq!(299300, balance);
+        assert_eq!(
+            vec![
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(5),
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(7)
+            ],
+            reveal_score
+        );
         let balance = Balances::free_balance(13);
         assert_eq!(298700, balance);
-        let balance = Balances::free_balance(14);
-        assert_eq!(298600, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(17);
+        // println!("{:?}", balance);
+        assert_eq!(298300, balance);
+        let balance = Balances::free_balance(23);
+        assert_eq!(297700, balance);
+        let balance = Balances::free_balance(25);
+        assert_eq!(297500, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(297100, balance);
         assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
             key.clone(),
             phase_data.clone(),
             RangePoint::ZeroToTen
         ));
         let mean_values = TemplateModule::new_mean_reveal_score(key.clone());
-        assert_eq!(2000, mean_values.unwrap());
-        let balance = Balances::free_balance(4);
+        assert_eq!(FixedI64::from_u32(2), mean_values.unwrap());
+        let balance = Balances::free_balance(13);
         // println!("{:?}", balance);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(13); // Balance deducted as voted 5
-        assert_eq!(299675, balance);
-        let balance = Balances::free_balance(14);
+        let balance = Balances::free_balance(23); // Balance deducted as voted 5
+        assert_eq!(299425, balance);
+        let balance = Balances::free_balance(25);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(15); // Balance deducted as voted 7
-        assert_eq!(299625, balance);
+        let balance = Balances::free_balance(29); // Balance deducted as voted 7
+        assert_eq!(299275, balance);
     });
 }
 
@@ -1013,7 +1068,7 @@ fn score_schelling_game_not_reveal_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -1029,31 +1084,31 @@ fn score_schelling_game_not_reveal_test() {
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            4,
+            13,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            7,
+            17,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("5salt3".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            13,
+            23,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            14,
+            25,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("7salt5".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            15,
+            29,
             hash
         ));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
@@ -1067,32 +1122,32 @@ fn score_schelling_game_not_reveal_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            13,
+            23,
             5,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_noop!(
             TemplateModule::reveal_vote_score_helper(
                 key.clone(),
-                15,
+                29,
                 8,
                 "salt5".as_bytes().to_vec()
             ),
@@ -1114,36 +1169,44 @@ fn score_schelling_game_not_reveal_test() {
         let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
         let reveal_score = TemplateModule::reveal_score_values(key.clone());
-        assert_eq!(vec![1000, 1000, 5000, 1000], reveal_score);
-        let balance = Balances::free_balance(4);
-        assert_eq!(299600, balance);
-        let balance = Balances::free_balance(7);
-        // println!("{:?}", balance);
-        assert_eq!(299300, balance);
+        assert_eq!(
+            vec![
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(5),
+                FixedI64::from_u32(1)
+            ],
+            reveal_score
+        );
         let balance = Balances::free_balance(13);
         assert_eq!(298700, balance);
-        let balance = Balances::free_balance(14);
-        assert_eq!(298600, balance);
-        let balance = Balances::free_balance(15);
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(17);
+        // println!("{:?}", balance);
+        assert_eq!(298300, balance);
+        let balance = Balances::free_balance(23);
+        assert_eq!(297700, balance);
+        let balance = Balances::free_balance(25);
+        assert_eq!(297500, balance);
+        let balance = Balances::free_balance(29);
+        assert_eq!(297100, balance);
         assert_ok!(TemplateModule::get_incentives_score_schelling_helper(
             key.clone(),
             phase_data.clone(),
             RangePoint::ZeroToTen
         ));
         let mean_values = TemplateModule::new_mean_reveal_score(key.clone());
-        assert_eq!(1000, mean_values.unwrap());
-        let balance = Balances::free_balance(4);
+        assert_eq!(FixedI64::from_u32(1), mean_values.unwrap());
+        let balance = Balances::free_balance(13);
         // println!("{:?}", balance);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(7);
+        let balance = Balances::free_balance(17);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(13); // Balance deducted as voted 5
-        assert_eq!(299675, balance);
-        let balance = Balances::free_balance(14);
+        let balance = Balances::free_balance(23); // Balance deducted as voted 5
+        assert_eq!(299425, balance);
+        let balance = Balances::free_balance(25);
         assert_eq!(300033, balance);
-        let balance = Balances::free_balance(15); // Balance deducted as voted 7
-        assert_eq!(298500, balance);
+        let balance = Balances::free_balance(29); // Balance deducted as voted 7
+        assert_eq!(297100, balance);
     });
 }
 
@@ -1197,7 +1260,7 @@ fn score_schelling_game_value_test() {
         assert_eq!(5, draws_in_round);
         let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
         assert_eq!(
-            vec![(4, 400), (7, 700), (13, 1300), (14, 1400), (15, 1500)],
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
             drawn_jurors
         );
         assert_ok!(TemplateModule::change_period(
@@ -1213,31 +1276,31 @@ fn score_schelling_game_value_test() {
         let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            4,
+            13,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt2".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            7,
+            17,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("5salt3".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            13,
+            23,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("1salt4".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            14,
+            25,
             hash
         ));
         let hash = sp_io::hashing::keccak_256("7salt5".as_bytes());
         assert_ok!(TemplateModule::commit_vote_for_score_helper(
             key.clone(),
-            15,
+            29,
             hash
         ));
         let commit_start_time = TemplateModule::commit_start_time(key.clone());
@@ -1251,32 +1314,32 @@ fn score_schelling_game_value_test() {
         assert_eq!(Some(Period::Vote), period);
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            4,
+            13,
             1,
             "salt".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            7,
+            17,
             1,
             "salt2".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            13,
+            23,
             5,
             "salt3".as_bytes().to_vec()
         ));
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            14,
+            25,
             1,
             "salt4".as_bytes().to_vec()
         ));
         assert_noop!(
             TemplateModule::reveal_vote_score_helper(
                 key.clone(),
-                15,
+                29,
                 8,
                 "salt5".as_bytes().to_vec()
             ),
@@ -1284,7 +1347,7 @@ fn score_schelling_game_value_test() {
         );
         assert_ok!(TemplateModule::reveal_vote_score_helper(
             key.clone(),
-            15,
+            29,
             7,
             "salt5".as_bytes().to_vec()
         ));
@@ -1298,33 +1361,1231 @@ fn score_schelling_game_value_test() {
         let period = TemplateModule::get_period(key.clone());
         assert_eq!(Some(Period::Execution), period);
         let reveal_score = TemplateModule::reveal_score_values(key.clone());
-        assert_eq!(vec![1000, 1000, 5000, 1000, 7000], reveal_score);
+        assert_eq!(
+            vec![
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(5),
+                FixedI64::from_u32(1),
+                FixedI64::from_u32(7)
+            ],
+            reveal_score
+        );
         assert_ok!(TemplateModule::set_new_mean_value(key.clone()));
         let mean_values = TemplateModule::new_mean_reveal_score(key.clone());
-        assert_eq!(2000, mean_values.unwrap());
+        assert_eq!(FixedI64::from_u32(2), mean_values.unwrap());
         let result_stake =
-            TemplateModule::get_result_of_juror_score(key.clone(), 4, RangePoint::ZeroToTen);
+            TemplateModule::get_result_of_juror_score(key.clone(), 13, RangePoint::ZeroToTen);
         let (result, _) = result_stake.unwrap();
         assert_eq!(result, JurorGameResult::Won);
         let result_stake =
-            TemplateModule::get_result_of_juror_score(key.clone(), 7, RangePoint::ZeroToTen);
+            TemplateModule::get_result_of_juror_score(key.clone(), 17, RangePoint::ZeroToTen);
         let (result, _) = result_stake.unwrap();
 
         assert_eq!(result, JurorGameResult::Won);
         let result_stake =
-            TemplateModule::get_result_of_juror_score(key.clone(), 13, RangePoint::ZeroToTen);
+            TemplateModule::get_result_of_juror_score(key.clone(), 23, RangePoint::ZeroToTen);
         let (result, _) = result_stake.unwrap();
 
         assert_eq!(result, JurorGameResult::Lost);
         let result_stake =
-            TemplateModule::get_result_of_juror_score(key.clone(), 14, RangePoint::ZeroToTen);
+            TemplateModule::get_result_of_juror_score(key.clone(), 25, RangePoint::ZeroToTen);
         let (result, _) = result_stake.unwrap();
 
         assert_eq!(result, JurorGameResult::Won);
         let result_stake =
-            TemplateModule::get_result_of_juror_score(key.clone(), 15, RangePoint::ZeroToTen);
+            TemplateModule::get_result_of_juror_score(key.clone(), 29, RangePoint::ZeroToTen);
         let (result, _) = result_stake.unwrap();
 
         assert_eq!(result, JurorGameResult::Lost);
     });
 }
+
+#[test]
+fn score_fixed_point_rounding() {
+    new_test_ext().execute_with(|| {
+        let scores = vec![FixedI64::from_u32(1), FixedI64::from_u32(2), FixedI64::from_u32(2)];
+        // 5/3 rounds towards zero
+        assert_eq!(
+            TemplateModule::mean_fixed(&scores).unwrap(),
+            FixedI64::from_inner(1_666_666_666)
+        );
+        let scores = vec![FixedI64::from_u32(0), FixedI64::from_u32(1)];
+        let (sd, mean) = TemplateModule::std_deviation_fixed(&scores).unwrap();
+        assert_eq!(mean, FixedI64::from_rational(1, 2));
+        assert_eq!(sd, FixedU128::from_rational(1, 2));
+        assert_eq!(
+            TemplateModule::calculate_new_mean(&scores, (sd, mean)).unwrap(),
+            FixedI64::from_rational(1, 2)
+        );
+        let scores = vec![FixedI64::from_inner(-9), FixedI64::from_u32(0)];
+        assert_eq!(TemplateModule::mean_fixed(&scores).unwrap(), FixedI64::from_inner(-4));
+        assert_noop!(TemplateModule::mean_fixed(&vec![]), Error::<Test>::NoRevealedScores);
+        assert_noop!(
+            TemplateModule::score_to_fixed(i64::MAX),
+            Error::<Test>::ScoreOverflow
+        );
+    });
+}
+
+#[test]
+fn reward_pool_pays_from_budget() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(TemplateModule::stake_into_reward_pool(&4, 400));
+        assert_eq!(Balances::free_balance(4), 299600);
+        // Epoch inflation is minted on the first payout, the forfeited quarter goes to the budget
+        assert_ok!(TemplateModule::pay_juror_from_pool(&4, 400, 300, 0));
+        assert_eq!(Balances::free_balance(4), 299900);
+        assert_eq!(TemplateModule::reward_pool_budget(), 1_000_100);
+        <RewardPoolBudget<Test>>::put(50);
+        assert_ok!(TemplateModule::stake_into_reward_pool(&7, 700));
+        assert_ok!(TemplateModule::pay_juror_from_pool(&7, 700, 700, 100));
+        assert_eq!(Balances::free_balance(7), 300050);
+        assert_eq!(TemplateModule::reward_pool_budget(), 0);
+        System::assert_has_event(
+            Event::RewardPoolInsufficient { who: 7, requested: 100, paid: 50 }.into(),
+        );
+
+        // Inflation of the skipped epochs 1 and 2 is minted with the one of epoch 3
+        System::set_block_number(3500);
+        assert_ok!(TemplateModule::pay_juror_from_pool(&7, 0, 0, 0));
+        assert_eq!(TemplateModule::reward_pool_budget(), 3_000_000);
+        System::assert_has_event(Event::RewardPoolFunded { epoch: 3, amount: 3_000_000 }.into());
+    });
+}
+
+#[test]
+fn abstain_vote_test() {
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..30 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+        assert_eq!(
+            vec![(13, 1300), (17, 1700), (23, 2300), (25, 2500), (29, 2900)],
+            drawn_jurors
+        );
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let votes = [(13, "2salt"), (17, "2salt2"), (23, "2salt3"), (25, "1salt4"), (29, "0salt5")];
+        for (juror, vote) in votes {
+            let hash = sp_io::hashing::keccak_256(vote.as_bytes());
+            assert_ok!(TemplateModule::commit_vote_helper(key.clone(), juror, hash));
+        }
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_noop!(
+            TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                13,
+                3,
+                "salt".as_bytes().to_vec()
+            ),
+            Error::<Test>::CommitDoesNotMatch
+        );
+        for (juror, vote) in votes {
+            let choice = vote[..1].parse::<u128>().unwrap();
+            assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                juror,
+                choice,
+                vote.as_bytes()[1..].to_vec()
+            ));
+        }
+        assert_eq!((1, 1), TemplateModule::decision_count(key.clone()));
+        assert_eq!(3, TemplateModule::abstain_count(key.clone()));
+        let vote_start_time = TemplateModule::vote_start_time(key.clone());
+        let new_now = vote_start_time + phase_data.vote_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_eq!(
+            TemplateModule::get_winning_decision_value(key.clone()).unwrap(),
+            WinningDecision::Abstain
+        );
+        for (juror, result) in [
+            (13, JurorGameResult::Won),
+            (17, JurorGameResult::Won),
+            (23, JurorGameResult::Won),
+            (25, JurorGameResult::Lost),
+            (29, JurorGameResult::Lost),
+        ] {
+            let (juror_result, _) =
+                TemplateModule::get_result_of_juror(key.clone(), juror).unwrap();
+            assert_eq!(juror_result, result);
+        }
+
+        // Abstain doesn't win without a strict majority, abstain voters then draw
+        assert_eq!(TemplateModule::get_winning_decision((1, 2), 3), WinningDecision::WinnerYes);
+        assert_eq!(
+            TemplateModule::get_juror_game_result(
+                &WinningDecision::WinnerYes,
+                &crate::types::RevealedVote::Abstain
+            ),
+            JurorGameResult::Draw
+        );
+    });
+}
+
+#[test]
+fn appeal_crowdfunding_test() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..30 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let votes = [(13, "1salt"), (17, "1salt2"), (23, "1salt3"), (25, "1salt4"), (29, "0salt5")];
+        for (juror, vote) in votes {
+            let hash = sp_io::hashing::keccak_256(vote.as_bytes());
+            assert_ok!(TemplateModule::commit_vote_helper(key.clone(), juror, hash));
+        }
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        for (juror, vote) in votes {
+            let choice = vote[..1].parse::<u128>().unwrap();
+            assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                juror,
+                choice,
+                vote.as_bytes()[1..].to_vec()
+            ));
+        }
+        let vote_start_time = TemplateModule::vote_start_time(key.clone());
+        let new_now = vote_start_time + phase_data.vote_length;
+        assert_noop!(
+            TemplateModule::fund_appeal_helper(key.clone(), phase_data.clone(), 31, 0, 10, new_now),
+            Error::<Test>::PeriodDontMatch
+        );
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_eq!(Some(Period::Appeal), TemplateModule::get_period(key.clone()));
+        assert_noop!(
+            TemplateModule::change_period(key.clone(), phase_data.clone(), new_now),
+            Error::<Test>::AppealPeriodNotOver
+        );
+
+        // Next round has (5 + 1) * 2 - 1 = 11 jurors, each side has to raise 11 * 10
+        assert_noop!(
+            TemplateModule::fund_appeal_helper(key.clone(), phase_data.clone(), 30, 2, 10, new_now),
+            Error::<Test>::NotValidChoice
+        );
+        assert_ok!(TemplateModule::fund_appeal_helper(
+            key.clone(),
+            phase_data.clone(),
+            30,
+            0,
+            60,
+            new_now
+        ));
+        assert_ok!(TemplateModule::fund_appeal_helper(
+            key.clone(),
+            phase_data.clone(),
+            31,
+            1,
+            40,
+            new_now
+        ));
+        assert_noop!(
+            TemplateModule::fund_appeal_helper(key.clone(), phase_data.clone(), 32, 0, 60, new_now),
+            Error::<Test>::AppealAmountGreaterThanRequired
+        );
+        assert_eq!(TemplateModule::appeal_funds(key.clone(), 0), (60, 40));
+        assert_eq!(Balances::free_balance(30), 299940);
+
+        // Side 0 is fully funded, round jurors are paid and a new round opens
+        assert_ok!(TemplateModule::fund_appeal_helper(
+            key.clone(),
+            phase_data.clone(),
+            32,
+            0,
+            50,
+            new_now
+        ));
+        System::assert_last_event(Event::AppealRoundOpened { key: key.clone(), round: 1 }.into());
+        assert_eq!(TemplateModule::appeal_round(key.clone()), 1);
+        assert_eq!(Some(Period::Staking), TemplateModule::get_period(key.clone()));
+        assert_eq!(TemplateModule::staking_start_time(key.clone()), new_now);
+        assert_eq!(TemplateModule::drawn_jurors(key.clone()), vec![]);
+        assert_eq!(TemplateModule::decision_count(key.clone()), (0, 0));
+        assert_eq!(Balances::free_balance(13), 300025);
+        assert_eq!(Balances::free_balance(29), 299275);
+        let (max_draws, _, _) =
+            TemplateModule::get_drawing_period_end_helper(key.clone(), phase_data.clone());
+        assert_eq!(max_draws, 11);
+
+        // Final ruling is 0, the appeal fee of 110 paid the new round jurors,
+        // contributors of 0 share the contributions of 1
+        <DecisionCount<Test>>::insert(&key, (6, 5));
+        <PeriodName<Test>>::insert(&key, Period::Execution);
+        assert_eq!(<AppealRound<Test>>::get(&key), 1);
+        assert_ok!(TemplateModule::withdraw_appeal_contribution_helper(key.clone(), 30, 0));
+        assert_eq!(Balances::free_balance(30), 299940 + 60 * 40 / 110);
+        assert_ok!(TemplateModule::withdraw_appeal_contribution_helper(key.clone(), 32, 0));
+        assert_eq!(Balances::free_balance(32), 299950 + 50 * 40 / 110);
+        assert_ok!(TemplateModule::withdraw_appeal_contribution_helper(key.clone(), 31, 0));
+        assert_eq!(Balances::free_balance(31), 299960);
+        assert_noop!(
+            TemplateModule::withdraw_appeal_contribution_helper(key.clone(), 30, 0),
+            Error::<Test>::AppealContributionNotExists
+        );
+    });
+}
+
+#[test]
+fn cancel_appeal_round_without_enough_jurors() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..12 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+        for (juror, _) in drawn_jurors.iter() {
+            let hash = sp_io::hashing::keccak_256("1salt".as_bytes());
+            assert_ok!(TemplateModule::commit_vote_helper(key.clone(), *juror, hash));
+        }
+        let new_now = new_now + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        for (juror, _) in drawn_jurors.iter() {
+            assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                *juror,
+                1,
+                "salt".as_bytes().to_vec()
+            ));
+        }
+        let new_now = new_now + phase_data.vote_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::fund_appeal_helper(
+            key.clone(),
+            phase_data.clone(),
+            30,
+            0,
+            110,
+            new_now
+        ));
+        assert_eq!(TemplateModule::appeal_round(key.clone()), 1);
+
+        // 3 stakers are left for the 11 jurors of the round, more than `min_number_juror_staked`
+        let new_now = new_now + phase_data.staking_length;
+        assert_ok!(TemplateModule::cancel_game_helper(
+            key.clone(),
+            phase_data.clone(),
+            new_now,
+            CancellationReason::NotEnoughJurorsStaked
+        ));
+        assert_eq!(Some(Period::Cancelled), TemplateModule::get_period(key.clone()));
+    });
+}
+
+#[test]
+fn rubric_schelling_game_test() {
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        assert_noop!(
+            TemplateModule::set_rubric_helper(
+                key.clone(),
+                vec![RubricDimension { name: "reach".as_bytes().to_vec(), weight: 0 }]
+            ),
+            Error::<Test>::InvalidRubric
+        );
+        let rubric = vec![
+            RubricDimension { name: "reach".as_bytes().to_vec(), weight: 3 },
+            RubricDimension { name: "verifiability".as_bytes().to_vec(), weight: 1 },
+        ];
+        assert_ok!(TemplateModule::set_rubric_helper(key.clone(), rubric));
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..30 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let votes = [
+            (13, vec![2, 4], "2,4", "salt"),
+            (17, vec![2, 4], "2,4", "salt2"),
+            (23, vec![2, 4], "2,4", "salt3"),
+            (25, vec![2, 4], "2,4", "salt4"),
+            (29, vec![5, 1], "5,1", "salt5"),
+        ];
+        for (juror, _, scores, salt) in votes.iter() {
+            let hash = sp_io::hashing::keccak_256(format!("{}{}", scores, salt).as_bytes());
+            assert_ok!(TemplateModule::commit_vote_for_rubric_helper(key.clone(), *juror, hash));
+        }
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_noop!(
+            TemplateModule::reveal_vote_rubric_helper(
+                key.clone(),
+                13,
+                vec![2],
+                "salt".as_bytes().to_vec()
+            ),
+            Error::<Test>::RubricLengthMismatch
+        );
+        for (juror, choices, _, salt) in votes.iter() {
+            assert_ok!(TemplateModule::reveal_vote_rubric_helper(
+                key.clone(),
+                *juror,
+                choices.clone(),
+                salt.as_bytes().to_vec()
+            ));
+        }
+        let vote_start_time = TemplateModule::vote_start_time(key.clone());
+        let new_now = vote_start_time + phase_data.vote_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+
+        // Dimensions are aggregated separately: 29 is outside one standard deviation in both
+        assert_ok!(TemplateModule::get_incentives_rubric_schelling_helper(
+            key.clone(),
+            phase_data.clone(),
+            RangePoint::ZeroToFive
+        ));
+        assert_eq!(
+            TemplateModule::rubric_dimension_means(key.clone()),
+            Some(vec![FixedI64::from_u32(2), FixedI64::from_u32(4)])
+        );
+        // Composite is (3 * 2 + 1 * 4) / 4
+        assert_eq!(TemplateModule::get_mean_value(key.clone()), Ok(FixedI64::from_rational(5, 2)));
+        assert_eq!(Balances::free_balance(13), 300025);
+        assert_eq!(Balances::free_balance(25), 300025);
+        assert_eq!(Balances::free_balance(29), 299275);
+    });
+}
+
+#[test]
+fn pairwise_schelling_game_test() {
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        assert_noop!(
+            TemplateModule::set_pairwise_game_helper(key.clone(), vec![10, 10], 2),
+            Error::<Test>::InvalidPairwiseGame
+        );
+        assert_ok!(TemplateModule::set_pairwise_game_helper(key.clone(), vec![30, 10, 20], 2));
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..30 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+
+        // Larger candidate is better, juror 29 votes the opposite of every pair
+        let jurors = [(13, "salt"), (17, "salt2"), (23, "salt3"), (25, "salt4"), (29, "salt5")];
+        let mut votes = vec![];
+        for (juror, salt) in jurors.iter() {
+            let pairs = TemplateModule::juror_pairs(key.clone(), *juror);
+            assert_eq!(pairs.len(), 2);
+            let choices: Vec<u8> = pairs
+                .iter()
+                .map(|(first, second)| {
+                    assert!(first != second);
+                    let second_better = second > first;
+                    if second_better != (*juror == 29) {
+                        1
+                    } else {
+                        0
+                    }
+                })
+                .collect();
+            let choices_string = choices
+                .iter()
+                .map(|choice| format!("{}", choice))
+                .collect::<Vec<_>>()
+                .join(",");
+            let hash = sp_io::hashing::keccak_256(format!("{}{}", choices_string, salt).as_bytes());
+            assert_ok!(TemplateModule::commit_vote_for_pairwise_helper(key.clone(), *juror, hash));
+            votes.push((*juror, choices, *salt));
+        }
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_noop!(
+            TemplateModule::reveal_vote_pairwise_helper(
+                key.clone(),
+                13,
+                vec![0],
+                "salt".as_bytes().to_vec()
+            ),
+            Error::<Test>::PairsLengthMismatch
+        );
+        for (juror, choices, salt) in votes.iter() {
+            assert_ok!(TemplateModule::reveal_vote_pairwise_helper(
+                key.clone(),
+                *juror,
+                choices.clone(),
+                salt.as_bytes().to_vec()
+            ));
+        }
+        let vote_start_time = TemplateModule::vote_start_time(key.clone());
+        let new_now = vote_start_time + phase_data.vote_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_eq!(Some(Period::Execution), TemplateModule::get_period(key.clone()));
+
+        assert_ok!(TemplateModule::get_incentives_pairwise_helper(
+            key.clone(),
+            phase_data.clone()
+        ));
+        let ranking = TemplateModule::get_pairwise_ranking(key.clone()).unwrap();
+        let order: Vec<u64> = ranking.iter().map(|(candidate, _)| *candidate).collect();
+        assert_eq!(order, vec![30, 20, 10]);
+        assert!(ranking[0].1 > ranking[1].1 && ranking[1].1 > ranking[2].1);
+        assert_eq!(Balances::free_balance(13), 300025);
+        assert_eq!(Balances::free_balance(25), 300025);
+        assert_eq!(Balances::free_balance(29), 299275);
+    });
+}
+
+#[test]
+fn self_selection_test() {
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        assert_ok!(TemplateModule::enable_self_selection_helper(key.clone()));
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        let pair_of = |juror: u64| sr25519::Pair::from_seed(&[juror as u8; 32]);
+        // VRF key is registered before staking
+        assert_noop!(
+            TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 1000),
+            Error::<Test>::VrfKeyNotSet
+        );
+        for juror in 4..9 {
+            assert_ok!(TemplateModule::set_juror_vrf_key_helper(juror, pair_of(juror).public()));
+        }
+        // Equal stakes of five jurors with five draws, every juror is within the threshold
+        for j in 4..9 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                1000
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        // No drawing period, nobody is known as juror before commit
+        assert_eq!(Some(Period::Commit), TemplateModule::get_period(key.clone()));
+        assert_eq!(TemplateModule::drawn_jurors(key.clone()), vec![]);
+        let (seed, total_stake) = TemplateModule::self_selection_seed(key.clone()).unwrap();
+        assert_eq!(total_stake, 5000);
+        let sign_data = TemplateModule::self_selection_sign_data(seed);
+
+        let votes = [(4, "1salt"), (5, "1salt2"), (6, "1salt3"), (7, "1salt4"), (8, "0salt5")];
+        let hash = sp_io::hashing::keccak_256(votes[0].1.as_bytes());
+        // Key can't be replaced once the seed is known
+        assert_noop!(
+            TemplateModule::set_juror_vrf_key_helper(4, pair_of(5).public()),
+            Error::<Test>::VrfKeyLocked
+        );
+        assert_noop!(
+            TemplateModule::commit_vote_self_selected_helper(
+                key.clone(),
+                phase_data.clone(),
+                4,
+                hash,
+                pair_of(5).vrf_sign(&sign_data)
+            ),
+            Error::<Test>::InvalidVrfProof
+        );
+        for (juror, vote) in votes {
+            let hash = sp_io::hashing::keccak_256(vote.as_bytes());
+            assert_ok!(TemplateModule::commit_vote_self_selected_helper(
+                key.clone(),
+                phase_data.clone(),
+                juror,
+                hash,
+                pair_of(juror).vrf_sign(&sign_data)
+            ));
+        }
+        assert_eq!(
+            TemplateModule::drawn_jurors(key.clone()),
+            vec![(4, 1000), (5, 1000), (6, 1000), (7, 1000), (8, 1000)]
+        );
+        assert_eq!(TemplateModule::user_staked_value(key.clone(), 4), 0);
+
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        for (juror, vote) in votes {
+            let choice = vote[..1].parse::<u128>().unwrap();
+            assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                juror,
+                choice,
+                vote.as_bytes()[1..].to_vec()
+            ));
+        }
+        assert_eq!((1, 4), TemplateModule::decision_count(key.clone()));
+        <PeriodName<Test>>::insert(&key, Period::Execution);
+        assert_ok!(TemplateModule::set_juror_vrf_key_helper(4, pair_of(5).public()));
+
+        // Threshold is proportional to the stake
+        assert!(TemplateModule::within_self_selection_threshold(u128::MAX / 2, 1000, 5000, 5));
+        assert!(!TemplateModule::within_self_selection_threshold(u128::MAX / 2, 100, 5000, 5));
+        assert!(!TemplateModule::within_self_selection_threshold(0, 100, 0, 5));
+    });
+}
+
+#[test]
+fn court_tree_test() {
+    new_test_ext().execute_with(|| {
+        let general_params =
+            CourtParams { min_juror_stake: Some(200), max_draws: Some(3), ..Default::default() };
+        assert_noop!(
+            TemplateModule::create_court(
+                RuntimeOrigin::signed(1),
+                None,
+                "General".as_bytes().to_vec(),
+                general_params.clone()
+            ),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(TemplateModule::create_court(
+            RuntimeOrigin::root(),
+            None,
+            "General".as_bytes().to_vec(),
+            general_params.clone()
+        ));
+        assert_noop!(
+            TemplateModule::create_court(
+                RuntimeOrigin::root(),
+                None,
+                "General".as_bytes().to_vec(),
+                general_params
+            ),
+            Error::<Test>::GeneralCourtExists
+        );
+        assert_noop!(
+            TemplateModule::create_court(
+                RuntimeOrigin::root(),
+                Some(5),
+                "Education".as_bytes().to_vec(),
+                CourtParams::default()
+            ),
+            Error::<Test>::CourtDoesNotExists
+        );
+        assert_ok!(TemplateModule::create_court(
+            RuntimeOrigin::root(),
+            Some(0),
+            "Education".as_bytes().to_vec(),
+            CourtParams { commit_length: Some(20), ..Default::default() }
+        ));
+        assert_ok!(TemplateModule::create_court(
+            RuntimeOrigin::root(),
+            Some(1),
+            "Primary schools".as_bytes().to_vec(),
+            CourtParams { max_draws: Some(5), ..Default::default() }
+        ));
+
+        // Subcourt overrides max draws, inherits commit length and min stake
+        let params = TemplateModule::court_params(2);
+        assert_eq!(params.max_draws, Some(5));
+        assert_eq!(params.commit_length, Some(20));
+        assert_eq!(params.min_juror_stake, Some(200));
+        assert_eq!(params.vote_length, None);
+
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        assert_ok!(TemplateModule::set_game_court_helper(key.clone(), 2));
+        let phase_data = get_the_phase_data();
+        let court_phase_data = TemplateModule::court_phase_data(&key, phase_data.clone());
+        assert_eq!(court_phase_data.max_draws, 5);
+        assert_eq!(court_phase_data.commit_length, 20);
+        assert_eq!(court_phase_data.min_juror_stake, 200);
+        assert_eq!(court_phase_data.vote_length, phase_data.vote_length);
+
+        // Game keeps the parameters of its court when it was created
+        assert_ok!(TemplateModule::update_court_params(
+            RuntimeOrigin::root(),
+            2,
+            CourtParams { max_draws: Some(7), ..Default::default() }
+        ));
+        assert_eq!(TemplateModule::court_params(2).max_draws, Some(7));
+        assert_eq!(TemplateModule::court_phase_data(&key, phase_data.clone()).max_draws, 5);
+
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        assert_noop!(
+            TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 100),
+            Error::<Test>::JurorStakeLessThanMin
+        );
+        assert_noop!(
+            TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 400),
+            Error::<Test>::NotEligibleForCourt
+        );
+
+        // Stake in the General court makes the juror eligible for its descendants
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 0, 200));
+        assert_eq!(Balances::reserved_balance(4), 200);
+        assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 400));
+
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(5), 2, 150));
+        assert_noop!(
+            TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 5, 500),
+            Error::<Test>::NotEligibleForCourt
+        );
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(5), 2, 50));
+        assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 5, 500));
+
+        assert_noop!(
+            TemplateModule::unstake_from_court(RuntimeOrigin::signed(4), 0, 300),
+            Error::<Test>::CourtStakeNotEnough
+        );
+        // Court stake is locked while the juror serves in the game
+        assert_noop!(
+            TemplateModule::unstake_from_court(RuntimeOrigin::signed(4), 0, 1),
+            Error::<Test>::CourtStakeLocked
+        );
+        assert_noop!(
+            TemplateModule::unstake_from_court(RuntimeOrigin::signed(5), 2, 200),
+            Error::<Test>::CourtStakeLocked
+        );
+        // Stake in another court keeps the juror eligible
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(5), 1, 200));
+        assert_ok!(TemplateModule::unstake_from_court(RuntimeOrigin::signed(5), 2, 200));
+
+        <PeriodName<Test>>::insert(&key, Period::Execution);
+        assert_ok!(TemplateModule::unstake_from_court(RuntimeOrigin::signed(4), 0, 200));
+        assert_eq!(Balances::reserved_balance(4), 0);
+        assert_eq!(TemplateModule::court_stakes(0, 4), 0);
+        assert_eq!(TemplateModule::juror_court_games(4), vec![]);
+    });
+}
+
+#[test]
+fn court_seniority_test() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(TemplateModule::create_court(
+            RuntimeOrigin::root(),
+            None,
+            "General".as_bytes().to_vec(),
+            CourtParams {
+                min_juror_stake: Some(100),
+                seniority_weighting: Some((100, 20)),
+                ..Default::default()
+            }
+        ));
+        System::set_block_number(10);
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 0, 100));
+        System::set_block_number(60);
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(5), 0, 100));
+        assert_ok!(TemplateModule::stake_in_court(RuntimeOrigin::signed(4), 0, 100));
+        assert_eq!(TemplateModule::court_staked_since(0, 4), Some(10));
+
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        assert_ok!(TemplateModule::set_game_court_helper(key.clone(), 0));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+
+        // Court stakes are aged to the end of staking at block 110, whenever the jurors apply
+        assert_eq!(TemplateModule::juror_seniority(&key, &4, &phase_data), 100);
+        assert_eq!(TemplateModule::juror_seniority(&key, &5, &phase_data), 50);
+        assert_eq!(TemplateModule::juror_seniority(&key, &6, &phase_data), 0);
+        assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 5, 1000));
+        System::set_block_number(100);
+        assert_ok!(TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 4, 1000));
+
+        // 4 counts with its whole stake, 5 with 200 + 800 * 50 / 100
+        assert_eq!(TemplateModule::user_staked_value(key.clone(), 4), 1000);
+        assert_eq!(TemplateModule::user_staked_value(key.clone(), 5), 1000);
+        assert_eq!(
+            pallet_sortition_sum_game::SortitionSumTreeNodes::<Test>::get(&key, 0),
+            1600
+        );
+
+        // Unstaking from the court drops the seniority
+        <PeriodName<Test>>::insert(&key, Period::Execution);
+        assert_ok!(TemplateModule::unstake_from_court(RuntimeOrigin::signed(5), 0, 100));
+        assert_eq!(TemplateModule::court_staked_since(0, 5), None);
+    });
+}
+
+#[test]
+fn juror_pool_test() {
+    new_test_ext().execute_with(|| {
+        let department_id = 1;
+        for j in 4..9 {
+            assert_ok!(TemplateModule::join_juror_pool(
+                RuntimeOrigin::signed(j),
+                department_id,
+                1000
+            ));
+            assert_eq!(Balances::free_balance(j), 299000);
+        }
+        // Pool is full, jurors of the pool can still add stake
+        assert_noop!(
+            TemplateModule::join_juror_pool(RuntimeOrigin::signed(9), department_id, 1000),
+            Error::<Test>::JurorPoolFull
+        );
+        assert_eq!(TemplateModule::juror_pool_size(department_id), 5);
+
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        assert_ok!(TemplateModule::use_juror_pool_helper(key.clone(), department_id));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        assert_noop!(
+            TemplateModule::apply_jurors_helper(key.clone(), phase_data.clone(), 9, 900),
+            Error::<Test>::JurorPoolGame
+        );
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+
+        // Pool is snapshotted into the game tree, nobody staked in the game
+        assert_eq!(TemplateModule::user_staked_value(key.clone(), 4), 1000);
+        assert_ok!(TemplateModule::draw_jurors_helper(
+            key.clone(),
+            phase_data.clone(),
+            5
+        ));
+        // Drawn jurors serve with `min_juror_stake` locked in the pool
+        assert_eq!(
+            TemplateModule::drawn_jurors(key.clone()),
+            vec![(4, 100), (5, 100), (6, 100), (7, 100), (8, 100)]
+        );
+        assert_eq!(
+            TemplateModule::juror_pool_stake(department_id, 4),
+            PoolStake { total: 1000, locked: 100 }
+        );
+        assert_noop!(
+            TemplateModule::leave_juror_pool(RuntimeOrigin::signed(4), department_id, 1000),
+            Error::<Test>::PoolStakeNotEnough
+        );
+        assert_ok!(TemplateModule::leave_juror_pool(
+            RuntimeOrigin::signed(4),
+            department_id,
+            900
+        ));
+        assert_eq!(Balances::free_balance(4), 299900);
+
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let votes = [(4, "1salt"), (5, "1salt2"), (6, "1salt3"), (7, "1salt4"), (8, "0salt5")];
+        for (juror, vote) in votes {
+            let hash = sp_io::hashing::keccak_256(vote.as_bytes());
+            assert_ok!(TemplateModule::commit_vote_helper(key.clone(), juror, hash));
+        }
+        let commit_start_time = TemplateModule::commit_start_time(key.clone());
+        let new_now = commit_start_time + phase_data.commit_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        for (juror, vote) in votes {
+            let choice = vote[..1].parse::<u128>().unwrap();
+            assert_ok!(TemplateModule::reveal_vote_two_choice_helper(
+                key.clone(),
+                juror,
+                choice,
+                vote.as_bytes()[1..].to_vec()
+            ));
+        }
+        let vote_start_time = TemplateModule::vote_start_time(key.clone());
+        let new_now = vote_start_time + phase_data.vote_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        let new_now = new_now + phase_data.appeal_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+        assert_ok!(TemplateModule::get_all_incentives_two_choice_helper(
+            key.clone(),
+            phase_data.clone()
+        ));
+
+        // Stake is unlocked and stays in the pool, only the reward is paid
+        assert_eq!(Balances::free_balance(4), 299925);
+        assert_eq!(
+            TemplateModule::juror_pool_stake(department_id, 4),
+            PoolStake { total: 100, locked: 0 }
+        );
+        assert_eq!(Balances::free_balance(8), 299000);
+        assert_eq!(
+            TemplateModule::juror_pool_stake(department_id, 8),
+            PoolStake { total: 975, locked: 0 }
+        );
+    });
+}
+
+#[test]
+fn migrate_scores_to_fixed_point() {
+    use crate::migrations::v1::InnerMigrateV0ToV1;
+    use crate::{IncentiveMeanRevealScore, RevealScoreValues};
+    use codec::Encode;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        // Scores stored before the migration as integers scaled by 1000
+        frame_support::storage::unhashed::put_raw(
+            &RevealScoreValues::<Test>::hashed_key_for(key.clone()),
+            &vec![1000i64, 2500i64].encode(),
+        );
+        frame_support::storage::unhashed::put_raw(
+            &IncentiveMeanRevealScore::<Test>::hashed_key_for(key.clone()),
+            &1750i64.encode(),
+        );
+
+        InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            TemplateModule::reveal_score_values(key.clone()),
+            vec![FixedI64::from_u32(1), FixedI64::from_rational(5, 2)]
+        );
+        assert_eq!(
+            TemplateModule::new_mean_reveal_score(key),
+            Some(FixedI64::from_rational(7, 4))
+        );
+    });
+}
+
+#[test]
+fn draw_verifier_replays_draws() {
+    use codec::Encode;
+    use frame_support::traits::Randomness;
+    use pallet_sortition_sum_game::{
+        LeafStakes, NodeIndexesToIds, SortitionSumTreeMetas, SortitionSumTreeNodes,
+    };
+    use sortition_draw_verifier::{verify_with, DrawCall, DrawExport, ExportedTree, Mismatch};
+    use sp_core::{hexdisplay::HexDisplay, H256};
+    use sp_std::collections::btree_map::BTreeMap;
+
+    new_test_ext().execute_with(|| {
+        let key = return_key_profile(0);
+        let now = 10;
+        assert_ok!(TemplateModule::set_to_evidence_period(key.clone(), now));
+        let phase_data = get_the_phase_data();
+        let staking_start_time = now + phase_data.evidence_length;
+        assert_ok!(TemplateModule::set_to_staking_period(
+            key.clone(),
+            phase_data.clone(),
+            staking_start_time
+        ));
+        assert_ok!(TemplateModule::create_tree_link_helper(key.clone(), 3));
+        for j in 4..30 {
+            assert_ok!(TemplateModule::apply_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                j,
+                j * 100
+            ));
+        }
+        let new_now = staking_start_time + phase_data.staking_length;
+        assert_ok!(TemplateModule::change_period(
+            key.clone(),
+            phase_data.clone(),
+            new_now
+        ));
+
+        // Tree of the game before the draws, as exported from the chain
+        let account = |juror: u64| format!("0x{}", HexDisplay::from(&juror.encode()));
+        let meta = SortitionSumTreeMetas::<Test>::get(&key).unwrap();
+        let tree = ExportedTree {
+            k: meta.k,
+            nodes: (0..meta.nodes_length)
+                .map(|index| SortitionSumTreeNodes::<Test>::get(&key, index))
+                .collect(),
+            node_indexes_to_ids: NodeIndexesToIds::<Test>::iter_prefix(&key)
+                .map(|(index, juror)| (index, account(juror)))
+                .collect(),
+            leaf_stakes: LeafStakes::<Test>::iter_prefix(&key)
+                .map(|(juror, leaf)| (account(juror), leaf.stake))
+                .collect(),
+        };
+
+        let mut calls = vec![];
+        for iterations in [3, 2] {
+            calls.push(DrawCall {
+                block_number: System::block_number() as u32,
+                random_material: vec![],
+                nonce: crate::Nonce::<Test>::get(),
+                iterations,
+                pool_available: BTreeMap::new(),
+            });
+            assert_ok!(TemplateModule::draw_jurors_helper(
+                key.clone(),
+                phase_data.clone(),
+                iterations
+            ));
+        }
+        let drawn_jurors = TemplateModule::drawn_jurors(key.clone());
+        assert_eq!(drawn_jurors.len(), 5);
+
+        let mut export = DrawExport {
+            tree,
+            max_draws: phase_data.max_draws,
+            pool_min_juror_stake: None,
+            draws_in_round: 0,
+            calls,
+            drawn_jurors: drawn_jurors
+                .iter()
+                .map(|(juror, stake)| (account(*juror), *stake))
+                .collect(),
+        };
+        // Seeds of the mock randomness
+        let seed_of = |call: &DrawCall| {
+            let (seed, _) =
+                <TestRandomness<Test> as Randomness<H256, u64>>::random(&call.nonce.encode());
+            seed.to_fixed_bytes()
+        };
+        let verification = verify_with(&export, seed_of).unwrap();
+        assert!(verification.is_valid());
+        assert_eq!(verification.expected.len(), 5);
+
+        let (juror, stake) = export.drawn_jurors[0].clone();
+        export.drawn_jurors[0] = (account(30), stake);
+        let verification = verify_with(&export, seed_of).unwrap();
+        assert_eq!(
+            verification.mismatches,
+            vec![
+                Mismatch::Missing { account: juror, stake },
+                Mismatch::Unexpected { account: account(30), stake },
+            ]
+        );
+    });
+}
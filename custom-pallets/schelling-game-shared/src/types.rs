@@ -13,6 +13,17 @@ pub enum Period {
 	Vote,    // Jurors reveal/cast their vote depending on whether the court has hidden votes or not.
 	Appeal,  // The dispute can be appealed.
 	Execution, // Tokens are redistributed and the ruling is executed.
+	Cancelled, // The game is cancelled, stakes are refunded and no ruling is executed.
+}
+
+/// Reason a schelling game was cancelled
+/// 1) Governance: cancelled by root
+/// 2) NotEnoughJurorsStaked: staking period is over with less jurors than the jury of the round
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum CancellationReason {
+	Governance,
+	NotEnoughJurorsStaked,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -39,7 +50,7 @@ pub struct PhaseData<T: Config> {
 	pub max_draws: u64,
 	pub min_number_juror_staked: u64,
 	pub min_juror_stake: BalanceOf<T>,
-	pub juror_incentives: (u64, u64), // (looser burn, winner mint)
+	pub juror_incentives: (u64, u64), // (looser burn, winner reward paid from the reward pool)
 }
 
 // #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -76,6 +87,7 @@ pub struct CommitVote {
 pub enum RevealedVote {
 	Yes,
 	No,
+	Abstain, // Refuse to arbitrate, e.g. evidence is insufficient
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -84,6 +96,7 @@ pub enum WinningDecision {
 	WinnerYes,
 	WinnerNo,
 	Draw,
+	Abstain, // Majority of the revealed votes refused to arbitrate
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
@@ -102,6 +115,116 @@ pub struct ScoreCommitVote {
 	pub revealed_vote: Option<i64>,
 }
 
+pub type CourtId = u64;
+
+/// Id of the General court, root of the court tree
+pub const GENERAL_COURT_ID: CourtId = 0;
+
+/// Parameters of a court, `None` is inherited from the parent court
+/// Parameters that no court in the path sets are taken from the phase data of the owning pallet
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct CourtParams {
+	pub evidence_length: Option<u64>,
+	pub end_of_staking_time: Option<u64>,
+	pub staking_length: Option<u64>,
+	pub drawing_length: Option<u64>,
+	pub commit_length: Option<u64>,
+	pub vote_length: Option<u64>,
+	pub appeal_length: Option<u64>,
+	pub max_draws: Option<u64>,
+	pub min_number_juror_staked: Option<u64>,
+	pub min_juror_stake: Option<u64>,
+	pub juror_incentives: Option<(u64, u64)>,
+	pub appeal_fee_per_juror: Option<u64>,
+	/// Stakes of the game are weighted by seniority: (blocks to full weight, initial weight in percent)
+	pub seniority_weighting: Option<(u64, u8)>,
+}
+
+impl CourtParams {
+	/// Fill the parameters that are not overridden with the parameters of the parent
+	pub fn inherit(self, parent: &CourtParams) -> CourtParams {
+		CourtParams {
+			evidence_length: self.evidence_length.or(parent.evidence_length),
+			end_of_staking_time: self.end_of_staking_time.or(parent.end_of_staking_time),
+			staking_length: self.staking_length.or(parent.staking_length),
+			drawing_length: self.drawing_length.or(parent.drawing_length),
+			commit_length: self.commit_length.or(parent.commit_length),
+			vote_length: self.vote_length.or(parent.vote_length),
+			appeal_length: self.appeal_length.or(parent.appeal_length),
+			max_draws: self.max_draws.or(parent.max_draws),
+			min_number_juror_staked: self.min_number_juror_staked.or(parent.min_number_juror_staked),
+			min_juror_stake: self.min_juror_stake.or(parent.min_juror_stake),
+			juror_incentives: self.juror_incentives.or(parent.juror_incentives),
+			appeal_fee_per_juror: self.appeal_fee_per_juror.or(parent.appeal_fee_per_juror),
+			seniority_weighting: self.seniority_weighting.or(parent.seniority_weighting),
+		}
+	}
+}
+
+/// Court of the court tree: General court > department courts > specialization subcourts
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct Court {
+	pub parent: Option<CourtId>,
+	pub name: Vec<u8>,
+	pub params: CourtParams,
+}
+
+pub type DepartmentId = u64;
+
+/// Stake of a juror in a department juror pool
+/// `locked` is the stake serving in running games, it can't be withdrawn
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PoolStake {
+	pub total: u64,
+	pub locked: u64,
+}
+
+impl PoolStake {
+	pub fn available(&self) -> u64 {
+		self.total.saturating_sub(self.locked)
+	}
+}
+
+/// Dimension of a rubric score game, e.g. reach or verifiability
+/// `weight` is the share of the dimension in the composite score
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RubricDimension {
+	pub name: Vec<u8>,
+	pub weight: u32,
+}
+
+/// Commit of a rubric vote, the revealed vote has a score for every dimension of the rubric
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct RubricCommitVote {
+	pub commit: [u8; 32],
+	pub votestatus: VoteStatus,
+	pub revealed_vote: Option<Vec<i64>>,
+}
+
+/// Pairwise comparison game, every drawn juror compares `pairs_per_juror` random pairs of `candidates`
+/// Candidates are item ids of the owning pallet, e.g. post ids or project ids
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PairwiseGame {
+	pub candidates: Vec<u64>,
+	pub pairs_per_juror: u32,
+}
+
+/// Commit of a pairwise vote, the revealed vote has a choice for every assigned pair
+/// 0: first item of the pair is better, 1: second item of the pair is better
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct PairwiseCommitVote {
+	pub commit: [u8; 32],
+	pub votestatus: VoteStatus,
+	pub revealed_vote: Option<Vec<u8>>,
+}
+
 /// RangePoint enum to determine whether score values are from
 /// 1) ZeroToTen: 0 to 10
 /// 2) MinusTenToPlusTen: -10 to +10
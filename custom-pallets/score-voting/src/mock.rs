@@ -5,6 +5,7 @@ use frame_support::{
     traits::{ConstU16, ConstU64},
 };
 use frame_system::pallet_prelude::*;
+use sp_arithmetic::FixedI64;
 use std::collections::HashMap;
 use trait_shared_storage::SharedStorageLink;
 
@@ -77,7 +78,19 @@ impl SharedStorageLink for MockSharedStorage {
         unimplemented!("get_approved_citizen_count_link is not mocked")
     }
 
-    fn set_positive_externality_link(_address: Self::AccountId, _score: i64) -> DispatchResult {
+    fn transfer_citizen_link(_old: Self::AccountId, _new: Self::AccountId) -> DispatchResult {
+        unimplemented!("transfer_citizen_link is not mocked")
+    }
+
+    fn remove_approved_citizen_link(_address: Self::AccountId) -> DispatchResult {
+        unimplemented!("remove_approved_citizen_link is not mocked")
+    }
+
+    fn revoke_citizen_link(_address: Self::AccountId) -> DispatchResult {
+        unimplemented!("revoke_citizen_link is not mocked")
+    }
+
+    fn set_positive_externality_link(_address: Self::AccountId, _score: FixedI64) -> DispatchResult {
         unimplemented!("set_positive_externality_link is not mocked")
     }
 
@@ -16,7 +16,22 @@ impl<T: Config> SharedStorageLink for Pallet<T> {
         Self::get_approved_citizen_count()
     }
 
-    fn set_positive_externality_link(address: Self::AccountId, score: i64) -> DispatchResult {
+    fn transfer_citizen_link(old: Self::AccountId, new: Self::AccountId) -> DispatchResult {
+        Self::transfer_citizen(old, new)
+    }
+
+    fn remove_approved_citizen_link(address: Self::AccountId) -> DispatchResult {
+        Self::remove_approved_citizen(address)
+    }
+
+    fn revoke_citizen_link(address: Self::AccountId) -> DispatchResult {
+        Self::revoke_citizen(address)
+    }
+
+    fn set_positive_externality_link(
+        address: Self::AccountId,
+        score: PositiveExternalityScoreOf,
+    ) -> DispatchResult {
         Self::set_positive_externality(address, score)
     }
 
@@ -79,10 +94,34 @@ impl<T: Config> Pallet<T> {
             Err(index) => {
                 members.insert(index, new_member.clone());
                 ApprovedCitizenAddress::<T>::put(members);
+                Self::restore_department_memberships(&new_member);
                 Ok(())
             }
         }
     }
+
+    /// Add the citizen back to the departments it was in when it was removed from the approved
+    /// citizens, departments that were removed or are full are skipped
+    fn restore_department_memberships(address: &T::AccountId) {
+        let suspended = match SuspendedDepartmentMemberships::<T>::take(address) {
+            Some(suspended) => suspended,
+            None => return,
+        };
+        for department_id in suspended.approved_departments {
+            ApprovedCitizenAddressByDepartment::<T>::mutate(department_id, |members| {
+                if let Err(index) = members.binary_search(address) {
+                    members.insert(index, address.clone());
+                }
+            });
+        }
+        for department_id in suspended.departments {
+            if Departments::<T>::contains_key(department_id) {
+                DepartmentMembers::<T>::mutate(department_id, |members| {
+                    let _ = members.try_insert(address.clone());
+                });
+            }
+        }
+    }
     pub(super) fn check_citizen_is_approved(address: T::AccountId) -> DispatchResult {
         let members = ApprovedCitizenAddress::<T>::get();
 
@@ -97,7 +136,114 @@ impl<T: Config> Pallet<T> {
         members.len() as u64
     }
 
-    pub(super) fn set_positive_externality(address: T::AccountId, score: Score) -> DispatchResult {
+    /// Move the approved citizen address, scores, department memberships and address of `old` to `new`
+    pub(super) fn transfer_citizen(old: T::AccountId, new: T::AccountId) -> DispatchResult {
+        let mut members = ApprovedCitizenAddress::<T>::get();
+        let old_index = members
+            .binary_search(&old)
+            .map_err(|_| Error::<T>::CitizenNotApproved)?;
+        members.remove(old_index);
+        match members.binary_search(&new) {
+            Ok(_) => Err(Error::<T>::AlreadyMember)?,
+            Err(index) => members.insert(index, new.clone()),
+        }
+        ApprovedCitizenAddress::<T>::put(members);
+
+        let departments = ApprovedCitizenAddressByDepartment::<T>::iter_keys().collect::<Vec<_>>();
+        for department_id in departments {
+            ApprovedCitizenAddressByDepartment::<T>::mutate(department_id, |members| {
+                if let Ok(index) = members.binary_search(&old) {
+                    members.remove(index);
+                    if let Err(index) = members.binary_search(&new) {
+                        members.insert(index, new.clone());
+                    }
+                }
+            });
+        }
+
+        let departments = DepartmentMembers::<T>::iter_keys().collect::<Vec<_>>();
+        for department_id in departments {
+            DepartmentMembers::<T>::try_mutate(department_id, |members| -> DispatchResult {
+                if members.remove(&old) {
+                    members
+                        .try_insert(new.clone())
+                        .map_err(|_| Error::<T>::TooManyMembers)?;
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(reputation_score) = ReputationScoreOfAccount::<T>::take(&old) {
+            ReputationScoreOfAccount::<T>::insert(&new, reputation_score);
+        }
+        if PositiveExternalityScore::<T>::contains_key(&old) {
+            let score = PositiveExternalityScore::<T>::take(&old);
+            PositiveExternalityScore::<T>::insert(&new, score);
+        }
+        if let Some(address) = Addresses::<T>::take(&old) {
+            Addresses::<T>::insert(&new, address);
+        }
+
+        Self::deposit_event(Event::CitizenTransferred { old, new });
+        Ok(())
+    }
+
+    /// Remove `address` from approved citizens and all departments, its reputation can't change anymore
+    pub(super) fn revoke_citizen(address: T::AccountId) -> DispatchResult {
+        Self::remove_approved_citizen(address.clone())?;
+        SuspendedDepartmentMemberships::<T>::remove(&address);
+        FrozenReputation::<T>::insert(&address, true);
+        Self::deposit_event(Event::CitizenRevoked { address });
+        Ok(())
+    }
+
+    /// Remove `address` from approved citizens and all departments
+    /// The departments are kept in `SuspendedDepartmentMemberships` until the citizen is approved again.
+    pub(super) fn remove_approved_citizen(address: T::AccountId) -> DispatchResult {
+        let mut members = ApprovedCitizenAddress::<T>::get();
+        let index = members
+            .binary_search(&address)
+            .map_err(|_| Error::<T>::CitizenNotApproved)?;
+        members.remove(index);
+        ApprovedCitizenAddress::<T>::put(members);
+
+        let mut suspended = SuspendedMemberships::default();
+        let departments = ApprovedCitizenAddressByDepartment::<T>::iter_keys().collect::<Vec<_>>();
+        for department_id in departments {
+            ApprovedCitizenAddressByDepartment::<T>::mutate(department_id, |members| {
+                if let Ok(index) = members.binary_search(&address) {
+                    members.remove(index);
+                    suspended.approved_departments.push(department_id);
+                }
+            });
+        }
+
+        let departments = DepartmentMembers::<T>::iter_keys().collect::<Vec<_>>();
+        for department_id in departments {
+            DepartmentMembers::<T>::mutate(department_id, |members| {
+                if members.remove(&address) {
+                    suspended.departments.push(department_id);
+                }
+            });
+        }
+        if suspended != SuspendedMemberships::default() {
+            SuspendedDepartmentMemberships::<T>::insert(&address, suspended);
+        }
+        Ok(())
+    }
+
+    fn ensure_reputation_not_frozen(address: &T::AccountId) -> DispatchResult {
+        ensure!(
+            !FrozenReputation::<T>::get(address),
+            Error::<T>::ReputationFrozen
+        );
+        Ok(())
+    }
+
+    pub(super) fn set_positive_externality(
+        address: T::AccountId,
+        score: PositiveExternalityScoreOf,
+    ) -> DispatchResult {
         PositiveExternalityScore::<T>::insert(address, score);
         Ok(())
     }
@@ -107,6 +253,7 @@ impl<T: Config> Pallet<T> {
         department_id: u64,
         score: i64,
     ) -> DispatchResult {
+        Self::ensure_reputation_not_frozen(&address)?;
         ReputationScoreOfAccount::<T>::mutate(address, |reputation_score| {
             if let Some(reputation_score) = reputation_score.as_mut() {
                 reputation_score.add_department(department_id.clone(), score);
@@ -126,6 +273,7 @@ impl<T: Config> Pallet<T> {
         department_id: u64,
         score: i64,
     ) -> DispatchResult {
+        Self::ensure_reputation_not_frozen(&address)?;
         ReputationScoreOfAccount::<T>::mutate(address, |reputation_score| {
             if let Some(reputation_score) = reputation_score.as_mut() {
                 reputation_score.update_department(department_id.clone(), score);
@@ -145,6 +293,7 @@ impl<T: Config> Pallet<T> {
         department_id: u64,
         amount: i64,
     ) -> DispatchResult {
+        Self::ensure_reputation_not_frozen(&address)?;
         ReputationScoreOfAccount::<T>::mutate(address, |reputation_score| {
             if let Some(reputation_score) = reputation_score.as_mut() {
                 reputation_score.add_score(department_id.clone(), amount);
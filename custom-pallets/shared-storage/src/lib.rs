@@ -12,6 +12,7 @@ mod mock;
 mod tests;
 
 pub mod extras;
+pub mod migrations;
 pub mod types;
 
 use codec::{Decode, Encode};
@@ -20,11 +21,13 @@ use frame_support::traits::BuildGenesisConfig;
 use frame_support::BoundedVec;
 use frame_system::ensure_root;
 use frame_system::pallet_prelude::*;
+use sp_arithmetic::FixedI64;
 use sp_std::prelude::*;
-use types::{Address, GpsCoordinate, ReputationScore};
+use types::{Address, GpsCoordinate, ReputationScore, SuspendedMemberships};
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-type Score = i64;
+/// Positive externality score, the trimmed mean of a score schelling game.
+type PositiveExternalityScoreOf = FixedI64;
 type DepartmentId = u64;
 pub type MaxNameLength = ConstU32<64>;
 pub type AddressNameLength = ConstU32<64>;
@@ -37,7 +40,11 @@ use crate::types::{Department, Group};
 pub mod pallet {
     use super::*;
 
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -59,7 +66,7 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn positive_externality_score)]
     pub type PositiveExternalityScore<T: Config> =
-        StorageMap<_, Blake2_128Concat, T::AccountId, Score, ValueQuery>;
+        StorageMap<_, Blake2_128Concat, T::AccountId, PositiveExternalityScoreOf, ValueQuery>;
 
     // Keep winning representatives of department in shared storage
 
@@ -68,6 +75,19 @@ pub mod pallet {
     pub type ReputationScoreOfAccount<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, ReputationScore>;
 
+    /// Reputation of revoked citizens can't change
+    #[pallet::storage]
+    #[pallet::getter(fn is_reputation_frozen)]
+    pub type FrozenReputation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+    /// Department memberships of citizens removed from the approved citizens until they are
+    /// approved again
+    #[pallet::storage]
+    #[pallet::getter(fn suspended_memberships)]
+    pub type SuspendedDepartmentMemberships<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SuspendedMemberships>;
+
     #[pallet::storage]
     pub type DepartmentCount<T: Config> = StorageValue<_, u64, ValueQuery>;
 
@@ -137,6 +157,13 @@ pub mod pallet {
         AddressSaved {
             who: T::AccountId,
         },
+        CitizenTransferred {
+            old: T::AccountId,
+            new: T::AccountId,
+        },
+        CitizenRevoked {
+            address: T::AccountId,
+        },
     }
 
     // Errors inform users that something went wrong.
@@ -162,6 +189,7 @@ pub mod pallet {
         CityTooLong,
         InvalidLatitude,
         InvalidLongitude,
+        ReputationFrozen,
     }
 
     #[pallet::call]
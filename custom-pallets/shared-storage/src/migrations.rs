@@ -0,0 +1,35 @@
+use crate::*;
+
+pub mod v1 {
+    use super::*;
+    use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+    use sp_arithmetic::FixedPointNumber;
+
+    /// Scores before storage version 1 are integers scaled by 1000.
+    fn score_from_scaled(score: i64) -> FixedI64 {
+        FixedI64::saturating_from_rational(score, 1000)
+    }
+
+    /// Converts the positive externality scores to fixed point.
+    pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            PositiveExternalityScore::<T>::translate::<i64, _>(|_, score| {
+                translated += 1;
+                Some(score_from_scaled(score))
+            });
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// Migrates the scores to fixed point, runs only when the on-chain storage version is 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -167,3 +167,67 @@ fn test_get_total_reputation_score() {
         assert_eq!(total_score, score1 + score2);
     });
 }
+
+#[test]
+fn migrate_scores_to_fixed_point() {
+    use crate::migrations::v1::InnerMigrateV0ToV1;
+    use crate::PositiveExternalityScore;
+    use codec::Encode;
+    use frame_support::traits::UncheckedOnRuntimeUpgrade;
+    use sp_arithmetic::FixedI64;
+
+    new_test_ext().execute_with(|| {
+        let address = 1;
+        // Score of 2.5 stored before the migration as an integer scaled by 1000
+        frame_support::storage::unhashed::put_raw(
+            &PositiveExternalityScore::<Test>::hashed_key_for(address),
+            &2500i64.encode(),
+        );
+
+        InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            SharedStorage::positive_externality_score(address),
+            FixedI64::from_rational(5, 2)
+        );
+    });
+}
+
+#[test]
+fn department_memberships_are_restored_on_approval() {
+    use crate::DepartmentMembers;
+    use frame_system::RawOrigin;
+
+    new_test_ext().execute_with(|| {
+        let address = 1;
+        let name: BoundedVec<u8, MaxNameLength> = BoundedVec::try_from(vec![1, 2, 3]).unwrap();
+        assert_ok!(SharedStorage::create_department(
+            RawOrigin::Root.into(),
+            name,
+            DepartmentType::District
+        ));
+        assert_ok!(SharedStorage::add_approved_citizen_address(address));
+        assert_ok!(SharedStorage::add_member_to_department(
+            RawOrigin::Signed(address).into(),
+            0,
+            address
+        ));
+
+        // Removed citizen leaves the department until it is approved again
+        assert_ok!(SharedStorage::remove_approved_citizen(address));
+        assert!(!DepartmentMembers::<Test>::get(0).contains(&address));
+        assert_eq!(
+            SharedStorage::suspended_memberships(address).map(|suspended| suspended.departments),
+            Some(vec![0])
+        );
+
+        assert_ok!(SharedStorage::add_approved_citizen_address(address));
+        assert!(DepartmentMembers::<Test>::get(0).contains(&address));
+        assert_eq!(SharedStorage::suspended_memberships(address), None);
+
+        // Revoked citizen doesn't get its departments back
+        assert_ok!(SharedStorage::revoke_citizen(address));
+        assert_ok!(SharedStorage::add_approved_citizen_address(address));
+        assert!(!DepartmentMembers::<Test>::get(0).contains(&address));
+    });
+}
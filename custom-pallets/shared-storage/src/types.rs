@@ -26,6 +26,13 @@ pub struct ReputationScore {
     pub total_score: i64,
 }
 
+/// Departments of a citizen removed from the approved citizens, restored when it is approved again
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, Default)]
+pub struct SuspendedMemberships {
+    pub approved_departments: Vec<u64>,
+    pub departments: Vec<u64>,
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, PartialOrd, Ord, RuntimeDebug, TypeInfo)]
 pub enum DepartmentType {
     District,
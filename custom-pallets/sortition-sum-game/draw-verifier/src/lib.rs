@@ -0,0 +1,349 @@
+//! Replays the juror draws of `draw_jurors_helper` from exported chain state.
+//!
+//! The export holds the sortition tree of the game before the draws (`SortitionSumTreeNodes` and
+//! `NodeIndexesToIds`), and for every `draw_jurors` call the block number, the `RandomMaterial`
+//! of the randomness pallet in that block and the schelling game `Nonce` before the call.
+//! Every call draws its jurors with `draw_many` from one seed, the random output for the `Nonce`.
+//! The replay draws the same jurors as the chain, mismatches with `DrawnJurors` are reported.
+//!
+//! Trees with seniority weighting hold weights in their leaves, the stakes are exported from `LeafStakes`.
+//!
+//! Accounts are hex encoded account ids, so they are ordered like on chain.
+//!
+//! Games drawing from a juror pool record the pool stake locked for a drawn juror instead of the
+//! tree stake, and put the jurors without free pool stake back in the tree with `restore`.
+//! The export holds the `min_juror_stake` of the round and, for every call, the free pool stakes
+//! of the jurors from `JurorPoolStakes` before the call.
+
+use codec::Encode;
+use safe_mix::TripletMix;
+use serde::{Deserialize, Serialize};
+use sp_core::{hashing::blake2_256, H256};
+use std::collections::BTreeMap;
+
+#[cfg(test)]
+mod tests;
+
+/// Length of the random material of `pallet-insecure-randomness-collective-flip`
+pub const RANDOM_MATERIAL_LEN: u32 = 81;
+
+/// Sortition tree of the game before the draws
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ExportedTree {
+    pub k: u64,
+    pub nodes: Vec<u64>,
+    pub node_indexes_to_ids: BTreeMap<u64, String>,
+    /// `LeafStakes` of a tree with seniority weighting, the stakes of the leaves holding weights
+    #[serde(default)]
+    pub leaf_stakes: BTreeMap<String, u64>,
+}
+
+/// One `draw_jurors` call of the game
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DrawCall {
+    /// Block in which the call was executed
+    pub block_number: u32,
+    /// `RandomMaterial` of the randomness pallet in that block
+    pub random_material: Vec<H256>,
+    /// `Nonce` of the schelling game before the call
+    pub nonce: u64,
+    pub iterations: u64,
+    /// Free pool stakes of the jurors before the call, total minus locked, for a pool game
+    #[serde(default)]
+    pub pool_available: BTreeMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DrawExport {
+    pub tree: ExportedTree,
+    /// `max_draws` of the round
+    pub max_draws: u64,
+    /// `min_juror_stake` of the round for a game drawing from a juror pool, `None` for other games
+    #[serde(default)]
+    pub pool_min_juror_stake: Option<u64>,
+    /// `DrawsInRound` before the first call
+    #[serde(default)]
+    pub draws_in_round: u64,
+    pub calls: Vec<DrawCall>,
+    /// `DrawnJurors` of the game after the calls
+    pub drawn_jurors: Vec<(String, u64)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// Drawn by the replay, missing in `DrawnJurors`
+    Missing { account: String, stake: u64 },
+    /// In `DrawnJurors`, not drawn by the replay
+    Unexpected { account: String, stake: u64 },
+    /// Drawn by both with different stakes
+    Stake { account: String, expected: u64, recorded: u64 },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Verification {
+    /// Jurors drawn by the replay with their stakes, ordered by account
+    pub expected: Vec<(String, u64)>,
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl Verification {
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Sortition tree in memory, `draw`, `draw_many`, `remove` and `restore` follow
+/// `pallet-sortition-sum-game`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tree {
+    k: u64,
+    nodes: Vec<u64>,
+    ids_to_node_indexes: BTreeMap<String, u64>,
+    node_indexes_to_ids: BTreeMap<u64, String>,
+    leaf_stakes: BTreeMap<String, u64>,
+    /// Nodes vacated by the replay, the top of `SortitionSumTreeStack`
+    stack: Vec<u64>,
+    /// Weights of the participants removed by the replay
+    removed: BTreeMap<String, u64>,
+}
+
+impl Tree {
+    pub fn from_export(export: &ExportedTree) -> Result<Self, String> {
+        if export.k < 1 {
+            return Err("k must be greater than zero".into());
+        }
+        if export.nodes.is_empty() {
+            return Err("tree has no root node".into());
+        }
+        if let Some(index) =
+            export.node_indexes_to_ids.keys().find(|index| **index as usize >= export.nodes.len())
+        {
+            return Err(format!("node index {} is out of the tree", index));
+        }
+        let ids_to_node_indexes = export
+            .node_indexes_to_ids
+            .iter()
+            .map(|(index, id)| (id.clone(), *index))
+            .collect();
+        Ok(Tree {
+            k: export.k,
+            nodes: export.nodes.clone(),
+            ids_to_node_indexes,
+            node_indexes_to_ids: export.node_indexes_to_ids.clone(),
+            leaf_stakes: export.leaf_stakes.clone(),
+            stack: Vec::new(),
+            removed: BTreeMap::new(),
+        })
+    }
+
+    pub fn total_stake(&self) -> u64 {
+        self.nodes[0]
+    }
+
+    /// Weight of the participant, the value of its leaf
+    pub fn weight_of(&self, id: &str) -> Option<u64> {
+        self.ids_to_node_indexes.get(id).map(|index| self.nodes[*index as usize])
+    }
+
+    /// Stake of the participant, from `LeafStakes` when the leaf holds a weight
+    pub fn stake_of(&self, id: &str) -> Option<u64> {
+        let weight = self.weight_of(id)?;
+        Some(self.leaf_stakes.get(id).copied().unwrap_or(weight))
+    }
+
+    /// Participant of the leaf of `draw_number`, `None` for an empty or inconsistent tree
+    pub fn draw(&self, draw_number: u64) -> Option<String> {
+        if self.total_stake() == 0 {
+            return None;
+        }
+        let mut tree_index = 0;
+        let mut current_draw_number = draw_number % self.total_stake();
+        while (self.k * tree_index) + 1 < self.nodes.len() as u64 {
+            let mut child_index = None;
+            for i in 1..self.k + 1 {
+                let node_index = (self.k * tree_index) + i;
+                let node_value = *self.nodes.get(node_index as usize).unwrap_or(&0);
+                if current_draw_number >= node_value {
+                    current_draw_number -= node_value;
+                } else {
+                    child_index = Some(node_index);
+                    break;
+                }
+            }
+            tree_index = child_index?;
+        }
+        self.node_indexes_to_ids.get(&tree_index).cloned()
+    }
+
+    /// Draws up to `n` distinct participants from `seed` and removes them from the tree,
+    /// they are returned with their stakes
+    pub fn draw_many(&mut self, seed: [u8; 32], n: u64) -> Vec<(String, u64)> {
+        let mut drawn = Vec::new();
+        let mut draw_nonce = 0u64;
+        while (drawn.len() as u64) < n && self.total_stake() > 0 {
+            let number = draw_number(seed, draw_nonce);
+            draw_nonce += 1;
+            let account = match self.draw(number) {
+                Some(account) => account,
+                None => break,
+            };
+            let stake = self.stake_of(&account).unwrap_or(0);
+            self.remove(&account);
+            drawn.push((account, stake));
+        }
+        drawn
+    }
+
+    /// Sets the stake of the participant to zero, as the game does for a drawn juror
+    pub fn remove(&mut self, id: &str) {
+        let tree_index = match self.ids_to_node_indexes.remove(id) {
+            Some(tree_index) => tree_index,
+            None => return,
+        };
+        self.node_indexes_to_ids.remove(&tree_index);
+        let value = std::mem::take(&mut self.nodes[tree_index as usize]);
+        self.stack.push(tree_index);
+        self.removed.insert(id.to_string(), value);
+        let mut parent_index = tree_index;
+        while parent_index != 0 {
+            parent_index = (parent_index - 1) / self.k;
+            let node = &mut self.nodes[parent_index as usize];
+            *node = node.saturating_sub(value);
+        }
+    }
+
+    /// Puts participants removed by the replay back with their weights, in reverse order,
+    /// every one takes the last vacant node from the stack
+    pub fn restore(&mut self, ids: &[String]) {
+        for id in ids.iter().rev() {
+            let (value, tree_index) = match (self.removed.remove(id), self.stack.pop()) {
+                (Some(value), Some(tree_index)) => (value, tree_index),
+                _ => continue,
+            };
+            self.nodes[tree_index as usize] = value;
+            self.ids_to_node_indexes.insert(id.clone(), tree_index);
+            self.node_indexes_to_ids.insert(tree_index, id.clone());
+            let mut parent_index = tree_index;
+            while parent_index != 0 {
+                parent_index = (parent_index - 1) / self.k;
+                let node = &mut self.nodes[parent_index as usize];
+                *node = node.saturating_add(value);
+            }
+        }
+    }
+}
+
+/// Output of `pallet-insecure-randomness-collective-flip` for `subject` in the block
+pub fn collective_flip_random(subject: &[u8], block_number: u32, random_material: &[H256]) -> H256 {
+    if random_material.is_empty() {
+        return H256::default();
+    }
+    let index = (block_number.saturating_sub(1) % RANDOM_MATERIAL_LEN) as usize;
+    random_material
+        .iter()
+        .cycle()
+        .skip(index)
+        .take(RANDOM_MATERIAL_LEN as usize)
+        .enumerate()
+        .map(|(i, h)| H256::from((i as i8, subject, h).using_encoded(blake2_256)))
+        .triplet_mix()
+}
+
+/// Seed of the `draw_jurors` call with `nonce`, the random hash
+pub fn draw_seed(nonce: u64, block_number: u32, random_material: &[H256]) -> [u8; 32] {
+    collective_flip_random(&nonce.encode(), block_number, random_material).to_fixed_bytes()
+}
+
+/// Draw number of the `draw_nonce`th draw of `draw_many`, the first eight bytes of the hash
+pub fn draw_number(seed: [u8; 32], draw_nonce: u64) -> u64 {
+    let hash = (seed, draw_nonce).using_encoded(blake2_256);
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hash[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Stake locked for a drawn juror of a pool game as `serving_stake` of the schelling game,
+/// zero when the juror has no free pool stake to lock
+pub fn pool_serving_stake(min_juror_stake: u64, available: u64) -> u64 {
+    let locked = if min_juror_stake == 0 { available } else { min_juror_stake };
+    if locked == 0 || locked > available {
+        return 0;
+    }
+    locked
+}
+
+/// Jurors drawn by the calls, ordered by account as `DrawnJurors`
+pub fn replay(export: &DrawExport) -> Result<Vec<(String, u64)>, String> {
+    replay_with(export, |call| draw_seed(call.nonce, call.block_number, &call.random_material))
+}
+
+/// Like `replay`, with the seeds of the calls from `seed_of`, for chains with another randomness source
+pub fn replay_with(
+    export: &DrawExport,
+    seed_of: impl Fn(&DrawCall) -> [u8; 32],
+) -> Result<Vec<(String, u64)>, String> {
+    let mut tree = Tree::from_export(&export.tree)?;
+    let mut drawn: Vec<(String, u64)> = Vec::new();
+    let mut draws_in_round = export.draws_in_round;
+
+    for call in export.calls.iter() {
+        if draws_in_round >= export.max_draws {
+            return Err(format!("call in block {} exceeds max draws", call.block_number));
+        }
+        let end_index = (draws_in_round + call.iterations).min(export.max_draws);
+        let seed = seed_of(call);
+        let mut not_serving = Vec::new();
+        for (account, stake) in tree.draw_many(seed, end_index - draws_in_round) {
+            if let Err(index) = drawn.binary_search_by(|(c, _)| c.as_str().cmp(&account)) {
+                let serving_stake = match export.pool_min_juror_stake {
+                    Some(min_juror_stake) => pool_serving_stake(
+                        min_juror_stake,
+                        call.pool_available.get(&account).copied().unwrap_or(0),
+                    ),
+                    None => stake,
+                };
+                if serving_stake > 0 {
+                    drawn.insert(index, (account, serving_stake));
+                    draws_in_round += 1;
+                } else {
+                    not_serving.push(account);
+                }
+            }
+        }
+        tree.restore(&not_serving);
+    }
+    Ok(drawn)
+}
+
+/// Replays the draws and compares them with `DrawnJurors`
+pub fn verify(export: &DrawExport) -> Result<Verification, String> {
+    verify_with(export, |call| draw_seed(call.nonce, call.block_number, &call.random_material))
+}
+
+/// Like `verify`, with the seeds of the calls from `seed_of`
+pub fn verify_with(
+    export: &DrawExport,
+    seed_of: impl Fn(&DrawCall) -> [u8; 32],
+) -> Result<Verification, String> {
+    let expected = replay_with(export, seed_of)?;
+    let recorded: BTreeMap<&String, u64> =
+        export.drawn_jurors.iter().map(|(account, stake)| (account, *stake)).collect();
+    let mut mismatches = Vec::new();
+    for (account, stake) in expected.iter() {
+        match recorded.get(account) {
+            None => mismatches.push(Mismatch::Missing { account: account.clone(), stake: *stake }),
+            Some(recorded) if recorded != stake => mismatches.push(Mismatch::Stake {
+                account: account.clone(),
+                expected: *stake,
+                recorded: *recorded,
+            }),
+            Some(_) => {},
+        }
+    }
+    for (account, stake) in export.drawn_jurors.iter() {
+        if expected.binary_search_by(|(c, _)| c.cmp(account)).is_err() {
+            mismatches.push(Mismatch::Unexpected { account: account.clone(), stake: *stake });
+        }
+    }
+    Ok(Verification { expected, mismatches })
+}
@@ -0,0 +1,58 @@
+//! `sortition-draw-verifier <export.json>`
+//!
+//! Prints the jurors drawn by the replay and the mismatches with `DrawnJurors`,
+//! exits with status 1 when the draw can't be reproduced.
+
+use sortition_draw_verifier::{verify, DrawExport, Mismatch};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: sortition-draw-verifier <export.json>");
+            return ExitCode::from(2);
+        },
+    };
+    let export: DrawExport = match std::fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+    {
+        Ok(export) => export,
+        Err(e) => {
+            eprintln!("unable to read {}: {}", path, e);
+            return ExitCode::from(2);
+        },
+    };
+    let verification = match verify(&export) {
+        Ok(verification) => verification,
+        Err(e) => {
+            eprintln!("unable to replay the draws: {}", e);
+            return ExitCode::from(2);
+        },
+    };
+
+    println!("Expected jurors:");
+    for (account, stake) in verification.expected.iter() {
+        println!("  {} {}", account, stake);
+    }
+    if verification.is_valid() {
+        println!("Drawn jurors match the replay");
+        return ExitCode::SUCCESS;
+    }
+    println!("Mismatches:");
+    for mismatch in verification.mismatches.iter() {
+        match mismatch {
+            Mismatch::Missing { account, stake } => {
+                println!("  missing {} {}", account, stake)
+            },
+            Mismatch::Unexpected { account, stake } => {
+                println!("  unexpected {} {}", account, stake)
+            },
+            Mismatch::Stake { account, expected, recorded } => {
+                println!("  stake of {} expected {} recorded {}", account, expected, recorded)
+            },
+        }
+    }
+    ExitCode::from(1)
+}
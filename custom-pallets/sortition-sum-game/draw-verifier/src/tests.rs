@@ -0,0 +1,158 @@
+use crate::*;
+
+fn material() -> Vec<H256> {
+    (0u8..81).map(H256::repeat_byte).collect()
+}
+
+/// Tree of k = 2 with the stakes 10, 20, 30, 40, as built by the pallet
+fn export(drawn_jurors: Vec<(String, u64)>) -> DrawExport {
+    DrawExport {
+        tree: ExportedTree {
+            k: 2,
+            nodes: vec![100, 40, 60, 30, 10, 40, 20],
+            node_indexes_to_ids: BTreeMap::from([
+                (3, "0x03".to_string()),
+                (4, "0x01".to_string()),
+                (5, "0x04".to_string()),
+                (6, "0x02".to_string()),
+            ]),
+            leaf_stakes: BTreeMap::new(),
+        },
+        max_draws: 3,
+        pool_min_juror_stake: None,
+        draws_in_round: 0,
+        calls: vec![
+            DrawCall {
+                block_number: 20,
+                random_material: material(),
+                nonce: 5,
+                iterations: 2,
+                pool_available: BTreeMap::new(),
+            },
+            DrawCall {
+                block_number: 21,
+                random_material: material(),
+                nonce: 7,
+                iterations: 5,
+                pool_available: BTreeMap::new(),
+            },
+        ],
+        drawn_jurors,
+    }
+}
+
+#[test]
+fn draw_follows_the_tree() {
+    let tree = Tree::from_export(&export(vec![]).tree).unwrap();
+    assert_eq!(tree.draw(0), Some("0x03".to_string()));
+    assert_eq!(tree.draw(35), Some("0x01".to_string()));
+    assert_eq!(tree.draw(79), Some("0x04".to_string()));
+    assert_eq!(tree.draw(99), Some("0x02".to_string()));
+    assert_eq!(tree.draw(199), Some("0x02".to_string()));
+
+    let mut tree = tree;
+    tree.remove("0x04");
+    assert_eq!(tree.total_stake(), 60);
+    assert_eq!(tree.stake_of("0x04"), None);
+    assert_eq!(tree.draw(59), Some("0x02".to_string()));
+
+    // Drawn participants are distinct, the tree runs out of stake after three
+    let drawn = tree.draw_many(draw_seed(5, 20, &material()), 5);
+    assert_eq!(drawn.len(), 3);
+    let mut accounts: Vec<String> = drawn.iter().map(|(account, _)| account.clone()).collect();
+    accounts.sort();
+    assert_eq!(accounts, vec!["0x01".to_string(), "0x02".to_string(), "0x03".to_string()]);
+    assert_eq!(drawn.iter().map(|(_, stake)| stake).sum::<u64>(), 60);
+    assert_eq!(tree.total_stake(), 0);
+}
+
+#[test]
+fn weighted_leaves_are_drawn_with_their_stakes() {
+    let mut weighted = export(vec![]);
+    weighted.tree.leaf_stakes = BTreeMap::from([("0x04".to_string(), 100)]);
+    let tree = Tree::from_export(&weighted.tree).unwrap();
+    assert_eq!(tree.weight_of("0x04"), Some(40));
+    assert_eq!(tree.stake_of("0x04"), Some(100));
+    assert_eq!(tree.stake_of("0x01"), Some(10));
+
+    // Jurors are drawn by weight, the same as without stakes, and recorded with their stakes
+    let expected: Vec<(String, u64)> = replay(&export(vec![]))
+        .unwrap()
+        .into_iter()
+        .map(|(account, stake)| {
+            let stake = if account == "0x04" { 100 } else { stake };
+            (account, stake)
+        })
+        .collect();
+    assert_eq!(replay(&weighted).unwrap(), expected);
+}
+
+#[test]
+fn replay_draws_distinct_jurors() {
+    assert_eq!(draw_seed(5, 20, &material()), draw_seed(5, 20, &material()));
+    assert_ne!(draw_seed(5, 20, &material()), draw_seed(6, 20, &material()));
+    let seed = draw_seed(5, 20, &material());
+    assert_ne!(draw_number(seed, 0), draw_number(seed, 1));
+
+    let expected = replay(&export(vec![])).unwrap();
+    assert_eq!(expected.len(), 3);
+    assert!(expected.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+    let verification = verify(&export(expected.clone())).unwrap();
+    assert!(verification.is_valid());
+
+    let mut rigged = expected.clone();
+    rigged[0].1 += 1;
+    rigged.push(("0x05".to_string(), 50));
+    let verification = verify(&export(rigged)).unwrap();
+    assert_eq!(
+        verification.mismatches,
+        vec![
+            Mismatch::Stake {
+                account: expected[0].0.clone(),
+                expected: expected[0].1,
+                recorded: expected[0].1 + 1
+            },
+            Mismatch::Unexpected { account: "0x05".to_string(), stake: 50 },
+        ]
+    );
+}
+
+#[test]
+fn restored_jurors_take_the_last_vacant_nodes() {
+    let mut tree = Tree::from_export(&export(vec![]).tree).unwrap();
+    tree.remove("0x04");
+    tree.remove("0x01");
+    assert_eq!(tree.total_stake(), 50);
+
+    tree.restore(&["0x04".to_string()]);
+    assert_eq!(tree.total_stake(), 90);
+    assert_eq!(tree.weight_of("0x04"), Some(40));
+    assert_eq!(tree.weight_of("0x01"), None);
+    // 0x04 is back in the node of 0x01, after 0x03
+    assert_eq!(tree.draw(30), Some("0x04".to_string()));
+    assert_eq!(tree.draw(70), Some("0x02".to_string()));
+}
+
+#[test]
+fn pool_jurors_without_free_stake_are_put_back() {
+    let mut pool = export(vec![]);
+    pool.pool_min_juror_stake = Some(15);
+    let available = BTreeMap::from([
+        ("0x01".to_string(), 100),
+        ("0x02".to_string(), 100),
+        ("0x03".to_string(), 10),
+        ("0x04".to_string(), 15),
+    ]);
+    for call in pool.calls.iter_mut() {
+        call.pool_available = available.clone();
+    }
+
+    // Jurors are recorded with the locked stake, 0x03 can't lock it and is never recorded
+    let expected = replay(&pool).unwrap();
+    assert!(!expected.is_empty());
+    assert!(expected.iter().all(|(account, stake)| account != "0x03" && *stake == 15));
+
+    pool.drawn_jurors = expected;
+    assert!(verify(&pool).unwrap().is_valid());
+}
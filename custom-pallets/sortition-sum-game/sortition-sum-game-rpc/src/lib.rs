@@ -0,0 +1,155 @@
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::{ErrorObject, ErrorObjectOwned},
+};
+use sortition_sum_game_runtime_api::SortitionSumGameApi as SortitionSumGameRuntimeApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+#[rpc(client, server)]
+pub trait SortitionSumGameApi<BlockHash, SumTreeName, AccountId> {
+    #[method(name = "sortitionsumgame_totalstake")]
+    fn total_stake(&self, key: SumTreeName, at: Option<BlockHash>) -> RpcResult<Option<u64>>;
+
+    #[method(name = "sortitionsumgame_queryleafs")]
+    fn query_leafs(
+        &self,
+        key: SumTreeName,
+        cursor: u64,
+        count: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<(Vec<(AccountId, u64)>, bool)>>;
+
+    #[method(name = "sortitionsumgame_stakeof")]
+    fn stake_of(
+        &self,
+        key: SumTreeName,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u64>>;
+
+    /// Probability that one draw from the tree selects the participant, in parts per billion.
+    /// Jurors of a game are drawn without replacement, their chance to be drawn over all draws
+    /// of the round is higher.
+    #[method(name = "sortitionsumgame_singledrawprobability")]
+    fn single_draw_probability(
+        &self,
+        key: SumTreeName,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<u32>>;
+}
+
+/// A struct that implements the `SortitionSumGameApi`.
+pub struct SortitionSumGame<C, M> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> SortitionSumGame<C, M> {
+    /// Create new `SortitionSumGame` instance with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The transaction was not decodable.
+    DecodeError,
+    /// The call to runtime failed.
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::DecodeError => 2,
+        }
+    }
+}
+
+fn map_err(error: impl ToString, desc: &'static str) -> ErrorObjectOwned {
+    ErrorObject::owned(Error::RuntimeError.into(), desc, Some(error.to_string()))
+}
+
+impl<C, Block, SumTreeName, AccountId>
+    SortitionSumGameApiServer<<Block as BlockT>::Hash, SumTreeName, AccountId>
+    for SortitionSumGame<C, Block>
+where
+    Block: BlockT,
+    SumTreeName: Codec,
+    AccountId: Codec,
+    C: Send + Sync + 'static,
+    C: ProvideRuntimeApi<Block>,
+    C: HeaderBackend<Block>,
+    C::Api: SortitionSumGameRuntimeApi<Block, SumTreeName, AccountId>,
+{
+    fn total_stake(&self, key: SumTreeName, at: Option<Block::Hash>) -> RpcResult<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.total_stake(at, key);
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query total stake."))?;
+        Ok(res)
+    }
+
+    fn query_leafs(
+        &self,
+        key: SumTreeName,
+        cursor: u64,
+        count: u64,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<(Vec<(AccountId, u64)>, bool)>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.query_leafs(at, key, cursor, count);
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query leafs."))?;
+        Ok(res)
+    }
+
+    fn stake_of(
+        &self,
+        key: SumTreeName,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<u64>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.stake_of(at, key, who);
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query stake."))?;
+        Ok(res)
+    }
+
+    fn single_draw_probability(
+        &self,
+        key: SumTreeName,
+        who: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<u32>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+        let runtime_api_result = api.single_draw_probability(at, key, who);
+        let res = runtime_api_result.map_err(|e| map_err(e, "Unable to query draw probability."))?;
+        Ok(res.map(|probability| probability.deconstruct()))
+    }
+}
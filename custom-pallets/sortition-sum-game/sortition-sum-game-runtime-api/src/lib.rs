@@ -0,0 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::Perbill;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+    pub trait SortitionSumGameApi<SumTreeName, AccountId> where SumTreeName: Codec, AccountId: Codec {
+
+        fn total_stake(key: SumTreeName) -> Option<u64>;
+        fn query_leafs(key: SumTreeName, cursor: u64, count: u64) -> Option<(Vec<(AccountId, u64)>, bool)>;
+        fn stake_of(key: SumTreeName, who: AccountId) -> Option<u64>;
+        /// Probability that one draw from the tree selects the participant, see `single_draw_probability`
+        fn single_draw_probability(key: SumTreeName, who: AccountId) -> Option<Perbill>;
+    }
+}
@@ -0,0 +1,91 @@
+//! Benchmarking setup for pallet-sortition-sum-game
+#![cfg(feature = "runtime-benchmarks")]
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as SortitionSumGame;
+use frame_benchmarking::v2::*;
+
+/// Benchmarks run up to trees of this many leaves
+const MAX_LEAVES: u32 = MAX_WEIGHTED_LEAVES;
+const STAKE: u64 = 100;
+
+fn tree_key<T: Config>() -> SumTreeNameType<T> {
+	SumTreeName::ProfileValidation {
+		citizen_address: account("citizen", 0, 0),
+		block_number: 0u32.into(),
+	}
+}
+
+fn create_tree_with_leaves<T: Config>(key: &SumTreeNameType<T>, leaves: u32) {
+	SortitionSumGame::<T>::create_tree(key.clone(), 2).unwrap();
+	for i in 0..leaves {
+		SortitionSumGame::<T>::set(key.clone(), STAKE, account("staker", i, 0)).unwrap();
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_new_leaf(n: Linear<1, MAX_LEAVES>) -> Result<(), BenchmarkError> {
+		let key = tree_key::<T>();
+		create_tree_with_leaves::<T>(&key, n);
+		let staker: T::AccountId = account("staker", n, 0);
+
+		#[block]
+		{
+			SortitionSumGame::<T>::set(key.clone(), STAKE, staker.clone())?;
+		}
+
+		assert_eq!(SortitionSumGame::<T>::stake_of(key, staker)?, Some(STAKE));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_existing_leaf(n: Linear<1, MAX_LEAVES>) -> Result<(), BenchmarkError> {
+		let key = tree_key::<T>();
+		create_tree_with_leaves::<T>(&key, n);
+		let staker: T::AccountId = account("staker", n - 1, 0);
+
+		#[block]
+		{
+			SortitionSumGame::<T>::set(key.clone(), STAKE * 2, staker.clone())?;
+		}
+
+		assert_eq!(SortitionSumGame::<T>::stake_of(key, staker)?, Some(STAKE * 2));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_leaf(n: Linear<1, MAX_LEAVES>) -> Result<(), BenchmarkError> {
+		let key = tree_key::<T>();
+		create_tree_with_leaves::<T>(&key, n);
+		let staker: T::AccountId = account("staker", n - 1, 0);
+
+		#[block]
+		{
+			SortitionSumGame::<T>::set(key.clone(), 0, staker.clone())?;
+		}
+
+		assert_eq!(SortitionSumGame::<T>::stake_of(key, staker)?, None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn draw(n: Linear<1, MAX_LEAVES>) -> Result<(), BenchmarkError> {
+		let key = tree_key::<T>();
+		create_tree_with_leaves::<T>(&key, n);
+		let draw_number = STAKE * n as u64 - 1;
+
+		#[block]
+		{
+			SortitionSumGame::<T>::draw(key.clone(), draw_number)?;
+		}
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(SortitionSumGame, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -1,4 +1,9 @@
 use crate::*;
+use frame_support::{
+	sp_runtime::{Perbill, Percent, SaturatedConversion},
+	Hashable,
+};
+use sp_std::collections::btree_map::BTreeMap;
 
 impl<T: Config> SortitionSumGameLink for Pallet<T> {
 	type SumTreeName = SumTreeNameType<T>;
@@ -25,91 +30,130 @@ impl<T: Config> SortitionSumGameLink for Pallet<T> {
 	fn remove_tree_link(key: Self::SumTreeName) -> DispatchResult {
 		Self::remove_tree(key)
 	}
+	fn stakers_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError> {
+		Self::stakers(key)
+	}
+	fn draw_many_link(
+		key: Self::SumTreeName,
+		seed: [u8; 32],
+		n: u64,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError> {
+		Self::draw_many(key, seed, n)
+	}
+	fn restore_link(key: Self::SumTreeName, drawn: Vec<(Self::AccountId, u64)>) -> DispatchResult {
+		Self::restore(key, drawn)
+	}
+	fn set_seniority_weighting_link(
+		key: Self::SumTreeName,
+		full_weight_after: u64,
+		initial_weight_percent: u8,
+	) -> DispatchResult {
+		let seniority = SeniorityWeighting {
+			full_weight_after: full_weight_after.saturated_into(),
+			initial_weight: Percent::from_percent(initial_weight_percent),
+		};
+		Self::set_seniority_weighting(key, seniority)
+	}
+	fn set_with_seniority_link(
+		key: Self::SumTreeName,
+		value: u64,
+		citizen_id: Self::AccountId,
+		staked_for: u64,
+	) -> DispatchResult {
+		Self::set_with_seniority(key, value, citizen_id, staked_for.saturated_into())
+	}
+	fn set_weight_link() -> Weight {
+		T::WeightInfo::set_new_leaf(MAX_WEIGHTED_LEAVES)
+	}
+	fn draw_many_weight_link(n: u64) -> Weight {
+		T::WeightInfo::draw(MAX_WEIGHTED_LEAVES)
+			.saturating_add(T::WeightInfo::remove_leaf(MAX_WEIGHTED_LEAVES))
+			.saturating_mul(n)
+	}
 }
 
 impl<T: Config> Pallet<T> {
 	/// `create_tree`:
 	/// This function creates a new sortition sum tree. It checks whether the number k (the number of children per node) is valid (greater than 1) and whether the tree already exists.
 	/// If no tree exists, it initializes a new tree with a root node of value 0 and inserts it into the storage.
-
 	pub fn create_tree(key: SumTreeNameType<T>, k: u64) -> DispatchResult {
 		if k < 1 {
 			Err(Error::<T>::KMustGreaterThanOne)?
 		}
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-		match tree_option {
-			Some(_tree) => Err(Error::<T>::TreeAlreadyExists)?,
-			None => {
-				let mut sum_tree = SortitionSumTree {
-					k,
-					stack: Vec::new(),
-					nodes: Vec::new(),
-					ids_to_node_indexes: BTreeMap::new(),
-					node_indexes_to_ids: BTreeMap::new(),
-				};
-
-				sum_tree.nodes.push(0);
-
-				<SortitionSumTrees<T>>::insert(&key, &sum_tree);
-			},
-		}
+		ensure!(!<SortitionSumTreeMetas<T>>::contains_key(&key), Error::<T>::TreeAlreadyExists);
+		let meta = SortitionSumTreeMeta { k, nodes_length: 1, stack_length: 0 };
+		<SortitionSumTreeNodes<T>>::insert(&key, 0, 0);
+		<SortitionSumTreeMetas<T>>::insert(&key, &meta);
 		Ok(())
 	}
 
+	fn tree_meta(key: &SumTreeNameType<T>) -> Result<SortitionSumTreeMeta, DispatchError> {
+		let meta = <SortitionSumTreeMetas<T>>::get(key).ok_or(Error::<T>::TreeDoesnotExist)?;
+		Ok(meta)
+	}
+
 	/// The set function is used to set a value (tokens staked) for a specific participant (citizen_id) in the sum tree.
 	/// It handles both the creation of new nodes (when a participant is not in the tree yet) and the update of existing nodes.
 	/// If the value is 0, it removes the node from the game by setting it to 0 and pushing the node to the vacant stack for future reuse.
 	/// If the value is greater than 0, the function either creates a new node for the participant or updates the value in the existing node.
 	/// The update_parents function is called after any change in a node's value to update the values of the parent nodes upwards to the root.
+	/// In a tree with seniority weighting the node holds the weight of the value, see `seniority_weight`.
+	/// The participant keeps its seniority, a new participant has none.
 	pub fn set(key: SumTreeNameType<T>, value: u64, citizen_id: AccountIdOf<T>) -> DispatchResult {
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-
-		match tree_option {
-			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(mut tree) => match tree.ids_to_node_indexes.get(&citizen_id) {
-				Some(tree_index_data) => {
-					let tree_index = *tree_index_data;
-					if tree_index == 0 {
-						Self::if_tree_index_zero(value, citizen_id, tree, tree_index, key);
+		let staked_for = <LeafStakes<T>>::get(&key, &citizen_id)
+			.map(|leaf| leaf.staked_for)
+			.unwrap_or_default();
+		Self::set_with_seniority(key, value, citizen_id, staked_for)
+	}
+
+	/// `set_with_seniority`: Sets the value of the participant as `set`, with the seniority `staked_for`.
+	/// The seniority only changes the weight in a tree with seniority weighting.
+	pub fn set_with_seniority(
+		key: SumTreeNameType<T>,
+		value: u64,
+		citizen_id: AccountIdOf<T>,
+		staked_for: BlockNumberOf<T>,
+	) -> DispatchResult {
+		Self::tree_meta(&key)?;
+		let weight = Self::seniority_weight(&key, value, &citizen_id, staked_for);
+		Self::set_leaf(key, weight, citizen_id)
+	}
+
+	/// `set_leaf`: Sets the value of the leaf of `citizen_id`, as described in `set`.
+	fn set_leaf(key: SumTreeNameType<T>, value: u64, citizen_id: AccountIdOf<T>) -> DispatchResult {
+		let mut meta = Self::tree_meta(&key)?;
+
+		match <IdsToNodeIndexes<T>>::get(&key, &citizen_id) {
+			Some(tree_index) if tree_index != 0 => {
+				// Existing node
+				let node_value = <SortitionSumTreeNodes<T>>::get(&key, tree_index);
+				if value == 0 {
+					<SortitionSumTreeNodes<T>>::insert(&key, tree_index, 0);
+					<SortitionSumTreeStack<T>>::insert(&key, meta.stack_length, tree_index);
+					meta.stack_length += 1;
+					<IdsToNodeIndexes<T>>::remove(&key, &citizen_id);
+					<NodeIndexesToIds<T>>::remove(&key, tree_index);
+					<SortitionSumTreeMetas<T>>::insert(&key, &meta);
+
+					// UpdateParents 🟥
+					Self::update_parents(&key, meta.k, tree_index, false, node_value)?;
+				} else if value != node_value {
+					let plus_or_minus = node_value <= value;
+					let plus_or_minus_value = if plus_or_minus {
+						value.checked_sub(node_value).ok_or(Error::<T>::StorageOverflow)?
 					} else {
-						// Existing node
-						if value == 0 {
-							let value = tree.nodes[tree_index as usize];
-							tree.nodes[tree_index as usize] = 0;
-							tree.stack.push(tree_index);
-							tree.ids_to_node_indexes.remove(&citizen_id);
-							tree.node_indexes_to_ids.remove(&tree_index);
-
-							// UpdateParents 🟥
-							Self::update_parents(tree, tree_index, false, value, key);
-						} else if value != tree.nodes[tree_index as usize] {
-							let plus_or_minus = tree.nodes[tree_index as usize] <= value;
-							let plus_or_minus_value = if plus_or_minus {
-								value
-									.checked_sub(tree.nodes[tree_index as usize])
-									.ok_or("StorageOverflow")?
-							} else {
-								(tree.nodes[tree_index as usize])
-									.checked_sub(value)
-									.ok_or("StorageOverflow")?
-							};
-							tree.nodes[tree_index as usize] = value;
-
-							// update parents 🟥
-							Self::update_parents(
-								tree,
-								tree_index,
-								plus_or_minus,
-								plus_or_minus_value,
-								key,
-							);
-						}
-					}
-				},
-
-				None => {
-					Self::if_tree_index_zero(value, citizen_id, tree, 0, key);
-				},
+						node_value.checked_sub(value).ok_or(Error::<T>::StorageOverflow)?
+					};
+					<SortitionSumTreeNodes<T>>::insert(&key, tree_index, value);
+
+					// update parents 🟥
+					Self::update_parents(&key, meta.k, tree_index, plus_or_minus, plus_or_minus_value)?;
+				}
+			},
+			_ => {
+				Self::if_tree_index_zero(value, citizen_id, meta, key)?;
 			},
 		}
 
@@ -117,72 +161,76 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// `update_parants`: This function ensures that when a leaf node's value is changed, the parent nodes' sums are also updated. It traverses from the updated node to the root and adjusts the sum of each parent based on whether the change was an increase or decrease.
+	/// Only the nodes on the path to the root are read and written.
 	fn update_parents(
-		mut tree: SortitionSumTree<AccountIdOf<T>>,
+		key: &SumTreeNameType<T>,
+		k: u64,
 		tree_index: u64,
 		plus_or_minus: bool,
 		value: u64,
-		key: SumTreeNameType<T>,
-	) {
+	) -> DispatchResult {
 		let mut parent_index = tree_index;
 		while parent_index != 0 {
-			parent_index = (parent_index - 1) / tree.k;
-			tree.nodes[parent_index as usize] = if plus_or_minus {
-				(tree.nodes[parent_index as usize]).checked_add(value).expect("StorageOverflow")
-			} else {
-				(tree.nodes[parent_index as usize]).checked_sub(value).expect("StorageOverflow")
-			};
-
-			<SortitionSumTrees<T>>::insert(&key, &tree);
+			parent_index = (parent_index - 1) / k;
+			<SortitionSumTreeNodes<T>>::try_mutate(key, parent_index, |node| -> DispatchResult {
+				*node = if plus_or_minus { node.checked_add(value) } else { node.checked_sub(value) }
+					.ok_or(Error::<T>::StorageOverflow)?;
+				Ok(())
+			})?;
 		}
+		Ok(())
 	}
 
 	/// `if_tree_index_zero`: This helper function is called when the participant does not yet have a node in the tree (i.e., it's the first time they're staking tokens). It adds a new node to the tree or reuses a node from the stack of vacant nodes. It then updates the tree structure by updating the parent nodes.
-
 	fn if_tree_index_zero(
 		value: u64,
 		citizen_id: AccountIdOf<T>,
-		mut tree: SortitionSumTree<AccountIdOf<T>>,
-		mut tree_index: u64,
+		mut meta: SortitionSumTreeMeta,
 		key: SumTreeNameType<T>,
-	) {
+	) -> DispatchResult {
 		// No existing node.
-		if value != 0 {
-			// Non zero value.
-			// Append.
-			// Add node.
-			if tree.stack.len() == 0 {
-				// No vacant spots.
-				// Get the index and append the value.
-				tree_index = tree.nodes.len() as u64;
-				tree.nodes.push(value);
-
-				// println!("{}", tree_index);
-
-				// Potentially append a new node and make the parent a sum node.
-				if tree_index != 1 && (tree_index - 1) % tree.k == 0 {
-					// Is first child.
-					let parent_index = tree_index / tree.k;
-					let parent_id = tree.node_indexes_to_ids.get(&parent_index).unwrap().clone();
-					let new_index = tree_index + 1;
-					tree.nodes.push(*tree.nodes.get(parent_index as usize).unwrap());
-					tree.node_indexes_to_ids.remove(&parent_index);
-					tree.ids_to_node_indexes.insert(parent_id.clone(), new_index);
-					tree.node_indexes_to_ids.insert(new_index, parent_id);
-				}
-			} else {
-				let tree_index = tree.stack.get(tree.stack.len() - 1);
-				tree.nodes[*tree_index.unwrap() as usize] = value;
-				tree.stack.pop();
-			}
-
-			tree.ids_to_node_indexes.insert(citizen_id.clone(), tree_index);
-			tree.node_indexes_to_ids.insert(tree_index, citizen_id);
-
-			// update_parents 🟥
-
-			Self::update_parents(tree, tree_index, true, value, key);
+		if value == 0 {
+			return Ok(());
 		}
+		// Non zero value.
+		// Append.
+		// Add node.
+		let tree_index = if meta.stack_length == 0 {
+			// No vacant spots.
+			// Get the index and append the value.
+			let tree_index = meta.nodes_length;
+			<SortitionSumTreeNodes<T>>::insert(&key, tree_index, value);
+			meta.nodes_length += 1;
+
+			// Potentially append a new node and make the parent a sum node.
+			if tree_index != 1 && (tree_index - 1).is_multiple_of(meta.k) {
+				// Is first child.
+				let parent_index = tree_index / meta.k;
+				let parent_id = <NodeIndexesToIds<T>>::take(&key, parent_index)
+					.ok_or(Error::<T>::NoneValue)?;
+				let new_index = tree_index + 1;
+				let parent_value = <SortitionSumTreeNodes<T>>::get(&key, parent_index);
+				<SortitionSumTreeNodes<T>>::insert(&key, new_index, parent_value);
+				meta.nodes_length += 1;
+				<IdsToNodeIndexes<T>>::insert(&key, &parent_id, new_index);
+				<NodeIndexesToIds<T>>::insert(&key, new_index, parent_id);
+			}
+			tree_index
+		} else {
+			// Reuse the last vacant node.
+			meta.stack_length -= 1;
+			let tree_index = <SortitionSumTreeStack<T>>::take(&key, meta.stack_length)
+				.ok_or(Error::<T>::NoneValue)?;
+			<SortitionSumTreeNodes<T>>::insert(&key, tree_index, value);
+			tree_index
+		};
+		<SortitionSumTreeMetas<T>>::insert(&key, &meta);
+
+		<IdsToNodeIndexes<T>>::insert(&key, &citizen_id, tree_index);
+		<NodeIndexesToIds<T>>::insert(&key, tree_index, citizen_id);
+
+		// update_parents 🟥
+		Self::update_parents(&key, meta.k, tree_index, true, value)
 	}
 
 	/// This function retrieves the number of tokens staked by a specific participant (`citizen_id`). If the participant does not exist in the tree, it returns `None`.
@@ -190,26 +238,28 @@ impl<T: Config> Pallet<T> {
 		key: SumTreeNameType<T>,
 		citizen_id: AccountIdOf<T>,
 	) -> Result<Option<u64>, DispatchError> {
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-		match tree_option {
-			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(tree) => {
-				let tree_index_data;
-				match tree.ids_to_node_indexes.get(&citizen_id) {
-					Some(v) => tree_index_data = v,
-					None => return Ok(None),
-				}
+		ensure!(<SortitionSumTreeMetas<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		let value = match Self::weight_of(&key, &citizen_id) {
+			None => return Ok(None),
+			Some(weight) => Self::leaf_stake(&key, &citizen_id, weight),
+		};
+		Ok(Some(value))
+	}
 
-				let value: u64;
-				let tree_index = *tree_index_data;
-				if tree_index == 0 {
-					value = 0;
-				} else {
-					value = tree.nodes[tree_index as usize];
-				}
-				Ok(Some(value))
-			},
+	/// `weight_of`: The value of the leaf of `citizen_id`, the weight with which it is drawn.
+	fn weight_of(key: &SumTreeNameType<T>, citizen_id: &AccountIdOf<T>) -> Option<u64> {
+		match <IdsToNodeIndexes<T>>::get(key, citizen_id)? {
+			0 => Some(0),
+			tree_index => Some(<SortitionSumTreeNodes<T>>::get(key, tree_index)),
+		}
+	}
+
+	/// `leaf_stake`: The stake of a leaf holding `weight`, the stake stored alongside the leaf in a tree with seniority weighting.
+	fn leaf_stake(key: &SumTreeNameType<T>, citizen_id: &AccountIdOf<T>, weight: u64) -> u64 {
+		if weight == 0 {
+			return 0;
 		}
+		<LeafStakes<T>>::get(key, citizen_id).map(|leaf| leaf.stake).unwrap_or(weight)
 	}
 
 	/// The draw function is the core of the sortition game. It draws a participant randomly, weighted by the number of tokens they have staked. It works by starting from the root node and recursively traversing the tree to find the participant corresponding to the random number (draw_number).
@@ -219,31 +269,111 @@ impl<T: Config> Pallet<T> {
 		key: SumTreeNameType<T>,
 		draw_number: u64,
 	) -> Result<AccountIdOf<T>, DispatchError> {
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-
-		match tree_option {
-			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(tree) => {
-				let mut tree_index = 0;
-				let mut current_draw_number = draw_number % tree.nodes[0];
-
-				while (tree.k * tree_index) + 1 < (tree.nodes.len() as u64) {
-					for i in 1..tree.k + 1 {
-						let node_index = (tree.k * tree_index) + i;
-						let node_value = tree.nodes[node_index as usize];
-
-						if current_draw_number >= node_value {
-							current_draw_number -= node_value;
-						} else {
-							tree_index = node_index;
-							break;
-						}
-					}
+		let meta = Self::tree_meta(&key)?;
+		let total = <SortitionSumTreeNodes<T>>::get(&key, 0);
+		ensure!(total != 0, Error::<T>::NoneValue);
+
+		let tree_index = Self::draw_index(&meta, draw_number % total, |node_index| {
+			<SortitionSumTreeNodes<T>>::get(&key, node_index)
+		})?;
+		let account_id =
+			<NodeIndexesToIds<T>>::get(&key, tree_index).ok_or(Error::<T>::NoneValue)?;
+		Ok(account_id)
+	}
+
+	/// `draw_index`: Traverses the tree from the root to the leaf of `draw_number`, reading the nodes with `node_value`.
+	fn draw_index(
+		meta: &SortitionSumTreeMeta,
+		draw_number: u64,
+		node_value: impl Fn(u64) -> u64,
+	) -> Result<u64, DispatchError> {
+		let mut tree_index = 0;
+		let mut current_draw_number = draw_number;
+
+		while (meta.k * tree_index) + 1 < meta.nodes_length {
+			let mut child_index = None;
+			for i in 1..meta.k + 1 {
+				let node_index = (meta.k * tree_index) + i;
+				let value = node_value(node_index);
+
+				if current_draw_number >= value {
+					current_draw_number -= value;
+				} else {
+					child_index = Some(node_index);
+					break;
 				}
-				let account_id = tree.node_indexes_to_ids.get(&tree_index).unwrap().clone();
-				Ok(account_id)
-			},
+			}
+			tree_index = child_index.ok_or(Error::<T>::NoneValue)?;
+		}
+		Ok(tree_index)
+	}
+
+	/// `draw_many`: Draws `n` distinct participants in one pass, deterministically from the `seed`.
+	/// The stake of a drawn participant is subtracted in memory from its leaf and the parents, so the next draw of the pass can't draw it again.
+	/// The drawn participants are then removed from the tree, they are returned with their stakes so that `restore` can put them back.
+	/// Fewer than `n` participants are returned when the tree runs out of stake.
+	pub fn draw_many(
+		key: SumTreeNameType<T>,
+		seed: [u8; 32],
+		n: u64,
+	) -> Result<Vec<(AccountIdOf<T>, u64)>, DispatchError> {
+		let meta = Self::tree_meta(&key)?;
+		// Nodes changed by the draws of this pass
+		let mut changed_nodes: BTreeMap<u64, u64> = BTreeMap::new();
+		let node_value = |changed_nodes: &BTreeMap<u64, u64>, node_index: u64| {
+			changed_nodes
+				.get(&node_index)
+				.copied()
+				.unwrap_or_else(|| <SortitionSumTreeNodes<T>>::get(&key, node_index))
+		};
+
+		let mut drawn = Vec::new();
+		let mut draw_nonce = 0u64;
+		while (drawn.len() as u64) < n {
+			let total = node_value(&changed_nodes, 0);
+			if total == 0 {
+				break;
+			}
+			let random_hash = (seed, draw_nonce).blake2_256();
+			let draw_number = u64::decode(&mut random_hash.as_ref())
+				.expect("secure hashes should always be bigger than u64; qed");
+			draw_nonce += 1;
+
+			let tree_index = Self::draw_index(&meta, draw_number % total, |node_index| {
+				node_value(&changed_nodes, node_index)
+			})?;
+			let weight = node_value(&changed_nodes, tree_index);
+			let account_id =
+				<NodeIndexesToIds<T>>::get(&key, tree_index).ok_or(Error::<T>::NoneValue)?;
+
+			changed_nodes.insert(tree_index, 0);
+			let mut parent_index = tree_index;
+			while parent_index != 0 {
+				parent_index = (parent_index - 1) / meta.k;
+				let value = node_value(&changed_nodes, parent_index)
+					.checked_sub(weight)
+					.ok_or(Error::<T>::StorageOverflow)?;
+				changed_nodes.insert(parent_index, value);
+			}
+			let stake = Self::leaf_stake(&key, &account_id, weight);
+			drawn.push((account_id, stake));
+		}
+
+		// The stakes stored alongside the leaves are kept, so `restore` keeps the seniority.
+		for (account_id, _) in drawn.iter() {
+			Self::set_leaf(key.clone(), 0, account_id.clone())?;
+		}
+		Ok(drawn)
+	}
+
+	/// `restore`: Puts the participants returned by `draw_many` back into the tree with their stakes.
+	/// They are set in reverse order, so every participant gets its vacant node back from the stack.
+	pub fn restore(key: SumTreeNameType<T>, drawn: Vec<(AccountIdOf<T>, u64)>) -> DispatchResult {
+		ensure!(<SortitionSumTreeMetas<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		for (account_id, stake) in drawn.into_iter().rev() {
+			Self::set(key.clone(), stake, account_id)?;
 		}
+		Ok(())
 	}
 
 	/// query_leafs:
@@ -253,47 +383,134 @@ impl<T: Config> Pallet<T> {
 		cursor: u64,
 		count: u64,
 	) -> Result<(u64, Vec<u64>, bool), DispatchError> {
-		let tree_option = <SortitionSumTrees<T>>::get(&key);
-
-		match tree_option {
-			None => Err(Error::<T>::TreeDoesnotExist)?,
-			Some(tree) => {
-				let mut start_index = 0;
-				for i in 0..tree.nodes.len() {
-					if (tree.k * i as u64) + 1 >= tree.nodes.len() as u64 {
-						start_index = i as u64;
-						break;
-					}
-				}
-				let loop_start_index = start_index + cursor;
-
-				// let value = if loop_start_index + count > tree.nodes.len() as u64 {
-				// 	tree.nodes.len() as u64 - loop_start_index
-				// } else {
-				// 	count
-				// };
-
-				let mut values = Vec::new();
-				let mut values_index = 0;
-				let mut has_more = false;
-				for j in loop_start_index..tree.nodes.len() as u64 {
-					if values_index < count {
-						values.push(tree.nodes[j as usize]);
-						values_index = values_index + 1;
-					} else {
-						has_more = true;
-						break;
-					}
-				}
+		let meta = Self::tree_meta(&key)?;
+		let start_index = (0..meta.nodes_length)
+			.find(|i| (meta.k * i) + 1 >= meta.nodes_length)
+			.unwrap_or(0);
+		let loop_start_index = start_index + cursor;
+
+		let mut values = Vec::new();
+		let mut has_more = false;
+		for j in loop_start_index..meta.nodes_length {
+			if (values.len() as u64) < count {
+				values.push(<SortitionSumTreeNodes<T>>::get(&key, j));
+			} else {
+				has_more = true;
+				break;
+			}
+		}
 
-				Ok((start_index, values, has_more))
-			},
+		Ok((start_index, values, has_more))
+	}
+
+	/// `stakers`: This function returns all participants of the tree with their stakes, in the same way as `stake_of`, ordered by account.
+	pub fn stakers(key: SumTreeNameType<T>) -> Result<Vec<(AccountIdOf<T>, u64)>, DispatchError> {
+		ensure!(<SortitionSumTreeMetas<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		let mut stakers = <IdsToNodeIndexes<T>>::iter_prefix(&key)
+			.map(|(citizen_id, tree_index)| {
+				let weight =
+					if tree_index == 0 { 0 } else { <SortitionSumTreeNodes<T>>::get(&key, tree_index) };
+				let value = Self::leaf_stake(&key, &citizen_id, weight);
+				(citizen_id, value)
+			})
+			.collect::<Vec<_>>();
+		stakers.sort_by(|(a, _), (b, _)| a.cmp(b));
+		Ok(stakers)
+	}
+
+	/// `total_stake`: The value of the root node, the stake of all participants of the tree.
+	pub fn total_stake(key: SumTreeNameType<T>) -> Option<u64> {
+		if !<SortitionSumTreeMetas<T>>::contains_key(&key) {
+			return None;
 		}
+		Some(<SortitionSumTreeNodes<T>>::get(&key, 0))
 	}
 
-	/// `remove_tree`: This function removes a sortition sum tree from storage.
+	/// `query_leafs_with_ids`: Like `query_leafs`, but returns the participants of the leaves with their stakes.
+	/// `cursor` and `count` are positions of leaves, vacant leaves are skipped.
+	pub fn query_leafs_with_ids(
+		key: SumTreeNameType<T>,
+		cursor: u64,
+		count: u64,
+	) -> Option<LeavesWithIdsOf<T>> {
+		let (start_index, values, has_more) = Self::query_leafs(key.clone(), cursor, count).ok()?;
+		let leaves = values
+			.into_iter()
+			.enumerate()
+			.filter_map(|(position, value)| {
+				let tree_index = start_index + cursor + position as u64;
+				<NodeIndexesToIds<T>>::get(&key, tree_index).map(|citizen_id| {
+					let stake = Self::leaf_stake(&key, &citizen_id, value);
+					(citizen_id, stake)
+				})
+			})
+			.collect();
+		Some((leaves, has_more))
+	}
+
+	/// `single_draw_probability`: The probability that one draw from the tree selects `citizen_id`, its weight over the total weight.
+	/// Jurors of a game are drawn without replacement in `max_draws` draws, their chance to be drawn is higher.
+	pub fn single_draw_probability(key: SumTreeNameType<T>, citizen_id: AccountIdOf<T>) -> Option<Perbill> {
+		let total = Self::total_stake(key.clone())?;
+		let weight = Self::weight_of(&key, &citizen_id)?;
+		if total == 0 {
+			return Some(Perbill::from_parts(0));
+		}
+		Some(Perbill::from_rational(weight, total))
+	}
+
+	/// `remove_tree`: This function removes a sortition sum tree and all its nodes from storage.
 	pub fn remove_tree(key: SumTreeNameType<T>) -> DispatchResult {
-		<SortitionSumTrees<T>>::remove(&key);
+		<SortitionSumTreeMetas<T>>::remove(&key);
+		let _ = <SortitionSumTreeNodes<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <SortitionSumTreeStack<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <IdsToNodeIndexes<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <NodeIndexesToIds<T>>::clear_prefix(&key, u32::MAX, None);
+		let _ = <LeafStakes<T>>::clear_prefix(&key, u32::MAX, None);
+		<TreeSeniority<T>>::remove(&key);
+		Ok(())
+	}
+
+	/// `set_seniority_weighting`: Weights the stakes of an empty tree by how long they have been staked.
+	/// A fresh stake counts with `initial_weight`, growing linearly to the whole stake after `full_weight_after` blocks,
+	/// so new accounts have less chance to be drawn than long standing participants with the same stake.
+	pub fn set_seniority_weighting(
+		key: SumTreeNameType<T>,
+		seniority: SeniorityWeighting<BlockNumberOf<T>>,
+	) -> DispatchResult {
+		ensure!(<SortitionSumTreeMetas<T>>::contains_key(&key), Error::<T>::TreeDoesnotExist);
+		ensure!(<IdsToNodeIndexes<T>>::iter_prefix(&key).next().is_none(), Error::<T>::TreeNotEmpty);
+		<TreeSeniority<T>>::insert(&key, seniority);
 		Ok(())
 	}
+
+	/// `seniority_weight`: The weight of `stake` staked for `staked_for` blocks, the stake itself for trees without seniority weighting.
+	/// The stake and the seniority are stored alongside the leaf, the weight is fixed when the stake is set.
+	fn seniority_weight(
+		key: &SumTreeNameType<T>,
+		stake: u64,
+		citizen_id: &AccountIdOf<T>,
+		staked_for: BlockNumberOf<T>,
+	) -> u64 {
+		let seniority = match <TreeSeniority<T>>::get(key) {
+			Some(seniority) => seniority,
+			None => return stake,
+		};
+		if stake == 0 {
+			<LeafStakes<T>>::remove(key, citizen_id);
+			return 0;
+		}
+		// Also kept by `draw_many`, so a restored participant keeps its seniority
+		<LeafStakes<T>>::insert(key, citizen_id, LeafStake { stake, staked_for });
+
+		let full_weight_after: u128 = seniority.full_weight_after.saturated_into();
+		let staked_for: u128 = staked_for.saturated_into();
+		if staked_for >= full_weight_after {
+			return stake;
+		}
+		let initial = seniority.initial_weight.mul_floor(stake);
+		let growth = (stake - initial) as u128 * staked_for / full_weight_after;
+		// A staker is never dropped from the tree by its weight
+		(initial + growth as u64).max(1)
+	}
 }
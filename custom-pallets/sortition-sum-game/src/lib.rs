@@ -11,24 +11,40 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::*;
+
 mod extras;
+pub mod migrations;
 pub mod types;
 
-use crate::types::{SortitionSumTree, SumTreeName};
+use crate::types::{LeafStake, SeniorityWeighting, SortitionSumTreeMeta, SumTreeName};
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
-use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use sp_std::vec::Vec;
 use trait_sortition_sum_game::SortitionSumGameLink;
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BlockNumberOf<T> = BlockNumberFor<T>;
 type SumTreeNameType<T> = SumTreeName<AccountIdOf<T>, BlockNumberOf<T>>;
+/// Participants of a page of leaves with their stakes, and whether more leaves follow
+type LeavesWithIdsOf<T> = (Vec<(AccountIdOf<T>, u64)>, bool);
+
+/// Trees are weighted as trees of this many leaves, the size the weights are benchmarked for.
+/// Set and draw weights grow with the depth of the tree, so larger trees are only a few
+/// levels deeper.
+pub const MAX_WEIGHTED_LEAVES: u32 = 16_384;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -36,6 +52,8 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// Type representing the weight of this pallet
+		type WeightInfo: WeightInfo;
 	}
 
 	// Pallets use events to inform users when important changes are made.
@@ -48,10 +66,82 @@ pub mod pallet {
 		SomethingStored { something: u32, who: T::AccountId },
 	}
 
+	/// Metadata of the tree, nodes and id mappings are stored per entry
+	/// so that `set` and `draw` only touch the path from a leaf to the root.
+	#[pallet::storage]
+	#[pallet::getter(fn sortition_sum_tree_meta)]
+	pub type SortitionSumTreeMetas<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SortitionSumTreeMeta>;
+
+	/// Value of the node at index, the root at index 0 holds the sum of all stakes
 	#[pallet::storage]
-	#[pallet::getter(fn sortition_sum_trees)]
-	pub type SortitionSumTrees<T> =
-		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SortitionSumTree<AccountIdOf<T>>>;
+	pub type SortitionSumTreeNodes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		u64,
+		ValueQuery,
+	>;
+
+	/// Vacant node indexes by stack position
+	#[pallet::storage]
+	pub type SortitionSumTreeStack<T> =
+		StorageDoubleMap<_, Blake2_128Concat, SumTreeNameType<T>, Twox64Concat, u64, u64>;
+
+	#[pallet::storage]
+	pub type IdsToNodeIndexes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		u64,
+	>;
+
+	#[pallet::storage]
+	pub type NodeIndexesToIds<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Twox64Concat,
+		u64,
+		AccountIdOf<T>,
+	>;
+
+	/// Trees whose leaves hold the stake weighted by how long it has been staked
+	#[pallet::storage]
+	#[pallet::getter(fn tree_seniority)]
+	pub type TreeSeniority<T> =
+		StorageMap<_, Blake2_128Concat, SumTreeNameType<T>, SeniorityWeighting<BlockNumberOf<T>>>;
+
+	#[pallet::storage]
+	pub type LeafStakes<T> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		LeafStake<BlockNumberOf<T>>,
+	>;
+
+	/// Items already migrated of the first tree left in the storage before version 1, set while
+	/// the trees are migrated in `on_idle`, see `migrations::v1::migrate_trees`
+	#[pallet::storage]
+	pub type MigrationCursor<T> = StorageValue<_, u64>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let item_weight = T::DbWeight::get().reads_writes(1, 1);
+			let max_items = remaining_weight
+				.ref_time()
+				.checked_div(item_weight.ref_time())
+				.unwrap_or(u64::MAX);
+			migrations::v1::migrate_trees::<T>(max_items)
+		}
+	}
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -60,5 +150,6 @@ pub mod pallet {
 		KMustGreaterThanOne,
 		TreeAlreadyExists,
 		TreeDoesnotExist,
+		TreeNotEmpty,
 	}
 }
@@ -0,0 +1,145 @@
+use crate::*;
+
+pub mod v1 {
+	use super::*;
+	use crate::types::SortitionSumTree;
+	use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+	#[cfg(feature = "try-runtime")]
+	use frame_support::sp_runtime::TryRuntimeError;
+
+	/// Trees before storage version 1, the whole tree is one storage value.
+	#[frame_support::storage_alias]
+	pub type SortitionSumTrees<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		SumTreeNameType<T>,
+		SortitionSumTree<AccountIdOf<T>>,
+	>;
+
+	/// Reading a tree, writing its metadata and removing it count as items of the tree
+	const TREE_ITEMS: u64 = 3;
+
+	/// Starts the migration of the trees to per node storage, the trees are migrated in `on_idle`
+	/// within the weight left in the block, see `migrate_trees`.
+	pub struct InnerMigrateV0ToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if SortitionSumTrees::<T>::iter_keys().next().is_none() {
+				return T::DbWeight::get().reads(1);
+			}
+			<MigrationCursor<T>>::put(0);
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let trees = SortitionSumTrees::<T>::iter_keys().count() as u64;
+			Ok(trees.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let trees = u64::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("Invalid pre upgrade state"))?;
+			ensure!(
+				trees == 0 || <MigrationCursor<T>>::exists(),
+				TryRuntimeError::Other("Trees left without a migration cursor")
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates the trees to per node storage, runs only when the on-chain storage version is 0.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+
+	/// Migrates at most `max_items` nodes, vacant stack entries and id mappings of the old trees.
+	/// A tree can take several blocks, it is usable once its metadata is written after all of its
+	/// items. `MigrationCursor` is removed when no old tree is left.
+	pub fn migrate_trees<T: Config>(max_items: u64) -> Weight {
+		let Some(mut position) = <MigrationCursor<T>>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+		let mut items = 0u64;
+		let mut finished = false;
+		while items.saturating_add(TREE_ITEMS) < max_items {
+			let Some((key, tree)) = SortitionSumTrees::<T>::iter().next() else {
+				finished = true;
+				break;
+			};
+			items += TREE_ITEMS;
+			let total = tree_items(&tree);
+			let end = total.min(position.saturating_add(max_items - items));
+			migrate_tree_items::<T>(&key, &tree, position, end);
+			items += end - position;
+			if end < total {
+				position = end;
+				break;
+			}
+			let meta = SortitionSumTreeMeta {
+				k: tree.k,
+				nodes_length: tree.nodes.len() as u64,
+				stack_length: tree.stack.len() as u64,
+			};
+			<SortitionSumTreeMetas<T>>::insert(&key, meta);
+			SortitionSumTrees::<T>::remove(&key);
+			position = 0;
+		}
+		if finished {
+			<MigrationCursor<T>>::kill();
+		} else {
+			<MigrationCursor<T>>::put(position);
+		}
+		T::DbWeight::get().reads_writes(items + 1, items + 1)
+	}
+
+	/// Items are the nodes, then the stack, then both id mappings of the tree
+	fn tree_items<AccountId>(tree: &SortitionSumTree<AccountId>) -> u64 {
+		(tree.nodes.len() +
+			tree.stack.len() +
+			tree.ids_to_node_indexes.len() +
+			tree.node_indexes_to_ids.len()) as u64
+	}
+
+	/// Positions of the items `start..end` in a section of `length` items at `offset`
+	fn section(start: u64, end: u64, offset: u64, length: usize) -> (usize, usize) {
+		let length = length as u64;
+		let from = start.saturating_sub(offset).min(length);
+		let to = end.saturating_sub(offset).min(length);
+		(from as usize, to as usize)
+	}
+
+	fn migrate_tree_items<T: Config>(
+		key: &SumTreeNameType<T>,
+		tree: &SortitionSumTree<AccountIdOf<T>>,
+		start: u64,
+		end: u64,
+	) {
+		let mut offset = 0u64;
+		let (from, to) = section(start, end, offset, tree.nodes.len());
+		for (index, value) in tree.nodes.iter().enumerate().take(to).skip(from) {
+			<SortitionSumTreeNodes<T>>::insert(key, index as u64, value);
+		}
+		offset += tree.nodes.len() as u64;
+		let (from, to) = section(start, end, offset, tree.stack.len());
+		for (position, index) in tree.stack.iter().enumerate().take(to).skip(from) {
+			<SortitionSumTreeStack<T>>::insert(key, position as u64, index);
+		}
+		offset += tree.stack.len() as u64;
+		let (from, to) = section(start, end, offset, tree.ids_to_node_indexes.len());
+		for (citizen_id, index) in tree.ids_to_node_indexes.iter().take(to).skip(from) {
+			<IdsToNodeIndexes<T>>::insert(key, citizen_id, index);
+		}
+		offset += tree.ids_to_node_indexes.len() as u64;
+		let (from, to) = section(start, end, offset, tree.node_indexes_to_ids.len());
+		for (index, citizen_id) in tree.node_indexes_to_ids.iter().take(to).skip(from) {
+			<NodeIndexesToIds<T>>::insert(key, index, citizen_id);
+		}
+	}
+}
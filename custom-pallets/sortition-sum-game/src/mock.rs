@@ -36,6 +36,7 @@ impl frame_system::Config for Test {
 
 impl pallet_template::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
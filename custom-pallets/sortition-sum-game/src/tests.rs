@@ -1,5 +1,16 @@
-use crate::{mock::*, types::SumTreeName};
-use frame_support::{assert_ok};
+use crate::{
+	migrations::v1::{migrate_trees, InnerMigrateV0ToV1, SortitionSumTrees},
+	mock::*,
+	types::{SeniorityWeighting, SortitionSumTree, SumTreeName},
+	Error,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::{Perbill, Percent},
+	traits::{Hooks, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_std::collections::btree_map::BTreeMap;
 
 #[test]
 fn it_works_for_default_value() {
@@ -40,3 +51,171 @@ fn correct_error_for_none_value() {
 		println!("{:?}", data);
 	});
 }
+
+#[test]
+fn vacant_node_is_reused() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		assert_ok!(TemplateModule::set(key.clone(), 0, 2));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 2), Ok(None));
+		assert_ok!(TemplateModule::set(key.clone(), 50, 4));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 4), Ok(Some(50)));
+		assert_eq!(TemplateModule::stakers(key.clone()), Ok(vec![(1, 10), (3, 30), (4, 50)]));
+		assert_eq!(crate::SortitionSumTreeNodes::<Test>::get(&key, 0), 90);
+		assert_eq!(TemplateModule::draw(key.clone(), 89), Ok(4));
+
+		assert_ok!(TemplateModule::remove_tree(key.clone()));
+		assert_eq!(TemplateModule::sortition_sum_tree_meta(key.clone()), None);
+		assert_eq!(crate::IdsToNodeIndexes::<Test>::iter_prefix(&key).count(), 0);
+	});
+}
+
+#[test]
+fn migrate_trees_to_per_node_storage() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		// Tree of k = 2 with the stakes 20, 30 and a vacant node
+		let tree = SortitionSumTree {
+			k: 2,
+			stack: vec![3],
+			nodes: vec![50, 20, 30, 0, 20],
+			ids_to_node_indexes: BTreeMap::from([(1, 4), (2, 2)]),
+			node_indexes_to_ids: BTreeMap::from([(4, 1), (2, 2)]),
+		};
+		SortitionSumTrees::<Test>::insert(&key, tree);
+
+		InnerMigrateV0ToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(crate::MigrationCursor::<Test>::get(), Some(0));
+
+		// Nodes and the stack are migrated, the tree is not usable before its id mappings are
+		migrate_trees::<Test>(3 + 6);
+		assert_eq!(crate::MigrationCursor::<Test>::get(), Some(6));
+		assert_eq!(crate::SortitionSumTreeNodes::<Test>::get(&key, 4), 20);
+		assert_noop!(TemplateModule::stake_of(key.clone(), 2), Error::<Test>::TreeDoesnotExist);
+
+		TemplateModule::on_idle(1, Weight::MAX);
+		assert_eq!(crate::MigrationCursor::<Test>::get(), None);
+		assert!(!SortitionSumTrees::<Test>::contains_key(&key));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 2), Ok(Some(30)));
+		assert_eq!(TemplateModule::stakers(key.clone()), Ok(vec![(1, 20), (2, 30)]));
+		assert_eq!(TemplateModule::draw(key.clone(), 25), Ok(2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 4));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 4), Ok(Some(10)));
+		assert_eq!(TemplateModule::draw(key.clone(), 5), Ok(4));
+	});
+}
+
+#[test]
+fn draw_many_without_replacement() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		for j in 1..11 {
+			assert_ok!(TemplateModule::set(key.clone(), j * 10, j));
+		}
+		let seed = [7u8; 32];
+		let drawn = TemplateModule::draw_many(key.clone(), seed, 4).unwrap();
+		assert_eq!(drawn.len(), 4);
+		let mut accounts = drawn.iter().map(|(account, _)| *account).collect::<Vec<_>>();
+		accounts.sort();
+		accounts.dedup();
+		assert_eq!(accounts.len(), 4);
+		for (account, stake) in drawn.iter() {
+			assert_eq!(*stake, account * 10);
+			assert_eq!(TemplateModule::stake_of(key.clone(), *account), Ok(None));
+		}
+		let drawn_stake = drawn.iter().map(|(_, stake)| stake).sum::<u64>();
+		assert_eq!(crate::SortitionSumTreeNodes::<Test>::get(&key, 0), 550 - drawn_stake);
+
+		assert_ok!(TemplateModule::restore(key.clone(), drawn.clone()));
+		assert_eq!(crate::SortitionSumTreeNodes::<Test>::get(&key, 0), 550);
+		// Same seed draws the same accounts from the same stakes
+		let mut drawn_again = TemplateModule::draw_many(key.clone(), seed, 4).unwrap();
+		let mut drawn = drawn;
+		drawn.sort();
+		drawn_again.sort();
+		assert_eq!(drawn_again, drawn);
+
+		// Draws stop when the tree runs out of stake
+		let rest = TemplateModule::draw_many(key.clone(), seed, 20).unwrap();
+		assert_eq!(rest.len(), 6);
+		assert_eq!(crate::SortitionSumTreeNodes::<Test>::get(&key, 0), 0);
+	});
+}
+
+#[test]
+fn inspect_tree() {
+	new_test_ext().execute_with(|| {
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_eq!(TemplateModule::total_stake(key.clone()), None);
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		assert_ok!(TemplateModule::set(key.clone(), 10, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 20, 2));
+		assert_ok!(TemplateModule::set(key.clone(), 30, 3));
+		assert_ok!(TemplateModule::set(key.clone(), 40, 4));
+		assert_ok!(TemplateModule::set(key.clone(), 0, 2));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(80));
+		assert_eq!(
+			TemplateModule::query_leafs_with_ids(key.clone(), 0, 10),
+			Some((vec![(3, 30), (1, 10), (4, 40)], false))
+		);
+		assert_eq!(
+			TemplateModule::query_leafs_with_ids(key.clone(), 0, 2),
+			Some((vec![(3, 30), (1, 10)], true))
+		);
+		assert_eq!(
+			TemplateModule::single_draw_probability(key.clone(), 4),
+			Some(Perbill::from_percent(50))
+		);
+		assert_eq!(TemplateModule::single_draw_probability(key.clone(), 2), None);
+	});
+}
+
+#[test]
+fn seniority_weighting() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let key = SumTreeName::ProfileValidation { citizen_address: 1, block_number: 10 };
+		assert_ok!(TemplateModule::create_tree(key.clone(), 2));
+		let seniority =
+			SeniorityWeighting { full_weight_after: 100, initial_weight: Percent::from_percent(20) };
+		assert_ok!(TemplateModule::set_seniority_weighting(key.clone(), seniority.clone()));
+		assert_ok!(TemplateModule::set_with_seniority(key.clone(), 1000, 1, 50));
+		assert_ok!(TemplateModule::set(key.clone(), 1000, 2));
+		assert_noop!(
+			TemplateModule::set_seniority_weighting(key.clone(), seniority),
+			Error::<Test>::TreeNotEmpty
+		);
+
+		// 1 has staked for 50 blocks: 200 + 800 * 50 / 100, 2 has the initial weight
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(800));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 1), Ok(Some(1000)));
+		assert_eq!(TemplateModule::stake_of(key.clone(), 2), Ok(Some(1000)));
+		assert_eq!(
+			TemplateModule::single_draw_probability(key.clone(), 1),
+			Some(Perbill::from_percent(75))
+		);
+
+		// Changing the stake keeps the seniority, the weight is capped at the stake
+		assert_ok!(TemplateModule::set(key.clone(), 2000, 1));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(1400));
+		assert_ok!(TemplateModule::set_with_seniority(key.clone(), 2000, 1, 200));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(2200));
+
+		// Unstaking drops the seniority
+		assert_ok!(TemplateModule::set(key.clone(), 0, 1));
+		assert_ok!(TemplateModule::set(key.clone(), 2000, 1));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(600));
+		assert_eq!(TemplateModule::stakers(key.clone()), Ok(vec![(1, 2000), (2, 1000)]));
+
+		// Drawn stakes are restored with their seniority
+		let drawn = TemplateModule::draw_many(key.clone(), [1u8; 32], 2).unwrap();
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(0));
+		assert_ok!(TemplateModule::restore(key.clone(), drawn));
+		assert_eq!(TemplateModule::total_stake(key.clone()), Some(600));
+	});
+}
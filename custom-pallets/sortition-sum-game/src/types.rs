@@ -1,11 +1,11 @@
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, sp_runtime::Percent};
 use scale_info::TypeInfo;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 type _CitizenId = u64;
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo)]
-#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug, serde::Serialize, serde::Deserialize))]
 pub enum SumTreeName<AccountId, BlockNumber> {
 	ProfileValidation { citizen_address: AccountId, block_number: BlockNumber },
 	PositiveExternality { user_address: AccountId, block_number: BlockNumber },
@@ -13,7 +13,7 @@ pub enum SumTreeName<AccountId, BlockNumber> {
 	ProjectTips { project_id: u64, block_number: BlockNumber },
 }
 
-/// SortitionSumTree Struct:
+/// SortitionSumTree Struct, layout of a tree before storage version 1, kept for the migration:
 /// `k`: Represents the number of children each non-leaf node has in the tree. For example, in a binary tree, k=2.
 /// `stack`: A stack used to store vacant nodes for efficient reuse when values are set to 0.
 /// `nodes`: The list of nodes in the tree. The root node holds the sum of all values (i.e., all the tokens in this case), and the leaf nodes store the actual stake values.
@@ -28,3 +28,35 @@ pub struct SortitionSumTree<AccountId> {
 	pub ids_to_node_indexes: BTreeMap<AccountId, u64>, // citizen id, node index
 	pub node_indexes_to_ids: BTreeMap<u64, AccountId>, // node index, citizen id
 }
+
+/// SortitionSumTreeMeta Struct:
+/// `k`: Represents the number of children each non-leaf node has in the tree.
+/// `nodes_length`: The number of nodes in the tree, nodes are stored by index in `SortitionSumTreeNodes`.
+/// `stack_length`: The number of vacant nodes, stored by stack position in `SortitionSumTreeStack`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SortitionSumTreeMeta {
+	pub k: u64,
+	pub nodes_length: u64,
+	pub stack_length: u64,
+}
+
+/// SeniorityWeighting Struct:
+/// `full_weight_after`: The number of blocks of staking after which the whole stake counts as weight.
+/// `initial_weight`: The part of the stake that counts for a fresh stake, it grows linearly to the whole stake.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct SeniorityWeighting<BlockNumber> {
+	pub full_weight_after: BlockNumber,
+	pub initial_weight: Percent,
+}
+
+/// LeafStake Struct, stored alongside the leaf of a tree with seniority weighting:
+/// `stake`: The tokens staked, the leaf holds the weight of the stake.
+/// `staked_for`: The number of blocks the participant has staked for, its seniority.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub struct LeafStake<BlockNumber> {
+	pub stake: u64,
+	pub staked_for: BlockNumber,
+}
@@ -1,30 +1,15 @@
-
-//! Autogenerated weights for pallet_template
+//! Weights for pallet_sortition_sum_game
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-04-06, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `Alexs-MacBook-Pro-2.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
-
-// Executed Command:
-// ../../target/release/node-template
-// benchmark
-// pallet
-// --chain
-// dev
-// --pallet
-// pallet_template
-// --extrinsic
-// *
-// --steps=50
-// --repeat=20
-// --execution=wasm
-// --wasm-execution=compiled
-// --output
-// pallets/template/src/weights.rs
-// --template
-// ../../.maintain/frame-weight-template.hbs
+//! Set and draw touch the nodes on the path from a leaf to the root, so the weights grow with the
+//! depth of the tree, `log2(n)` for the binary trees of the benchmarks with `n` leaves.
+//!
+//! These weights are NOT measured. They are estimates from the storage accesses of each call,
+//! with a fixed execution time per node, until they are generated with the benchmarks of
+//! `benchmarking.rs` (up to `MAX_WEIGHTED_LEAVES` leaves) on the target hardware:
+//!
+//! ./target/release/solochain-template-node benchmark pallet --chain dev
+//! --pallet pallet_sortition_sum_game --extrinsic * --steps=50 --repeat=20
+//! --wasm-execution=compiled --output custom-pallets/sortition-sum-game/src/weights.rs
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -33,59 +18,85 @@
 use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
 use core::marker::PhantomData;
 
-/// Weight functions needed for pallet_template.
+/// Weight functions needed for pallet_sortition_sum_game.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn set_new_leaf(n: u32, ) -> Weight;
+	fn set_existing_leaf(n: u32, ) -> Weight;
+	fn remove_leaf(n: u32, ) -> Weight;
+	fn draw(n: u32, ) -> Weight;
+}
+
+/// Depth of a binary tree with `n` leaves
+fn depth(n: u32) -> u64 {
+	(n.max(1).ilog2() as u64).saturating_add(1)
 }
 
-/// Weights for pallet_template using the Substrate node and recommended hardware.
+/// Estimated weights for pallet_sortition_sum_game, see the module documentation.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: SortitionSumGame SortitionSumTreeMetas (r:1 w:1)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1 w:2)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:1 w:2)
+	/// Storage: SortitionSumGame SortitionSumTreeNodes (r:depth w:depth + 2)
+	fn set_new_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(T::DbWeight::get().writes(7_u64.saturating_add(depth(n))))
+	}
+	/// Storage: SortitionSumGame SortitionSumTreeMetas (r:1 w:0)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTreeNodes (r:depth + 1 w:depth + 1)
+	fn set_existing_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(T::DbWeight::get().writes(1_u64.saturating_add(depth(n))))
+	}
+	/// Storage: SortitionSumGame SortitionSumTreeMetas (r:1 w:1)
+	/// Storage: SortitionSumGame IdsToNodeIndexes (r:1 w:1)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:0 w:1)
+	/// Storage: SortitionSumGame SortitionSumTreeStack (r:0 w:1)
+	/// Storage: SortitionSumGame SortitionSumTreeNodes (r:depth + 1 w:depth + 1)
+	fn remove_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(T::DbWeight::get().writes(5_u64.saturating_add(depth(n))))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+	/// Storage: SortitionSumGame SortitionSumTreeMetas (r:1 w:0)
+	/// Storage: SortitionSumGame SortitionSumTreeNodes (r:2 * depth + 1 w:0)
+	/// Storage: SortitionSumGame NodeIndexesToIds (r:1 w:0)
+	fn draw(n: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 3_500)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(T::DbWeight::get().reads(3_u64.saturating_add(2_u64.saturating_mul(depth(n)))))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: TemplateModule Something (r:0 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	fn set_new_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(RocksDbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(RocksDbWeight::get().writes(7_u64.saturating_add(depth(n))))
+	}
+	fn set_existing_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(RocksDbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(RocksDbWeight::get().writes(1_u64.saturating_add(depth(n))))
+	}
+	fn remove_leaf(n: u32, ) -> Weight {
+		Weight::from_parts(18_000_000, 3_500)
+			.saturating_add(Weight::from_parts(4_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(RocksDbWeight::get().reads(3_u64.saturating_add(depth(n))))
+			.saturating_add(RocksDbWeight::get().writes(5_u64.saturating_add(depth(n))))
 	}
-	/// Storage: TemplateModule Something (r:1 w:1)
-	/// Proof: TemplateModule Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	fn draw(n: u32, ) -> Weight {
+		Weight::from_parts(12_000_000, 3_500)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(depth(n)))
+			.saturating_add(RocksDbWeight::get().reads(3_u64.saturating_add(2_u64.saturating_mul(depth(n)))))
 	}
 }
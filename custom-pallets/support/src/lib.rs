@@ -96,6 +96,17 @@ impl Content {
     }
 }
 
+/// Status of a citizen in the profile validation directory
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum CitizenStatus {
+    /// Profile without a running validation game that is not approved
+    Pending = 0,
+    InValidation = 1,
+    Approved = 2,
+    /// Approved citizen removed by a fraud challenge
+    Revoked = 3,
+}
+
 #[derive(Encode, Decode, RuntimeDebug, strum::IntoStaticStr)]
 pub enum ContentError {
     /// IPFS CID is invalid.
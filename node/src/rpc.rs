@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
 use sc_transaction_pool_api::TransactionPool;
-use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce, SumTreeName};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
@@ -36,10 +36,12 @@ where
     P: TransactionPool + 'static,
     C::Api: profile_validation_runtime_api::ProfileValidationApi<Block, AccountId>,
     C::Api: positive_externality_runtime_api::PositiveExternalityApi<Block, AccountId>,
+    C::Api: sortition_sum_game_runtime_api::SortitionSumGameApi<Block, SumTreeName, AccountId>,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use positive_externality_rpc::{PositiveExternality, PositiveExternalityApiServer};
     use profile_validation_rpc::{ProfileValidation, ProfileValidationApiServer};
+    use sortition_sum_game_rpc::{SortitionSumGame, SortitionSumGameApiServer};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
     let mut module = RpcModule::new(());
@@ -49,6 +51,7 @@ where
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
     module.merge(ProfileValidation::new(client.clone()).into_rpc())?;
     module.merge(PositiveExternality::new(client.clone()).into_rpc())?;
+    module.merge(SortitionSumGame::new(client.clone()).into_rpc())?;
 
     // Extend this RPC with a custom API by using the following syntax.
     // `YourRpcStruct` should have a reference to a client, which is needed
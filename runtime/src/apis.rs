@@ -30,6 +30,7 @@ use frame_support::{
     weights::Weight,
 };
 use pallet_grandpa::AuthorityId as GrandpaId;
+use pallet_profile_validation::CitizenStatus;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
@@ -44,7 +45,7 @@ use sp_version::RuntimeVersion;
 use super::{
     AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce,
     PositiveExternality, ProfileValidation, Runtime, RuntimeCall, RuntimeGenesisConfig,
-    SessionKeys, System, TransactionPayment, VERSION,
+    SessionKeys, SortitionSumGame, SumTreeName, System, TransactionPayment, VERSION,
 };
 
 pub type ChallengePostId = u64;
@@ -324,6 +325,18 @@ impl_runtime_apis! {
             fn selected_as_juror(profile_user_account: AccountId, who: AccountId) -> bool {
                 ProfileValidation::selected_as_juror(profile_user_account, who)
             }
+            fn get_citizenship_expiry(who: AccountId) -> Option<u32> {
+                ProfileValidation::get_citizenship_expiry(who)
+            }
+            fn get_upcoming_expirations(within_blocks: u32, limit: u16) -> Vec<(AccountId, u32)> {
+                ProfileValidation::get_upcoming_expirations(within_blocks, limit)
+            }
+            fn get_citizen_account(citizen_id: u64) -> Option<AccountId> {
+                ProfileValidation::citizen_account(citizen_id)
+            }
+            fn get_citizens(offset: u64, limit: u16) -> Vec<(u64, AccountId, CitizenStatus)> {
+                ProfileValidation::get_citizens(offset, limit)
+            }
         }
 
         impl positive_externality_runtime_api::PositiveExternalityApi<Block, AccountId> for Runtime {
@@ -385,4 +398,23 @@ impl_runtime_apis! {
 
     }
 
+    impl sortition_sum_game_runtime_api::SortitionSumGameApi<Block, SumTreeName, AccountId> for Runtime {
+
+        fn total_stake(key: SumTreeName) -> Option<u64> {
+            SortitionSumGame::total_stake(key)
+        }
+
+        fn query_leafs(key: SumTreeName, cursor: u64, count: u64) -> Option<(Vec<(AccountId, u64)>, bool)> {
+            SortitionSumGame::query_leafs_with_ids(key, cursor, count)
+        }
+
+        fn stake_of(key: SumTreeName, who: AccountId) -> Option<u64> {
+            SortitionSumGame::stake_of(key, who).ok().flatten()
+        }
+
+        fn single_draw_probability(key: SumTreeName, who: AccountId) -> Option<sp_runtime::Perbill> {
+            SortitionSumGame::single_draw_probability(key, who)
+        }
+    }
+
 }
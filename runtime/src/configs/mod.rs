@@ -31,17 +31,18 @@ use frame_support::{
         constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
         IdentityFee, Weight,
     },
+    PalletId,
 };
 use frame_system::limits::{BlockLength, BlockWeights};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use scale_info::TypeInfo;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{traits::One, Perbill, Percent};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo,
+    AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Kyc, Nonce, PalletInfo,
     RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
     RuntimeHoldReason, RuntimeOrigin, RuntimeTask, SchellingGameShared, SharedStorage,
     SortitionSumGame, System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
@@ -195,9 +196,16 @@ parameter_types! {
     pub const VoteLengthProfileValidation: u64 = 50;
     pub const AppealLengthProfileValidation: u64 = 50 ;
     pub const MaxDrawsProfileValidation: u64 = 5;
-    pub const MinNumberJurorStakedProfileValidation: u64 = 3;
+    pub const MinNumberJurorStakedProfileValidation: u64 = 5;
     pub const MinJurorStakeProfileValidation: u64 = 100;
     pub const JurorIncentivesProfileValidation: (u64, u64) = (100, 100);
+    pub const RecoveryDelayProfileValidation: u64 = 14400;
+    pub const RecoveryExpiryProfileValidation: u64 = 3 * 14400;
+    pub const CitizenshipValidityProfileValidation: u64 = 10_512_000; // 2 years
+    pub const RevalidationGracePeriodProfileValidation: u64 = 432_000; // 30 days
+    pub const MaxDemotionsPerBlockProfileValidation: u32 = 100;
+    pub const JurorShareOfLosingFundProfileValidation: Percent = Percent::from_percent(10);
+    pub const IdentityAttestationPeriodProfileValidation: u64 = 100_800; // 7 days
     pub const TotalNumbersGamesForIncentivesProfileValidation: u64 = 20;
     pub const JurorWinMultiplierProfileValidation: u64 = 10 * 100;
     pub const JurorLossMultiplierProfileValidation: u64 = 15 * 100;
@@ -214,15 +222,30 @@ impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 
 impl pallet_sortition_sum_game::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_sortition_sum_game::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+    pub const JurorRewardPoolPalletId: PalletId = PalletId(*b"sg/jrpol");
+    pub const RewardEpochLength: BlockNumber = 14400; // 1 day = (24*60*60)/6
+    pub const RewardEpochInflation: Balance = 100_000;
+    pub const MaxRewardPerEpoch: Balance = 200_000;
+    pub const AppealFeePerJuror: Balance = 100;
+    pub const MaxJurorPoolSize: u32 = 1000;
 }
 
 impl pallet_schelling_game_shared::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type RandomnessSource = RandomnessCollectiveFlip;
-    type Slash = ();
     type Reward = ();
     type SortitionSumGameSource = SortitionSumGame;
+    type RewardPoolPalletId = JurorRewardPoolPalletId;
+    type RewardEpochLength = RewardEpochLength;
+    type RewardEpochInflation = RewardEpochInflation;
+    type MaxRewardPerEpoch = MaxRewardPerEpoch;
+    type AppealFeePerJuror = AppealFeePerJuror;
+    type MaxJurorPoolSize = MaxJurorPoolSize;
 }
 
 impl pallet_profile_validation::Config for Runtime {
@@ -244,6 +267,23 @@ impl pallet_profile_validation::Config for Runtime {
     type MinNumberJurorStaked = MinNumberJurorStakedProfileValidation;
     type MinJurorStake = MinJurorStakeProfileValidation;
     type JurorIncentives = JurorIncentivesProfileValidation;
+    type RecoveryDelay = RecoveryDelayProfileValidation;
+    type RecoveryExpiry = RecoveryExpiryProfileValidation;
+    type CitizenshipValidity = CitizenshipValidityProfileValidation;
+    type RevalidationGracePeriod = RevalidationGracePeriodProfileValidation;
+    type MaxDemotionsPerBlock = MaxDemotionsPerBlockProfileValidation;
+    type JurorShareOfLosingFund = JurorShareOfLosingFundProfileValidation;
+    type IdentityAttestationPeriod = IdentityAttestationPeriodProfileValidation;
+    type KycSource = Kyc;
+    // Replace with the elected council origin once available
+    type UpdateOrigin = frame_system::EnsureRoot<AccountId>;
+}
+
+impl pallet_kyc::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    // Replace with the verifier of the risc0 receipt once available
+    type ProofVerifier = pallet_kyc::RejectAllProofVerifier;
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
 }
 
 impl pallet_shared_storage::Config for Runtime {
@@ -69,10 +69,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     //   `spec_version`, and `authoring_version` are the same between Wasm and native.
     // This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
     //   the compatible custom types.
-    spec_version: 100,
+    spec_version: 101,
     impl_version: 1,
     apis: apis::RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     system_version: 1,
 };
 
@@ -134,6 +134,9 @@ pub type Hash = sp_core::H256;
 /// An index to a block.
 pub type BlockNumber = u32;
 
+/// Name of a sortition sum tree, the key of a game.
+pub type SumTreeName = pallet_sortition_sum_game::types::SumTreeName<AccountId, BlockNumber>;
+
 /// The address format for describing accounts.
 pub type Address = MultiAddress<AccountId, ()>;
 
@@ -173,7 +176,13 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+    pallet_sortition_sum_game::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_shared_storage::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_schelling_game_shared::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_profile_validation::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_profile_validation::migrations::v2::MigrateV1ToV2<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -244,4 +253,7 @@ mod runtime {
 
     #[runtime::pallet_index(13)]
     pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
+
+    #[runtime::pallet_index(14)]
+    pub type Kyc = pallet_kyc;
 }
@@ -0,0 +1,14 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+use frame_support::dispatch::DispatchResult;
+
+pub trait KycLink {
+    type AccountId;
+
+    /// Account has a registered KYC commitment that is not expired
+    fn ensure_live_commitment_link(who: Self::AccountId) -> DispatchResult;
+
+    fn commitment_of_link(who: Self::AccountId) -> Option<[u8; 32]>;
+
+    /// Move the commitment of `from` to `to`, used by the recovery of a citizen account
+    fn transfer_commitment_link(from: Self::AccountId, to: Self::AccountId) -> DispatchResult;
+}
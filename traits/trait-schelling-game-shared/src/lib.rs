@@ -1,8 +1,26 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use frame_support::dispatch::DispatchResult;
 use frame_support::pallet_prelude::DispatchError;
+use frame_support::weights::Weight;
+use sp_arithmetic::{FixedI64, FixedU128};
 use sp_std::prelude::*;
 
+/// Phase lengths in blocks and jury parameters of a game, see `create_phase_with_all_data`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PhaseParams {
+	pub evidence_length: u64,
+	pub end_of_staking_time: u64,
+	pub staking_length: u64,
+	pub drawing_length: u64,
+	pub commit_length: u64,
+	pub vote_length: u64,
+	pub appeal_length: u64,
+	pub max_draws: u64,
+	pub min_number_juror_staked: u64,
+	pub min_juror_stake: u64,
+	pub juror_incentives: (u64, u64),
+}
+
 pub trait SchellingGameSharedLink {
 	type SumTreeName;
 	type SchellingGameType;
@@ -14,6 +32,10 @@ pub trait SchellingGameSharedLink {
 	type PhaseData;
 	type WinningDecision;
 	type JurorGameResult;
+	type CancellationReason;
+	type RubricDimension;
+	type VrfPublic;
+	type VrfSignature;
 
 	fn create_phase_data(
 		block_length: u64,
@@ -23,25 +45,17 @@ pub trait SchellingGameSharedLink {
 		juror_incentives: (u64, u64),
 	) -> Self::PhaseData;
 
-	fn create_phase_with_all_data(
-		evidence_length: u64,
-		end_of_staking_time: u64,
-		staking_length: u64,
-		drawing_length: u64,
-		commit_length: u64,
-		vote_length: u64,
-		appeal_length: u64,
-		max_draws: u64,
-		min_number_juror_staked: u64,
-		min_juror_stake: u64,
-		juror_incentives: (u64, u64),
-	) -> Self::PhaseData;
+	fn create_phase_with_all_data(params: PhaseParams) -> Self::PhaseData;
 	fn get_period_link(key: Self::SumTreeName) -> Option<Self::Period>;
 
 	fn set_to_evidence_period_link(
 		key: Self::SumTreeName,
 		now: Self::BlockNumber,
 	) -> DispatchResult;
+
+	fn set_game_court_link(key: Self::SumTreeName, court_id: u64) -> DispatchResult;
+
+	fn use_juror_pool_link(key: Self::SumTreeName, department_id: u64) -> DispatchResult;
 	fn create_tree_helper_link(key: Self::SumTreeName, k: u64) -> DispatchResult;
 
 	fn set_to_staking_period_link(
@@ -77,6 +91,10 @@ pub trait SchellingGameSharedLink {
 		interations: u64,
 	) -> DispatchResult;
 	fn unstaking_helper_link(key: Self::SumTreeName, who: Self::AccountId) -> DispatchResult;
+	/// Weight of staking or unstaking a juror, a set in the sortition tree
+	fn set_stake_weight_link() -> Weight;
+	/// Weight of drawing up to `iterations` jurors
+	fn draw_jurors_weight_link(iterations: u64) -> Weight;
 	fn commit_vote_helper_link(
 		key: Self::SumTreeName,
 		who: Self::AccountId,
@@ -136,7 +154,66 @@ pub trait SchellingGameSharedLink {
 		range_point: Self::RangePoint,
 	) -> DispatchResult;
 
-	fn get_mean_value_link(key: Self::SumTreeName) -> Result<i64, DispatchError>;
+	fn get_mean_value_link(key: Self::SumTreeName) -> Result<FixedI64, DispatchError>;
+
+	fn set_rubric_link(key: Self::SumTreeName, rubric: Vec<Self::RubricDimension>) -> DispatchResult;
+	fn get_rubric_link(key: Self::SumTreeName) -> Option<Vec<Self::RubricDimension>>;
+	fn commit_vote_for_rubric_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult;
+	fn reveal_vote_rubric_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choices: Vec<i64>,
+		salt: Vec<u8>,
+	) -> DispatchResult;
+	fn set_new_rubric_mean_value_link(key: Self::SumTreeName) -> DispatchResult;
+	fn get_incentives_rubric_schelling_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		range_point: Self::RangePoint,
+	) -> DispatchResult;
+	fn get_rubric_dimension_means_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<FixedI64>, DispatchError>;
+
+	fn enable_self_selection_link(key: Self::SumTreeName) -> DispatchResult;
+	fn set_juror_vrf_key_link(who: Self::AccountId, vrf_key: Self::VrfPublic) -> DispatchResult;
+	fn commit_vote_self_selected_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+		vrf_signature: Self::VrfSignature,
+	) -> DispatchResult;
+
+	fn set_pairwise_game_link(
+		key: Self::SumTreeName,
+		candidates: Vec<u64>,
+		pairs_per_juror: u32,
+	) -> DispatchResult;
+	fn get_juror_pairs_link(key: Self::SumTreeName, who: Self::AccountId) -> Vec<(u64, u64)>;
+	fn commit_vote_for_pairwise_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		vote_commit: [u8; 32],
+	) -> DispatchResult;
+	fn reveal_vote_pairwise_helper_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		choices: Vec<u8>,
+		salt: Vec<u8>,
+	) -> DispatchResult;
+	fn set_pairwise_ranking_link(key: Self::SumTreeName) -> DispatchResult;
+	fn get_incentives_pairwise_helper_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+	) -> DispatchResult;
+	fn get_pairwise_ranking_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<(u64, FixedU128)>, DispatchError>;
 
 	fn get_all_incentives_two_choice_helper(
 		key: Self::SumTreeName,
@@ -165,4 +242,33 @@ pub trait SchellingGameSharedLink {
 
 	fn has_user_staked(key: Self::SumTreeName, who: Self::AccountId ) -> bool;
 	fn user_staked_value(key: Self::SumTreeName, who: Self::AccountId) -> u64;
+
+	fn cancel_game_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		now: Self::BlockNumber,
+		reason: Self::CancellationReason,
+	) -> DispatchResult;
+
+	fn fund_appeal_link(
+		key: Self::SumTreeName,
+		phase_data: Self::PhaseData,
+		who: Self::AccountId,
+		choice: u128,
+		amount: Self::Balance,
+		now: Self::BlockNumber,
+	) -> DispatchResult;
+
+	fn withdraw_appeal_contribution_link(
+		key: Self::SumTreeName,
+		who: Self::AccountId,
+		round: u32,
+	) -> DispatchResult;
+
+	fn deposit_into_reward_pool_link(amount: Self::Balance);
+
+	fn pay_from_reward_pool_link(
+		who: Self::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
 }
@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use sp_arithmetic::FixedI64;
 
 pub trait SharedStorageLink {
     type AccountId;
@@ -9,7 +10,18 @@ pub trait SharedStorageLink {
     fn check_citizen_is_approved_link(address: Self::AccountId) -> DispatchResult;
 
     fn get_approved_citizen_count_link() -> u64;
-    fn set_positive_externality_link(address: Self::AccountId, score: i64) -> DispatchResult;
+
+    /// Move citizenship, reputation and department memberships to a new account
+    fn transfer_citizen_link(old: Self::AccountId, new: Self::AccountId) -> DispatchResult;
+
+    /// Remove the citizen from approved citizens and departments, the departments are restored
+    /// when the citizen is approved again
+    fn remove_approved_citizen_link(address: Self::AccountId) -> DispatchResult;
+
+    /// Remove the citizen from approved citizens and departments, and freeze its reputation
+    fn revoke_citizen_link(address: Self::AccountId) -> DispatchResult;
+
+    fn set_positive_externality_link(address: Self::AccountId, score: FixedI64) -> DispatchResult;
     fn add_reputation_score_to_department(
         address: Self::AccountId,
         department_id: u64,
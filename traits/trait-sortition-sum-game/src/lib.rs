@@ -1,5 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+use sp_std::prelude::*;
 
 pub trait SortitionSumGameLink {
 	type SumTreeName;
@@ -15,4 +16,29 @@ pub trait SortitionSumGameLink {
 		draw_number: u64,
 	) -> Result<Self::AccountId, DispatchError>;
 	fn remove_tree_link(key: Self::SumTreeName) -> DispatchResult;
+	fn stakers_link(
+		key: Self::SumTreeName,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError>;
+	fn draw_many_link(
+		key: Self::SumTreeName,
+		seed: [u8; 32],
+		n: u64,
+	) -> Result<Vec<(Self::AccountId, u64)>, DispatchError>;
+	fn restore_link(key: Self::SumTreeName, drawn: Vec<(Self::AccountId, u64)>) -> DispatchResult;
+	fn set_seniority_weighting_link(
+		key: Self::SumTreeName,
+		full_weight_after: u64,
+		initial_weight_percent: u8,
+	) -> DispatchResult;
+	/// Set a stake that has been staked for `staked_for` blocks, it counts in trees with seniority weighting
+	fn set_with_seniority_link(
+		key: Self::SumTreeName,
+		value: u64,
+		citizen_id: Self::AccountId,
+		staked_for: u64,
+	) -> DispatchResult;
+	/// Weight of setting a stake in a tree
+	fn set_weight_link() -> Weight;
+	/// Weight of drawing `n` stakers without replacement
+	fn draw_many_weight_link(n: u64) -> Weight;
 }